@@ -4,8 +4,8 @@ use std::fs::create_dir_all;
 use cosmwasm_schema::{export_schema_with_title, remove_schemas, schema_for};
 
 use astroport_governance::assembly::{
-    Cw20HookMsg, ExecuteMsg, InstantiateMsg, Proposal, ProposalListResponse, ProposalVotesResponse,
-    QueryMsg, UpdateConfig,
+    ArchivedProposalListResponse, Cw20HookMsg, ExecuteMsg, InstantiateMsg, Proposal,
+    ProposalListResponse, ProposalVotesResponse, QueryMsg, UpdateConfig, UserVoteResponse,
 };
 
 fn main() {
@@ -29,5 +29,11 @@ fn main() {
         &out_dir,
         "ProposalListResponse",
     );
+    export_schema_with_title(
+        &schema_for!(ArchivedProposalListResponse),
+        &out_dir,
+        "ArchivedProposalListResponse",
+    );
+    export_schema_with_title(&schema_for!(UserVoteResponse), &out_dir, "UserVoteResponse");
     export_schema_with_title(&schema_for!(UpdateConfig), &out_dir, "UpdateConfig");
 }
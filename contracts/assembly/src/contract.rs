@@ -1,28 +1,48 @@
 use cosmwasm_std::{
-    attr, entry_point, from_binary, to_binary, Addr, Binary, CosmosMsg, Decimal, Deps, DepsMut,
-    Env, MessageInfo, Order, Response, StdResult, Uint128, Uint64, WasmMsg,
+    attr, entry_point, from_binary, to_binary, Addr, Binary, ContractResult, CosmosMsg, Decimal,
+    Deps, DepsMut, Empty, Env, IbcMsg, MessageInfo, Order, Reply, Response, StdError, StdResult,
+    Storage, SubMsg, SubMsgExecutionResponse, Uint128, Uint64, WasmMsg,
 };
 use cw2::{get_contract_version, set_contract_version};
 use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20ReceiveMsg};
 use cw_storage_plus::{Bound, U64Key};
+use std::convert::TryInto;
 use std::str::FromStr;
 
 use astroport::asset::addr_validate_to_lower;
 use astroport_governance::assembly::{
-    helpers::validate_links, Config, Cw20HookMsg, ExecuteMsg, InstantiateMsg, Proposal,
-    ProposalListResponse, ProposalMessage, ProposalStatus, ProposalVoteOption,
-    ProposalVotesResponse, QueryMsg, UpdateConfig,
+    helpers::validate_links, ArchivedProposal, ArchivedProposalListResponse, Config, Cw20HookMsg,
+    ExecuteMsg, IbcMessageStatus, InstantiateMsg, Proposal, ProposalIbcMessageStatus,
+    ProposalListResponse, ProposalMessage, ProposalMessageResult, ProposalStatus, ProposalType,
+    ProposalVote, ProposalVoteOption, ProposalVotesResponse, QueryMsg, UpdateConfig,
+    UserVoteResponse, VotingPowerAdapter, VotingPowerQueryMsg, VotingPowerSource,
+    IBC_PACKET_TIMEOUT, MAX_ACTIVE_PROPOSALS_PER_SUBMITTER, MAX_DELEGATORS,
 };
 
 use astroport::xastro_token::QueryMsg as XAstroTokenQueryMsg;
 use astroport_governance::builder_unlock::msg::{
     AllocationResponse, QueryMsg as BuilderUnlockQueryMsg, StateResponse,
 };
-use astroport_governance::voting_escrow::{QueryMsg as VotingEscrowQueryMsg, VotingPowerResponse};
+use astroport_governance::satellite::SatellitePacket;
+use astroport_governance::timelock::{
+    Config as TimelockConfig, ExecuteMsg as TimelockExecuteMsg, QueryMsg as TimelockQueryMsg,
+    Transaction, TransactionStatus,
+};
+use astroport_governance::utils::{get_period, EPOCH_START, WEEK};
+use astroport_governance::voting_escrow::{
+    get_voting_power_at, QueryMsg as VotingEscrowQueryMsg, VotingPowerResponse,
+};
 
 use crate::error::ContractError;
-use crate::migration::{MigrateMsg, CONFIGV100, CONFIGV101};
-use crate::state::{CONFIG, PROPOSALS, PROPOSAL_COUNT};
+use crate::migration::{
+    load_legacy_vote, migrate_proposals, migrate_to_v110, ConfigV102, MigrateMsg, CONFIGV100,
+    CONFIGV101, CONFIGV102, LEGACY_VOTERS, PROPOSAL_MIGRATION_CURSOR, PROPOSAL_MIGRATION_LIMIT,
+};
+use crate::state::{
+    proposals, ARCHIVED_PROPOSALS, CONFIG, DELEGATED_VOTES, DELEGATED_VOTE_POWERS, DELEGATIONS,
+    DELEGATORS, IBC_CHANNELS, PROPOSAL_COUNT, PROPOSAL_DELEGATIONS, PROPOSAL_DELEGATORS,
+    PROPOSAL_VOTERS, PROPOSAL_VOTES, USER_VOTES,
+};
 
 // Contract name and version used for migration.
 const CONTRACT_NAME: &str = "astro-assembly";
@@ -32,6 +52,14 @@ const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 const DEFAULT_LIMIT: u32 = 10;
 const MAX_LIMIT: u32 = 30;
 
+// Reply id of the last message dispatched by `check_messages`. Proposal ids start from 1,
+// so replies with any other id belong to the messages of the proposal with the same id.
+const CHECK_MESSAGES_REPLY_ID: u64 = 0;
+
+// The maximum number of vote and delegation entries of a removed proposal deleted in a single
+// `remove_completed_proposal` call
+const PROPOSAL_CLEANUP_LIMIT: usize = 100;
+
 /// ## Description
 /// Creates a new contract with the specified parameters in the `msg` variable.
 /// Returns a [`Response`] with the specified attributes if the operation was successful,
@@ -69,13 +97,50 @@ pub fn instantiate(
         proposal_required_deposit: msg.proposal_required_deposit,
         proposal_required_quorum: Decimal::from_str(&msg.proposal_required_quorum)?,
         proposal_required_threshold: Decimal::from_str(&msg.proposal_required_threshold)?,
+        proposal_required_veto_threshold: Decimal::from_str(&msg.proposal_required_veto_threshold)?,
+        expedited_proposal_required_deposit: msg.expedited_proposal_required_deposit,
+        expedited_proposal_voting_period: msg.expedited_proposal_voting_period,
+        expedited_proposal_required_threshold: Decimal::from_str(
+            &msg.expedited_proposal_required_threshold,
+        )?,
+        early_finalization_enabled: msg.early_finalization_enabled.unwrap_or(false),
+        proposal_required_vxastro_power: msg.proposal_required_vxastro_power,
+        quorum_failure_deposit_slash: Decimal::from_str(&msg.quorum_failure_deposit_slash)?,
+        veto_deposit_slash: Decimal::from_str(&msg.veto_deposit_slash)?,
+        slashed_deposit_receiver: None,
+        guardian: None,
+        timelock_addr: None,
+        proposal_validation: msg.proposal_validation.unwrap_or_default(),
+        voting_power_sources: vec![],
         whitelisted_links: msg.whitelisted_links,
+        proposal_types: msg.proposal_types.unwrap_or_default(),
+        ibc_satellites: vec![],
     };
 
     if let Some(vxastro_token_addr) = msg.vxastro_token_addr {
         config.vxastro_token_addr = Some(addr_validate_to_lower(deps.api, &vxastro_token_addr)?);
     }
 
+    if let Some(slashed_deposit_receiver) = msg.slashed_deposit_receiver {
+        config.slashed_deposit_receiver =
+            Some(addr_validate_to_lower(deps.api, &slashed_deposit_receiver)?);
+    }
+
+    if let Some(guardian) = msg.guardian {
+        config.guardian = Some(addr_validate_to_lower(deps.api, &guardian)?);
+    }
+
+    if let Some(timelock_addr) = msg.timelock_addr {
+        config.timelock_addr = Some(addr_validate_to_lower(deps.api, &timelock_addr)?);
+    }
+
+    config.voting_power_sources = match msg.voting_power_sources {
+        Some(voting_power_sources) => {
+            validate_voting_power_sources(deps.as_ref(), voting_power_sources)?
+        }
+        None => default_voting_power_sources(&config),
+    };
+
     config.validate()?;
 
     CONFIG.save(deps.storage, &config)?;
@@ -100,12 +165,24 @@ pub fn instantiate(
 /// * **ExecuteMsg::Receive(cw20_msg)** Receives a message of type [`Cw20ReceiveMsg`] and processes
 /// it depending on the received template.
 ///
+/// * **ExecuteMsg::SubmitProposal { .. }** Submits a proposal backed by the sender's vxASTRO voting power.
+///
 /// * **ExecuteMsg::CastVote { proposal_id, vote }** Cast a vote on a specific proposal.
 ///
 /// * **ExecuteMsg::EndProposal { proposal_id }** Sets the status of an expired/finalized proposal.
 ///
 /// * **ExecuteMsg::ExecuteProposal { proposal_id }** Executes a successful proposal.
 ///
+/// * **ExecuteMsg::CancelProposal { proposal_id }** Cancels a proposal.
+///
+/// * **ExecuteMsg::SyncQueuedProposal { proposal_id }** Updates the status of a proposal queued in the Timelock.
+///
+/// * **ExecuteMsg::CheckMessages { messages }** Executes the messages and reverts the transaction.
+///
+/// * **ExecuteMsg::Delegate { delegatee, proposal_id }** Delegates voting power to another address.
+///
+/// * **ExecuteMsg::Undelegate { proposal_id }** Removes a voting power delegation.
+///
 /// * **ExecuteMsg::RemoveCompletedProposal { proposal_id }** Removes a finalized proposal from the proposal list.
 ///
 /// * **ExecuteMsg::UpdateConfig(config)** Updates the contract configuration.
@@ -116,17 +193,53 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
+    if PROPOSAL_MIGRATION_CURSOR.may_load(deps.storage)?.is_some() {
+        return match msg {
+            ExecuteMsg::MigrateProposals { limit } => continue_proposal_migration(deps, env, limit),
+            _ => Err(ContractError::ProposalMigrationPending {}),
+        };
+    }
+
     match msg {
         ExecuteMsg::Receive(cw20_msg) => receive_cw20(deps, env, info, cw20_msg),
+        ExecuteMsg::SubmitProposal {
+            title,
+            description,
+            link,
+            content_hash,
+            messages,
+            proposal_type,
+        } => submit_proposal_with_voting_power(
+            deps,
+            env,
+            info,
+            title,
+            description,
+            link,
+            content_hash,
+            messages,
+            proposal_type,
+        ),
         ExecuteMsg::CastVote { proposal_id, vote } => cast_vote(deps, env, info, proposal_id, vote),
         ExecuteMsg::EndProposal { proposal_id } => end_proposal(deps, env, info, proposal_id),
         ExecuteMsg::ExecuteProposal { proposal_id } => {
             execute_proposal(deps, env, info, proposal_id)
         }
+        ExecuteMsg::CancelProposal { proposal_id } => cancel_proposal(deps, env, info, proposal_id),
+        ExecuteMsg::SyncQueuedProposal { proposal_id } => {
+            sync_queued_proposal(deps, env, info, proposal_id)
+        }
+        ExecuteMsg::CheckMessages { messages } => check_messages(messages),
+        ExecuteMsg::Delegate {
+            delegatee,
+            proposal_id,
+        } => delegate(deps, env, info, delegatee, proposal_id),
+        ExecuteMsg::Undelegate { proposal_id } => undelegate(deps, env, info, proposal_id),
         ExecuteMsg::RemoveCompletedProposal { proposal_id } => {
             remove_completed_proposal(deps, env, info, proposal_id)
         }
         ExecuteMsg::UpdateConfig(config) => update_config(deps, env, info, config),
+        ExecuteMsg::MigrateProposals { .. } => Err(ContractError::ProposalMigrationFinished {}),
     }
 }
 
@@ -153,7 +266,10 @@ pub fn receive_cw20(
             title,
             description,
             link,
+            content_hash,
             messages,
+            proposal_type,
+            expedited,
         } => submit_proposal(
             deps,
             env,
@@ -163,7 +279,10 @@ pub fn receive_cw20(
             title,
             description,
             link,
+            content_hash,
             messages,
+            proposal_type,
+            expedited.unwrap_or(false),
         ),
     }
 }
@@ -188,7 +307,13 @@ pub fn receive_cw20(
 ///
 /// * **link** is an object of type [`Option<String>`]. Proposal link.
 ///
+/// * **content_hash** is an object of type [`Option<String>`]. Hash of the document behind the link.
+///
 /// * **messages** is an object of type [`Option<Vec<ProposalMessage>>`]. Executable messages (actions to perform if the proposal passes).
+///
+/// * **proposal_type** is an object of type [`Option<String>`]. Name of the proposal type whose rules apply to the proposal.
+///
+/// * **expedited** is a parameter of type `bool`. Whether the proposal is voted on under the expedited rules.
 #[allow(clippy::too_many_arguments)]
 pub fn submit_proposal(
     deps: DepsMut,
@@ -199,7 +324,10 @@ pub fn submit_proposal(
     title: String,
     description: String,
     link: Option<String>,
+    content_hash: Option<String>,
     messages: Option<Vec<ProposalMessage>>,
+    proposal_type: Option<String>,
+    expedited: bool,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
@@ -207,49 +335,250 @@ pub fn submit_proposal(
         return Err(ContractError::Unauthorized {});
     }
 
-    if deposit_amount < config.proposal_required_deposit {
+    let rules = config
+        .proposal_type(&proposal_type)
+        .ok_or(ContractError::ProposalTypeNotFound {})?;
+
+    let required_deposit = if expedited {
+        rules
+            .required_deposit
+            .max(config.expedited_proposal_required_deposit)
+    } else {
+        rules.required_deposit
+    };
+
+    if deposit_amount < required_deposit {
         return Err(ContractError::InsufficientDeposit {});
     }
 
+    create_proposal(
+        deps,
+        &env,
+        &config,
+        &rules,
+        sender,
+        deposit_amount,
+        title,
+        description,
+        link,
+        content_hash,
+        messages,
+        proposal_type,
+        expedited,
+    )
+}
+
+/// ## Description
+/// Submit a brand new proposal without an xASTRO deposit. The sender's vxASTRO voting power at the
+/// end of the previous week must be at least `proposal_required_vxastro_power`, and the sender can't
+/// have more than [`MAX_ACTIVE_PROPOSALS_PER_SUBMITTER`] active proposals.
+/// Returns [`ContractError`] on failure, otherwise returns a [`Response`] with the specified attributes if the operation was successful.
+/// ## Params
+/// * **deps** is an object of type [`DepsMut`].
+///
+/// * **env** is an object of type [`Env`].
+///
+/// * **info** is an object of type [`MessageInfo`].
+///
+/// * **title** is an object of type [`String`]. Proposal title.
+///
+/// * **description** is an object of type [`String`]. Proposal description.
+///
+/// * **link** is an object of type [`Option<String>`]. Proposal link.
+///
+/// * **content_hash** is an object of type [`Option<String>`]. Hash of the document behind the link.
+///
+/// * **messages** is an object of type [`Option<Vec<ProposalMessage>>`]. Executable messages (actions to perform if the proposal passes).
+///
+/// * **proposal_type** is an object of type [`Option<String>`]. Name of the proposal type whose rules apply to the proposal.
+#[allow(clippy::too_many_arguments)]
+pub fn submit_proposal_with_voting_power(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    title: String,
+    description: String,
+    link: Option<String>,
+    content_hash: Option<String>,
+    messages: Option<Vec<ProposalMessage>>,
+    proposal_type: Option<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let (vxastro_token_addr, required_power) = match (
+        &config.vxastro_token_addr,
+        config.proposal_required_vxastro_power,
+    ) {
+        (Some(vxastro_token_addr), Some(required_power)) => {
+            (vxastro_token_addr.clone(), required_power)
+        }
+        _ => return Err(ContractError::VotingPowerSubmissionDisabled {}),
+    };
+
+    let rules = config
+        .proposal_type(&proposal_type)
+        .ok_or(ContractError::ProposalTypeNotFound {})?;
+
+    // The voting power of the current week can still change, so it is read at the end of the
+    // previous week
+    let voting_power = match vxastro_snapshot_time(env.block.time.seconds())? {
+        Some(time) => get_voting_power_at(deps.querier, &vxastro_token_addr, &info.sender, time)?,
+        None => Uint128::zero(),
+    };
+
+    if voting_power < required_power {
+        return Err(ContractError::InsufficientVotingPower {});
+    }
+
+    let active_proposals = proposals()
+        .idx
+        .submitter
+        .prefix(info.sender.as_bytes().to_vec())
+        .range(deps.storage, None, None, Order::Descending)
+        .filter(|item| {
+            item.as_ref().map_or(true, |(_, proposal)| {
+                proposal.status == ProposalStatus::Active
+            })
+        })
+        .take(MAX_ACTIVE_PROPOSALS_PER_SUBMITTER)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    if active_proposals.len() >= MAX_ACTIVE_PROPOSALS_PER_SUBMITTER {
+        return Err(ContractError::TooManyActiveProposals {});
+    }
+
+    let response = create_proposal(
+        deps,
+        &env,
+        &config,
+        &rules,
+        info.sender,
+        Uint128::zero(),
+        title,
+        description,
+        link,
+        content_hash,
+        messages,
+        proposal_type,
+        false,
+    )?;
+
+    Ok(response.add_attribute("submitter_voting_power", voting_power))
+}
+
+/// ## Description
+/// Creates and stores a new proposal with the specified rules.
+/// Returns [`ContractError`] on failure, otherwise returns a [`Response`] with the specified attributes if the operation was successful.
+/// ## Params
+/// * **deps** is an object of type [`DepsMut`].
+///
+/// * **env** is an object of type [`Env`].
+///
+/// * **config** is an object of type [`Config`].
+///
+/// * **rules** is an object of type [`ProposalType`]. These are the rules that apply to the proposal.
+///
+/// * **sender** is an object of type [`Addr`]. Proposal submitter.
+///
+/// * **deposit_amount** is an object of type [`Uint128`]. This is the amount of xASTRO deposited by the submitter.
+///
+/// * **title** is an object of type [`String`]. Proposal title.
+///
+/// * **description** is an object of type [`String`]. Proposal description.
+///
+/// * **link** is an object of type [`Option<String>`]. Proposal link.
+///
+/// * **content_hash** is an object of type [`Option<String>`]. Hash of the document behind the link.
+///
+/// * **messages** is an object of type [`Option<Vec<ProposalMessage>>`]. Executable messages (actions to perform if the proposal passes).
+///
+/// * **proposal_type** is an object of type [`Option<String>`]. Name of the proposal type whose rules apply to the proposal.
+///
+/// * **expedited** is a parameter of type `bool`. Whether the proposal is voted on under the expedited rules.
+#[allow(clippy::too_many_arguments)]
+fn create_proposal(
+    deps: DepsMut,
+    env: &Env,
+    config: &Config,
+    rules: &ProposalType,
+    sender: Addr,
+    deposit_amount: Uint128,
+    title: String,
+    description: String,
+    link: Option<String>,
+    content_hash: Option<String>,
+    messages: Option<Vec<ProposalMessage>>,
+    proposal_type: Option<String>,
+    expedited: bool,
+) -> Result<Response, ContractError> {
+    // Messages can only be sent to satellites over open channels
+    for message in messages.iter().flatten() {
+        if let Some(ibc_channel) = &message.ibc_channel {
+            if config.timelock_addr.is_some() {
+                return Err(ContractError::RemoteMessagesWithTimelock {});
+            }
+
+            if !IBC_CHANNELS.has(deps.storage, ibc_channel) {
+                return Err(ContractError::IbcChannelNotFound(ibc_channel.clone()));
+            }
+        }
+    }
+
     // Update the proposal count
     let count = PROPOSAL_COUNT.update(deps.storage, |c| -> StdResult<_> {
         Ok(c.checked_add(Uint64::new(1))?)
     })?;
 
+    let voting_period = if expedited {
+        config.expedited_proposal_voting_period
+    } else {
+        rules.voting_period
+    };
+
+    let end_time = env.block.time.seconds() + voting_period;
+    let effective_time = end_time + rules.effective_delay;
+
     let proposal = Proposal {
         proposal_id: count,
         submitter: sender.clone(),
         status: ProposalStatus::Active,
+        proposal_type,
+        expedited,
+        converted_from_expedited: false,
         for_power: Uint128::zero(),
         against_power: Uint128::zero(),
-        for_voters: Vec::new(),
-        against_voters: Vec::new(),
+        abstain_power: Uint128::zero(),
+        no_with_veto_power: Uint128::zero(),
         start_block: env.block.height,
         start_time: env.block.time.seconds(),
-        end_block: env.block.height + config.proposal_voting_period,
+        end_time,
+        effective_time,
+        expiration_time: effective_time + config.proposal_expiration_period,
         title,
         description,
         link,
+        content_hash,
         messages,
         deposit_amount,
+        execution_results: vec![],
+        ibc_message_statuses: vec![],
     };
 
-    proposal.validate(config.whitelisted_links)?;
+    proposal.validate(config)?;
 
-    PROPOSALS.save(deps.storage, U64Key::new(count.u64()), &proposal)?;
+    proposals().save(deps.storage, U64Key::new(count.u64()), &proposal)?;
 
     Ok(Response::new()
         .add_attribute("action", "submit_proposal")
         .add_attribute("submitter", sender.to_string())
         .add_attribute("proposal_id", count.to_string())
-        .add_attribute(
-            "proposal_end_height",
-            (env.block.height + config.proposal_voting_period).to_string(),
-        ))
+        .add_attribute("proposal_type", &rules.name)
+        .add_attribute("expedited", expedited.to_string())
+        .add_attribute("proposal_end_time", end_time.to_string()))
 }
 
 /// ## Description
-/// Cast a vote on a proposal.
+/// Cast a vote on a proposal. A voter may change their vote while the voting period is active.
 /// Returns [`ContractError`] on failure, otherwise returns a [`Response`] with the specified
 /// attributes if the operation was successful.
 /// ## Params
@@ -269,7 +598,7 @@ pub fn cast_vote(
     proposal_id: u64,
     vote_option: ProposalVoteOption,
 ) -> Result<Response, ContractError> {
-    let mut proposal = PROPOSALS.load(deps.storage, U64Key::new(proposal_id))?;
+    let mut proposal = proposals().load(deps.storage, U64Key::new(proposal_id))?;
 
     if proposal.status != ProposalStatus::Active {
         return Err(ContractError::ProposalNotActive {});
@@ -279,120 +608,298 @@ pub fn cast_vote(
         return Err(ContractError::Unauthorized {});
     }
 
-    if env.block.height > proposal.end_block {
+    if env.block.time.seconds() > proposal.end_time {
         return Err(ContractError::VotingPeriodEnded {});
     }
 
-    if proposal.for_voters.contains(&info.sender) || proposal.against_voters.contains(&info.sender)
+    let config = CONFIG.load(deps.storage)?;
+
+    let previous_vote =
+        PROPOSAL_VOTES.may_load(deps.storage, (U64Key::new(proposal_id), &info.sender))?;
+
+    // Votes cast before v1.1.0 don't store their voting power, so they can't be changed
+    if previous_vote.is_none()
+        && load_legacy_vote(deps.storage, proposal_id, &info.sender)?.is_some()
     {
         return Err(ContractError::UserAlreadyVoted {});
     }
 
-    let voting_power = calc_voting_power(deps.as_ref(), info.sender.to_string(), &proposal)?;
+    let mut attributes = vec![];
+
+    // A voter may change their vote while the voting period is active. Votes are final if
+    // proposals can be ended early, because a changed vote could flip a result that was decided
+    if let Some(previous_vote) = &previous_vote {
+        if previous_vote.option == vote_option || config.early_finalization_enabled {
+            return Err(ContractError::UserAlreadyVoted {});
+        }
+
+        let previous_power = vote_option_power(&mut proposal, &previous_vote.option);
+        *previous_power = previous_power.checked_sub(previous_vote.power)?;
+
+        PROPOSAL_VOTERS.remove(
+            deps.storage,
+            (
+                U64Key::new(proposal_id),
+                previous_vote.option.to_string(),
+                &info.sender,
+            ),
+        );
+
+        // The delegated voting power used by the previous vote is recalculated below,
+        // so delegators that can no longer be used by the voter are released
+        let previous_delegators = DELEGATED_VOTE_POWERS
+            .prefix((U64Key::new(proposal_id), &info.sender))
+            .keys(deps.storage, None, None, Order::Ascending)
+            .map(|key| Ok(Addr::unchecked(String::from_utf8(key)?)))
+            .collect::<StdResult<Vec<Addr>>>()?;
+
+        for delegator in previous_delegators {
+            DELEGATED_VOTES.remove(deps.storage, (U64Key::new(proposal_id), &delegator));
+            DELEGATED_VOTE_POWERS.remove(
+                deps.storage,
+                (U64Key::new(proposal_id), &info.sender, &delegator),
+            );
+        }
+
+        attributes.push(attr("previous_vote", previous_vote.option.to_string()));
+    }
+
+    // The voting power is always calculated at the proposal snapshot, so it can't be inflated by re-voting
+    let own_power = calc_own_voting_power(deps.as_ref(), info.sender.to_string(), &proposal)?;
+    let delegated_power = calc_delegated_voting_power(deps.as_ref(), &info.sender, &proposal)?;
+
+    let voting_power = delegated_power
+        .iter()
+        .try_fold(own_power, |total, (_, power)| total.checked_add(*power))?;
 
     if voting_power.is_zero() {
         return Err(ContractError::NoVotingPower {});
     }
 
-    match vote_option {
-        ProposalVoteOption::For => {
-            proposal.for_power = proposal.for_power.checked_add(voting_power)?;
-            proposal.for_voters.push(info.sender.clone());
-        }
-        ProposalVoteOption::Against => {
-            proposal.against_power = proposal.against_power.checked_add(voting_power)?;
-            proposal.against_voters.push(info.sender.clone());
+    // The voter's own vote overrides the vote of the delegatee that used their voting power.
+    // The voting power that was used by the delegatee is removed from its vote
+    if let Some(delegatee) =
+        DELEGATED_VOTES.may_load(deps.storage, (U64Key::new(proposal_id), &info.sender))?
+    {
+        // The delegated vote is final for the same reason as a re-vote
+        if config.early_finalization_enabled {
+            return Err(ContractError::DelegatedPowerAlreadyUsed {});
         }
+
+        let used_power = DELEGATED_VOTE_POWERS.load(
+            deps.storage,
+            (U64Key::new(proposal_id), &delegatee, &info.sender),
+        )?;
+
+        let mut delegatee_vote =
+            PROPOSAL_VOTES.load(deps.storage, (U64Key::new(proposal_id), &delegatee))?;
+        delegatee_vote.power = delegatee_vote.power.checked_sub(used_power)?;
+
+        let option_power = vote_option_power(&mut proposal, &delegatee_vote.option);
+        *option_power = option_power.checked_sub(used_power)?;
+
+        PROPOSAL_VOTES.save(
+            deps.storage,
+            (U64Key::new(proposal_id), &delegatee),
+            &delegatee_vote,
+        )?;
+
+        USER_VOTES.save(
+            deps.storage,
+            (&delegatee, U64Key::new(proposal_id)),
+            &delegatee_vote,
+        )?;
+
+        PROPOSAL_VOTERS.save(
+            deps.storage,
+            (
+                U64Key::new(proposal_id),
+                delegatee_vote.option.to_string(),
+                &delegatee,
+            ),
+            &delegatee_vote.power,
+        )?;
+
+        DELEGATED_VOTES.remove(deps.storage, (U64Key::new(proposal_id), &info.sender));
+        DELEGATED_VOTE_POWERS.remove(
+            deps.storage,
+            (U64Key::new(proposal_id), &delegatee, &info.sender),
+        );
+
+        attributes.push(attr("overridden_delegatee", delegatee));
+    }
+
+    for (delegator, power) in &delegated_power {
+        DELEGATED_VOTES.save(
+            deps.storage,
+            (U64Key::new(proposal_id), delegator),
+            &info.sender,
+        )?;
+        DELEGATED_VOTE_POWERS.save(
+            deps.storage,
+            (U64Key::new(proposal_id), &info.sender, delegator),
+            power,
+        )?;
+    }
+
+    let option_power = vote_option_power(&mut proposal, &vote_option);
+    *option_power = option_power.checked_add(voting_power)?;
+
+    proposals().save(deps.storage, U64Key::new(proposal_id), &proposal)?;
+
+    let vote = ProposalVote {
+        option: vote_option.clone(),
+        power: voting_power,
     };
 
-    PROPOSALS.save(deps.storage, U64Key::new(proposal_id), &proposal)?;
+    PROPOSAL_VOTES.save(
+        deps.storage,
+        (U64Key::new(proposal_id), &info.sender),
+        &vote,
+    )?;
+
+    USER_VOTES.save(
+        deps.storage,
+        (&info.sender, U64Key::new(proposal_id)),
+        &vote,
+    )?;
+
+    PROPOSAL_VOTERS.save(
+        deps.storage,
+        (
+            U64Key::new(proposal_id),
+            vote_option.to_string(),
+            &info.sender,
+        ),
+        &voting_power,
+    )?;
 
     Ok(Response::new()
         .add_attribute("action", "cast_vote")
         .add_attribute("proposal_id", proposal_id.to_string())
         .add_attribute("voter", &info.sender)
         .add_attribute("vote", vote_option.to_string())
-        .add_attribute("voting_power", voting_power))
+        .add_attribute("voting_power", voting_power)
+        .add_attributes(attributes))
 }
 
 /// ## Description
-/// Ends proposal voting and sets the proposal status.
-/// Returns a [`ContractError`] on failure, otherwise returns a [`Response`] with the specified
+/// Delegates the sender's voting power to another address. The delegatee votes with the power of
+/// all their delegators that didn't vote on a proposal themselves. If `proposal_id` is set, the
+/// delegation only applies to that proposal and overrides the general delegation.
+/// The sender must have voting power and a delegatee can have at most [`MAX_DELEGATORS`] delegators.
+/// Returns [`ContractError`] on failure, otherwise returns a [`Response`] with the specified
 /// attributes if the operation was successful.
 /// ## Params
 /// * **deps** is an object of type [`DepsMut`].
 ///
 /// * **env** is an object of type [`Env`].
 ///
-/// * **_info** is an object of type [`MessageInfo`].
+/// * **info** is an object of type [`MessageInfo`].
 ///
-/// * **proposal_id** is a parameter of type `u64`. This is the proposal identifier.
-pub fn end_proposal(
+/// * **delegatee** is an object of type [`String`]. This is the address that receives the voting power.
+///
+/// * **proposal_id** is an [`Option`] of type `u64`. This is the proposal the delegation applies to.
+pub fn delegate(
     deps: DepsMut,
     env: Env,
-    _info: MessageInfo,
-    proposal_id: u64,
+    info: MessageInfo,
+    delegatee: String,
+    proposal_id: Option<u64>,
 ) -> Result<Response, ContractError> {
-    let mut proposal = PROPOSALS.load(deps.storage, U64Key::new(proposal_id))?;
+    let delegatee = addr_validate_to_lower(deps.api, &delegatee)?;
 
-    if proposal.status != ProposalStatus::Active {
-        return Err(ContractError::ProposalNotActive {});
+    if delegatee == info.sender {
+        return Err(ContractError::SelfDelegation {});
     }
 
-    if env.block.height <= proposal.end_block {
-        return Err(ContractError::VotingPeriodNotEnded {});
-    }
+    // Delegators without voting power would only make the delegated voting power more expensive
+    // to calculate. Snapshots are read at the next block, so the changes in the current block count
+    let voting_power = calc_voting_power_at(
+        deps.as_ref(),
+        info.sender.as_str(),
+        env.block.height + 1,
+        env.block.time.seconds(),
+    )?;
 
-    let config = CONFIG.load(deps.storage)?;
+    if voting_power.is_zero() {
+        return Err(ContractError::NoVotingPower {});
+    }
 
-    let for_votes = proposal.for_power;
-    let against_votes = proposal.against_power;
-    let total_votes = for_votes + against_votes;
+    let mut response = Response::new()
+        .add_attribute("action", "delegate")
+        .add_attribute("delegator", &info.sender)
+        .add_attribute("delegatee", &delegatee);
+
+    match proposal_id {
+        Some(proposal_id) => {
+            check_proposal_delegation(deps.as_ref(), &env, &info.sender, proposal_id)?;
+
+            let previous_delegatee = PROPOSAL_DELEGATIONS
+                .may_load(deps.storage, (U64Key::new(proposal_id), &info.sender))?;
+
+            if previous_delegatee.as_ref() != Some(&delegatee)
+                && PROPOSAL_DELEGATORS
+                    .prefix((U64Key::new(proposal_id), &delegatee))
+                    .keys(deps.storage, None, None, Order::Ascending)
+                    .take(MAX_DELEGATORS)
+                    .count()
+                    == MAX_DELEGATORS
+            {
+                return Err(ContractError::TooManyDelegators {});
+            }
 
-    let total_voting_power = calc_total_voting_power_at(deps.as_ref(), &proposal)?;
+            if let Some(previous_delegatee) = previous_delegatee {
+                PROPOSAL_DELEGATORS.remove(
+                    deps.storage,
+                    (U64Key::new(proposal_id), &previous_delegatee, &info.sender),
+                );
+            }
 
-    let mut proposal_quorum: Decimal = Decimal::zero();
-    let mut proposal_threshold: Decimal = Decimal::zero();
+            PROPOSAL_DELEGATIONS.save(
+                deps.storage,
+                (U64Key::new(proposal_id), &info.sender),
+                &delegatee,
+            )?;
+            PROPOSAL_DELEGATORS.save(
+                deps.storage,
+                (U64Key::new(proposal_id), &delegatee, &info.sender),
+                &Empty {},
+            )?;
+
+            response = response.add_attribute("proposal_id", proposal_id.to_string());
+        }
+        None => {
+            check_delegation(deps.as_ref(), &env, &info.sender)?;
+
+            let previous_delegatee = DELEGATIONS.may_load(deps.storage, &info.sender)?;
+
+            if previous_delegatee.as_ref() != Some(&delegatee)
+                && DELEGATORS
+                    .prefix(&delegatee)
+                    .keys(deps.storage, None, None, Order::Ascending)
+                    .take(MAX_DELEGATORS)
+                    .count()
+                    == MAX_DELEGATORS
+            {
+                return Err(ContractError::TooManyDelegators {});
+            }
 
-    if !total_voting_power.is_zero() {
-        proposal_quorum = Decimal::from_ratio(total_votes, total_voting_power);
-    }
+            if let Some(previous_delegatee) = previous_delegatee {
+                DELEGATORS.remove(deps.storage, (&previous_delegatee, &info.sender));
+            }
 
-    if !total_votes.is_zero() {
-        proposal_threshold = Decimal::from_ratio(for_votes, total_votes);
+            DELEGATIONS.save(deps.storage, &info.sender, &delegatee)?;
+            DELEGATORS.save(deps.storage, (&delegatee, &info.sender), &Empty {})?;
+        }
     }
 
-    // Determine the proposal result
-    proposal.status = if proposal_quorum >= config.proposal_required_quorum
-        && proposal_threshold > config.proposal_required_threshold
-    {
-        ProposalStatus::Passed
-    } else {
-        ProposalStatus::Rejected
-    };
-
-    PROPOSALS.save(deps.storage, U64Key::new(proposal_id), &proposal)?;
-
-    let response = Response::new()
-        .add_attributes(vec![
-            attr("action", "end_proposal"),
-            attr("proposal_id", proposal_id.to_string()),
-            attr("proposal_result", proposal.status.to_string()),
-        ])
-        .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: config.xastro_token_addr.to_string(),
-            msg: to_binary(&Cw20ExecuteMsg::Transfer {
-                recipient: proposal.submitter.to_string(),
-                amount: proposal.deposit_amount,
-            })?,
-            funds: vec![],
-        }));
-
     Ok(response)
 }
 
 /// ## Description
-/// Executes a successful proposal.
+/// Removes the sender's voting power delegation. If `proposal_id` is set, only the delegation for
+/// that proposal is removed and the general delegation applies to it again.
 /// Returns [`ContractError`] on failure, otherwise returns a [`Response`] with the specified
 /// attributes if the operation was successful.
 /// ## Params
@@ -400,53 +907,741 @@ pub fn end_proposal(
 ///
 /// * **env** is an object of type [`Env`].
 ///
-/// * **_info** is an object of type [`MessageInfo`].
+/// * **info** is an object of type [`MessageInfo`].
 ///
-/// * **proposal_id** is a parameter of type `u64`. This is the proposal identifier.
-pub fn execute_proposal(
+/// * **proposal_id** is an [`Option`] of type `u64`. This is the proposal the delegation applies to.
+pub fn undelegate(
     deps: DepsMut,
     env: Env,
-    _info: MessageInfo,
-    proposal_id: u64,
+    info: MessageInfo,
+    proposal_id: Option<u64>,
 ) -> Result<Response, ContractError> {
-    let mut proposal = PROPOSALS.load(deps.storage, U64Key::new(proposal_id))?;
-
-    if proposal.status != ProposalStatus::Passed {
-        return Err(ContractError::ProposalNotPassed {});
-    }
+    let mut response = Response::new()
+        .add_attribute("action", "undelegate")
+        .add_attribute("delegator", &info.sender);
+
+    match proposal_id {
+        Some(proposal_id) => {
+            check_proposal_delegation(deps.as_ref(), &env, &info.sender, proposal_id)?;
+
+            let delegatee = PROPOSAL_DELEGATIONS
+                .may_load(deps.storage, (U64Key::new(proposal_id), &info.sender))?
+                .ok_or(ContractError::DelegationNotFound {})?;
+
+            PROPOSAL_DELEGATIONS.remove(deps.storage, (U64Key::new(proposal_id), &info.sender));
+            PROPOSAL_DELEGATORS.remove(
+                deps.storage,
+                (U64Key::new(proposal_id), &delegatee, &info.sender),
+            );
+
+            response = response
+                .add_attribute("delegatee", delegatee)
+                .add_attribute("proposal_id", proposal_id.to_string());
+        }
+        None => {
+            let delegatee = DELEGATIONS
+                .may_load(deps.storage, &info.sender)?
+                .ok_or(ContractError::DelegationNotFound {})?;
 
-    let config = CONFIG.load(deps.storage)?;
+            check_delegation(deps.as_ref(), &env, &info.sender)?;
 
-    if env.block.height < (proposal.end_block + config.proposal_effective_delay) {
-        return Err(ContractError::ProposalDelayNotEnded {});
-    }
+            DELEGATIONS.remove(deps.storage, &info.sender);
+            DELEGATORS.remove(deps.storage, (&delegatee, &info.sender));
 
-    if env.block.height
-        > (proposal.end_block + config.proposal_effective_delay + config.proposal_expiration_period)
-    {
-        return Err(ContractError::ExecuteProposalExpired {});
+            response = response.add_attribute("delegatee", delegatee);
+        }
     }
 
-    proposal.status = ProposalStatus::Executed;
-
-    PROPOSALS.save(deps.storage, U64Key::new(proposal_id), &proposal)?;
-
-    let messages = match proposal.messages {
-        Some(mut messages) => {
-            messages.sort_by(|a, b| a.order.cmp(&b.order));
-            messages.into_iter().map(|message| message.msg).collect()
-        }
-        None => vec![],
-    };
+    Ok(response)
+}
 
-    Ok(Response::new()
-        .add_attribute("action", "execute_proposal")
+/// ## Description
+/// Checks that a delegation for a specific proposal can be changed. The proposal must be in its
+/// voting period and the delegator's voting power must not be used by a delegatee yet.
+/// ## Params
+/// * **deps** is an object of type [`Deps`].
+///
+/// * **env** is an object of type [`Env`].
+///
+/// * **delegator** is an object of type [`Addr`]. This is the address that changes its delegation.
+///
+/// * **proposal_id** is a parameter of type `u64`. This is the proposal identifier.
+fn check_proposal_delegation(
+    deps: Deps,
+    env: &Env,
+    delegator: &Addr,
+    proposal_id: u64,
+) -> Result<(), ContractError> {
+    let proposal = proposals().load(deps.storage, U64Key::new(proposal_id))?;
+
+    if proposal.status != ProposalStatus::Active {
+        return Err(ContractError::ProposalNotActive {});
+    }
+
+    if env.block.time.seconds() > proposal.end_time {
+        return Err(ContractError::VotingPeriodEnded {});
+    }
+
+    if DELEGATED_VOTES.has(deps.storage, (U64Key::new(proposal_id), delegator)) {
+        return Err(ContractError::DelegatedPowerAlreadyUsed {});
+    }
+
+    Ok(())
+}
+
+/// ## Description
+/// Checks that the general delegation of an address can be changed. The delegator's voting power
+/// must not be used by a delegatee on any proposal that is still in its voting period.
+/// ## Params
+/// * **deps** is an object of type [`Deps`].
+///
+/// * **env** is an object of type [`Env`].
+///
+/// * **delegator** is an object of type [`Addr`]. This is the address that changes its delegation.
+fn check_delegation(deps: Deps, env: &Env, delegator: &Addr) -> Result<(), ContractError> {
+    let active_proposals = proposals()
+        .idx
+        .status
+        .prefix(ProposalStatus::Active.to_string().into_bytes())
+        .range(deps.storage, None, None, Order::Ascending);
+
+    for item in active_proposals {
+        let (_, proposal) = item?;
+
+        if proposal.status == ProposalStatus::Active
+            && env.block.time.seconds() <= proposal.end_time
+            && DELEGATED_VOTES.has(
+                deps.storage,
+                (U64Key::new(proposal.proposal_id.u64()), delegator),
+            )
+        {
+            return Err(ContractError::DelegatedPowerAlreadyUsed {});
+        }
+    }
+
+    Ok(())
+}
+
+/// ## Description
+/// Returns a mutable reference to the proposal power accumulated by the specified vote option.
+/// ## Params
+/// * **proposal** is an object of type [`Proposal`]. This is the proposal to get the vote power from.
+///
+/// * **option** is an object of type [`ProposalVoteOption`]. This is the vote option.
+fn vote_option_power<'a>(
+    proposal: &'a mut Proposal,
+    option: &ProposalVoteOption,
+) -> &'a mut Uint128 {
+    match option {
+        ProposalVoteOption::For => &mut proposal.for_power,
+        ProposalVoteOption::Against => &mut proposal.against_power,
+        ProposalVoteOption::Abstain => &mut proposal.abstain_power,
+        ProposalVoteOption::NoWithVeto => &mut proposal.no_with_veto_power,
+    }
+}
+
+/// ## Description
+/// Ends proposal voting and sets the proposal status. If early finalization is enabled, the voting
+/// can be ended before the voting period ends once the voting power that wasn't cast can't change the result.
+/// Returns a [`ContractError`] on failure, otherwise returns a [`Response`] with the specified
+/// attributes if the operation was successful.
+/// ## Params
+/// * **deps** is an object of type [`DepsMut`].
+///
+/// * **env** is an object of type [`Env`].
+///
+/// * **_info** is an object of type [`MessageInfo`].
+///
+/// * **proposal_id** is a parameter of type `u64`. This is the proposal identifier.
+pub fn end_proposal(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    let mut proposal = proposals().load(deps.storage, U64Key::new(proposal_id))?;
+
+    if proposal.status != ProposalStatus::Active {
+        return Err(ContractError::ProposalNotActive {});
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+
+    // The default rules apply if the proposal type was removed from the registry
+    let rules = config
+        .proposal_type(&proposal.proposal_type)
+        .unwrap_or_else(|| config.default_proposal_type());
+
+    let required_threshold = if proposal.expedited {
+        rules
+            .required_threshold
+            .max(config.expedited_proposal_required_threshold)
+    } else {
+        rules.required_threshold
+    };
+
+    let total_voting_power = calc_total_voting_power_at(deps.as_ref(), &proposal)?;
+
+    let ended_early = env.block.time.seconds() <= proposal.end_time;
+
+    if ended_early
+        && !(config.early_finalization_enabled
+            && is_result_decided(
+                &proposal,
+                total_voting_power,
+                rules.required_quorum,
+                required_threshold,
+                config.proposal_required_veto_threshold,
+            ))
+    {
+        return Err(ContractError::VotingPeriodNotEnded {});
+    }
+
+    let (proposal_quorum, proposal_threshold, proposal_veto_threshold) = calc_vote_ratios(
+        proposal.for_power,
+        proposal.against_power,
+        proposal.abstain_power,
+        proposal.no_with_veto_power,
+        total_voting_power,
+    );
+
+    let quorum_reached = proposal_quorum >= rules.required_quorum;
+    let vetoed =
+        quorum_reached && proposal_veto_threshold > config.proposal_required_veto_threshold;
+
+    let mut passed = quorum_reached && !vetoed && proposal_threshold > required_threshold;
+
+    // An expedited proposal that reached quorum and wasn't vetoed but didn't reach the expedited
+    // threshold is converted to a regular one. Its votes are kept and the voting period is extended
+    // to the regular one, counted from the proposal start. Any other failure ends it as usual
+    if proposal.expedited && quorum_reached && !vetoed && !passed {
+        proposal.expedited = false;
+        proposal.converted_from_expedited = true;
+        proposal.end_time = proposal.start_time + rules.voting_period;
+        proposal.effective_time = proposal.end_time + rules.effective_delay;
+        proposal.expiration_time = proposal.effective_time + config.proposal_expiration_period;
+
+        if env.block.time.seconds() <= proposal.end_time {
+            proposals().save(deps.storage, U64Key::new(proposal_id), &proposal)?;
+
+            return Ok(Response::new().add_attributes(vec![
+                attr("action", "end_proposal"),
+                attr("proposal_id", proposal_id.to_string()),
+                attr("proposal_result", "converted_to_regular"),
+                attr("proposal_end_time", proposal.end_time.to_string()),
+            ]));
+        }
+
+        // The regular voting period has already ended as well
+        passed = quorum_reached && !vetoed && proposal_threshold > rules.required_threshold;
+    }
+
+    // Determine the proposal result
+    proposal.status = if passed {
+        ProposalStatus::Passed
+    } else {
+        ProposalStatus::Rejected
+    };
+
+    // The effective delay of a proposal that was ended early is counted from the moment it was ended
+    if ended_early {
+        proposal.end_time = env.block.time.seconds();
+        proposal.effective_time = proposal.end_time + rules.effective_delay;
+        proposal.expiration_time = proposal.effective_time + config.proposal_expiration_period;
+    }
+
+    proposals().save(deps.storage, U64Key::new(proposal_id), &proposal)?;
+
+    let slash_ratio = if !quorum_reached {
+        config.quorum_failure_deposit_slash
+    } else if vetoed {
+        config.veto_deposit_slash
+    } else {
+        Decimal::zero()
+    };
+
+    let (deposit_msgs, slashed_amount) = settle_deposit(&config, &proposal, slash_ratio)?;
+
+    let response = Response::new()
+        .add_attributes(vec![
+            attr("action", "end_proposal"),
+            attr("proposal_id", proposal_id.to_string()),
+            attr("proposal_result", proposal.status.to_string()),
+            attr("proposal_vetoed", vetoed.to_string()),
+            attr("ended_early", ended_early.to_string()),
+            attr("slashed_deposit", slashed_amount),
+        ])
+        .add_messages(deposit_msgs);
+
+    Ok(response)
+}
+
+/// ## Description
+/// Calculates the quorum, threshold and veto threshold reached by the specified votes.
+/// Abstain votes count toward the quorum but are ignored by the threshold.
+/// ## Params
+/// * **for_votes** is an object of type [`Uint128`]. This is the `For` voting power.
+///
+/// * **against_votes** is an object of type [`Uint128`]. This is the `Against` voting power.
+///
+/// * **abstain_votes** is an object of type [`Uint128`]. This is the `Abstain` voting power.
+///
+/// * **veto_votes** is an object of type [`Uint128`]. This is the `NoWithVeto` voting power.
+///
+/// * **total_voting_power** is an object of type [`Uint128`]. This is the total voting power at the proposal snapshot.
+fn calc_vote_ratios(
+    for_votes: Uint128,
+    against_votes: Uint128,
+    abstain_votes: Uint128,
+    veto_votes: Uint128,
+    total_voting_power: Uint128,
+) -> (Decimal, Decimal, Decimal) {
+    let total_votes = for_votes + against_votes + abstain_votes + veto_votes;
+    let threshold_votes = total_votes - abstain_votes;
+
+    let mut quorum: Decimal = Decimal::zero();
+    let mut threshold: Decimal = Decimal::zero();
+    let mut veto_threshold: Decimal = Decimal::zero();
+
+    if !total_voting_power.is_zero() {
+        quorum = Decimal::from_ratio(total_votes, total_voting_power);
+    }
+
+    if !threshold_votes.is_zero() {
+        threshold = Decimal::from_ratio(for_votes, threshold_votes);
+    }
+
+    if !total_votes.is_zero() {
+        veto_threshold = Decimal::from_ratio(veto_votes, total_votes);
+    }
+
+    (quorum, threshold, veto_threshold)
+}
+
+/// ## Description
+/// Returns whether the voting power that wasn't cast on a proposal can no longer change its result.
+/// A passing proposal is decided if it still passes when all the remaining power votes `NoWithVeto`.
+/// A failing proposal is decided if it still fails when all the remaining power votes `For`.
+/// ## Params
+/// * **proposal** is an object of type [`Proposal`].
+///
+/// * **total_voting_power** is an object of type [`Uint128`]. This is the total voting power at the proposal snapshot.
+///
+/// * **required_quorum** is an object of type [`Decimal`]. This is the quorum the proposal must reach.
+///
+/// * **required_threshold** is an object of type [`Decimal`]. This is the threshold the proposal must exceed.
+///
+/// * **required_veto_threshold** is an object of type [`Decimal`]. This is the veto threshold above which the proposal is vetoed.
+fn is_result_decided(
+    proposal: &Proposal,
+    total_voting_power: Uint128,
+    required_quorum: Decimal,
+    required_threshold: Decimal,
+    required_veto_threshold: Decimal,
+) -> bool {
+    let cast_power = proposal.for_power
+        + proposal.against_power
+        + proposal.abstain_power
+        + proposal.no_with_veto_power;
+    let uncast_power = total_voting_power.saturating_sub(cast_power);
+
+    let passes = |for_votes: Uint128, veto_votes: Uint128| {
+        let (quorum, threshold, veto_threshold) = calc_vote_ratios(
+            for_votes,
+            proposal.against_power,
+            proposal.abstain_power,
+            veto_votes,
+            total_voting_power,
+        );
+
+        quorum >= required_quorum
+            && veto_threshold <= required_veto_threshold
+            && threshold > required_threshold
+    };
+
+    if passes(proposal.for_power, proposal.no_with_veto_power) {
+        passes(
+            proposal.for_power,
+            proposal.no_with_veto_power + uncast_power,
+        )
+    } else {
+        !passes(
+            proposal.for_power + uncast_power,
+            proposal.no_with_veto_power,
+        )
+    }
+}
+
+/// ## Description
+/// Returns the messages that send a proposal deposit back to its submitter after slashing the specified share of it,
+/// as well as the slashed amount. The slashed share is sent to the slashed deposit receiver or burned if the receiver is not set.
+/// ## Params
+/// * **config** is an object of type [`Config`]. This is the Assembly configuration.
+///
+/// * **proposal** is an object of type [`Proposal`]. This is the proposal whose deposit is settled.
+///
+/// * **slash_ratio** is an object of type [`Decimal`]. This is the share of the deposit to slash.
+fn settle_deposit(
+    config: &Config,
+    proposal: &Proposal,
+    slash_ratio: Decimal,
+) -> StdResult<(Vec<CosmosMsg>, Uint128)> {
+    let slashed_amount = proposal.deposit_amount * slash_ratio;
+    let returned_amount = proposal.deposit_amount.checked_sub(slashed_amount)?;
+
+    let mut cw20_msgs = vec![];
+
+    if !returned_amount.is_zero() {
+        cw20_msgs.push(Cw20ExecuteMsg::Transfer {
+            recipient: proposal.submitter.to_string(),
+            amount: returned_amount,
+        });
+    }
+
+    if !slashed_amount.is_zero() {
+        cw20_msgs.push(match &config.slashed_deposit_receiver {
+            Some(receiver) => Cw20ExecuteMsg::Transfer {
+                recipient: receiver.to_string(),
+                amount: slashed_amount,
+            },
+            None => Cw20ExecuteMsg::Burn {
+                amount: slashed_amount,
+            },
+        });
+    }
+
+    let messages = cw20_msgs
+        .iter()
+        .map(|msg| {
+            Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: config.xastro_token_addr.to_string(),
+                msg: to_binary(msg)?,
+                funds: vec![],
+            }))
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok((messages, slashed_amount))
+}
+
+/// ## Description
+/// Executes a successful proposal. If a Timelock is set, the proposal messages are queued in the
+/// Timelock instead of being dispatched by the Assembly and the proposal is marked as
+/// [`ProposalStatus::Queued`].
+/// Returns [`ContractError`] on failure, otherwise returns a [`Response`] with the specified
+/// attributes if the operation was successful.
+/// ## Params
+/// * **deps** is an object of type [`DepsMut`].
+///
+/// * **env** is an object of type [`Env`].
+///
+/// * **_info** is an object of type [`MessageInfo`].
+///
+/// * **proposal_id** is a parameter of type `u64`. This is the proposal identifier.
+pub fn execute_proposal(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    let mut proposal = proposals().load(deps.storage, U64Key::new(proposal_id))?;
+
+    if proposal.status != ProposalStatus::Passed {
+        return Err(ContractError::ProposalNotPassed {});
+    }
+
+    if env.block.time.seconds() < proposal.effective_time {
+        return Err(ContractError::ProposalDelayNotEnded {});
+    }
+
+    if env.block.time.seconds() > proposal.expiration_time {
+        return Err(ContractError::ExecuteProposalExpired {});
+    }
+
+    proposal.status = ProposalStatus::Executed;
+
+    let mut response = Response::new()
+        .add_attribute("action", "execute_proposal")
+        .add_attribute("proposal_id", proposal_id.to_string());
+
+    let config = CONFIG.load(deps.storage)?;
+
+    if let Some(timelock_addr) = config.timelock_addr {
+        if sorted_messages(&proposal)
+            .iter()
+            .any(|message| message.ibc_channel.is_some())
+        {
+            return Err(ContractError::RemoteMessagesWithTimelock {});
+        }
+
+        let messages: Vec<CosmosMsg> = sorted_messages(&proposal)
+            .into_iter()
+            .map(|message| message.msg)
+            .collect();
+
+        if !messages.is_empty() {
+            proposal.status = ProposalStatus::Queued;
+
+            response = response
+                .add_attribute("timelock_addr", timelock_addr.to_string())
+                .add_message(WasmMsg::Execute {
+                    contract_addr: timelock_addr.to_string(),
+                    msg: to_binary(&TimelockExecuteMsg::QueueTransaction {
+                        proposal_id,
+                        messages,
+                    })?,
+                    funds: vec![],
+                });
+        }
+
+        proposals().save(deps.storage, U64Key::new(proposal_id), &proposal)?;
+
+        return Ok(response);
+    }
+
+    proposals().save(deps.storage, U64Key::new(proposal_id), &proposal)?;
+
+    // The remaining messages are dispatched one by one from the reply of the previous one
+    if let Some(message) = sorted_messages(&proposal).into_iter().next() {
+        response = response.add_submessage(proposal_message_submsg(&env, proposal_id, message)?);
+    }
+
+    Ok(response)
+}
+
+/// ## Description
+/// Returns the submessage that executes a proposal message. A message with an IBC channel is
+/// sent to the satellite over that channel, any other message is dispatched by the Assembly.
+/// ## Params
+/// * **env** is an object of type [`Env`].
+///
+/// * **proposal_id** is a parameter of type `u64`. This is the proposal identifier.
+///
+/// * **message** is an object of type [`ProposalMessage`].
+fn proposal_message_submsg(
+    env: &Env,
+    proposal_id: u64,
+    message: ProposalMessage,
+) -> StdResult<SubMsg> {
+    let msg = match message.ibc_channel {
+        Some(channel_id) => CosmosMsg::Ibc(IbcMsg::SendPacket {
+            channel_id,
+            data: to_binary(&SatellitePacket::ExecuteProposalMessage {
+                proposal_id,
+                order: message.order,
+                msg: message.msg,
+            })?,
+            timeout: env.block.time.plus_seconds(IBC_PACKET_TIMEOUT).into(),
+        }),
+        None => message.msg,
+    };
+
+    Ok(SubMsg::reply_always(msg, proposal_id))
+}
+
+/// ## Description
+/// Records the execution outcome of a proposal message. If the message succeeded, the next
+/// message of the proposal is dispatched. If it failed, the proposal status is set to
+/// [`ProposalStatus::FailedExecution`] and the remaining messages are not executed.
+/// A message sent to a satellite succeeds once its packet is sent, and its acknowledgement
+/// status is tracked in the proposal until the satellite executes it.
+/// ## Params
+/// * **deps** is an object of type [`DepsMut`].
+///
+/// * **env** is an object of type [`Env`].
+///
+/// * **proposal_id** is a parameter of type `u64`. This is the proposal identifier.
+///
+/// * **result** is an object of type [`ContractResult<SubMsgExecutionResponse>`]. This is the message result.
+fn process_proposal_message_reply(
+    deps: DepsMut,
+    env: Env,
+    proposal_id: u64,
+    result: ContractResult<SubMsgExecutionResponse>,
+) -> Result<Response, ContractError> {
+    let mut proposal = proposals().load(deps.storage, U64Key::new(proposal_id))?;
+    let messages = sorted_messages(&proposal);
+
+    let (order, ibc_channel) = messages
+        .get(proposal.execution_results.len())
+        .map(|message| (message.order, message.ibc_channel.clone()))
+        .ok_or_else(|| StdError::generic_err("All proposal messages are already executed"))?;
+
+    let mut response = Response::new()
+        .add_attribute("action", "execute_proposal_message")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("order", order.to_string());
+
+    match result {
+        ContractResult::Ok(_) => {
+            proposal
+                .execution_results
+                .push(ProposalMessageResult { order, error: None });
+
+            if let Some(channel) = ibc_channel {
+                proposal
+                    .ibc_message_statuses
+                    .push(ProposalIbcMessageStatus {
+                        order,
+                        channel,
+                        status: IbcMessageStatus::Pending,
+                        error: None,
+                    });
+            }
+
+            if let Some(message) = messages.into_iter().nth(proposal.execution_results.len()) {
+                response =
+                    response.add_submessage(proposal_message_submsg(&env, proposal_id, message)?);
+            }
+
+            response = response.add_attribute("result", "success");
+        }
+        ContractResult::Err(error) => {
+            proposal.execution_results.push(ProposalMessageResult {
+                order,
+                error: Some(error.clone()),
+            });
+            proposal.status = ProposalStatus::FailedExecution;
+
+            response = response
+                .add_attribute("result", "failure")
+                .add_attribute("error", error);
+        }
+    }
+
+    proposals().save(deps.storage, U64Key::new(proposal_id), &proposal)?;
+
+    Ok(response)
+}
+
+/// ## Description
+/// Returns the proposal messages sorted by their execution order.
+/// ## Params
+/// * **proposal** is an object of type [`Proposal`].
+fn sorted_messages(proposal: &Proposal) -> Vec<ProposalMessage> {
+    let mut messages = proposal.messages.clone().unwrap_or_default();
+    messages.sort_by_key(|message| message.order);
+    messages
+}
+
+/// ## Description
+/// Checks proposal messages by executing them on behalf of the Assembly. The messages are
+/// dispatched in their execution order and the last one is sent as a submessage. Once it succeeds,
+/// [`reply`] returns [`ContractError::MessagesCheckPassed`] so that the whole transaction is reverted.
+/// If any of the messages fails, the transaction is reverted with its error.
+/// Messages sent to satellites can't be checked on this chain and are skipped.
+/// ## Params
+/// * **messages** is a [`Vec`] of type [`ProposalMessage`]. These are the messages to check.
+pub fn check_messages(mut messages: Vec<ProposalMessage>) -> Result<Response, ContractError> {
+    messages.sort_by_key(|message| message.order);
+
+    let mut messages: Vec<CosmosMsg> = messages
+        .into_iter()
+        .filter(|message| message.ibc_channel.is_none())
+        .map(|message| message.msg)
+        .collect();
+
+    let last_message = match messages.pop() {
+        Some(message) => message,
+        None => return Err(ContractError::MessagesCheckPassed {}),
+    };
+
+    Ok(Response::new()
+        .add_attribute("action", "check_messages")
+        .add_messages(messages)
+        .add_submessage(SubMsg::reply_on_success(
+            last_message,
+            CHECK_MESSAGES_REPLY_ID,
+        )))
+}
+
+/// ## Description
+/// Cancels a proposal. The submitter can cancel an active proposal as long as no votes were cast on it.
+/// The guardian can cancel an active, passed or queued proposal at any time before it is executed.
+/// The Timelock transaction of a queued proposal is cancelled as well.
+/// The deposit of an active proposal is returned to the submitter.
+/// Returns [`ContractError`] on failure, otherwise returns a [`Response`] with the specified
+/// attributes if the operation was successful.
+/// ## Params
+/// * **deps** is an object of type [`DepsMut`].
+///
+/// * **_env** is an object of type [`Env`].
+///
+/// * **info** is an object of type [`MessageInfo`].
+///
+/// * **proposal_id** is a parameter of type `u64`. This is the proposal identifier.
+pub fn cancel_proposal(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let mut proposal = proposals().load(deps.storage, U64Key::new(proposal_id))?;
+
+    if config.guardian.as_ref() == Some(&info.sender) {
+        if proposal.status != ProposalStatus::Active
+            && proposal.status != ProposalStatus::Passed
+            && proposal.status != ProposalStatus::Queued
+        {
+            return Err(ContractError::ProposalNotCancellable {});
+        }
+    } else if info.sender == proposal.submitter {
+        if proposal.status != ProposalStatus::Active {
+            return Err(ContractError::ProposalNotActive {});
+        }
+
+        if !(proposal.for_power
+            + proposal.against_power
+            + proposal.abstain_power
+            + proposal.no_with_veto_power)
+            .is_zero()
+        {
+            return Err(ContractError::ProposalHasVotes {});
+        }
+    } else {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // The deposit of a passed proposal was already returned in end_proposal
+    let deposit_msgs = if proposal.status == ProposalStatus::Active {
+        settle_deposit(&config, &proposal, Decimal::zero())?.0
+    } else {
+        vec![]
+    };
+
+    let mut response = Response::new()
+        .add_attribute("action", "cancel_proposal")
         .add_attribute("proposal_id", proposal_id.to_string())
-        .add_messages(messages))
+        .add_attribute("cancelled_by", info.sender)
+        .add_messages(deposit_msgs);
+
+    if proposal.status == ProposalStatus::Queued {
+        let (timelock_addr, transaction) =
+            query_timelock_transaction(deps.as_ref(), &config, proposal_id)?;
+
+        response = response.add_message(WasmMsg::Execute {
+            contract_addr: timelock_addr.to_string(),
+            msg: to_binary(&TimelockExecuteMsg::CancelTransaction {
+                transaction_id: transaction.transaction_id,
+            })?,
+            funds: vec![],
+        });
+    }
+
+    proposal.status = ProposalStatus::Cancelled;
+
+    proposals().save(deps.storage, U64Key::new(proposal_id), &proposal)?;
+
+    Ok(response)
 }
 
 /// ## Description
-/// Removes an expired or rejected proposal from the general proposal list.
+/// Updates the status of a proposal whose messages are queued in the Timelock according to its
+/// Timelock transaction. The proposal is marked as executed or cancelled once the transaction was
+/// executed or cancelled, and as expired once the Timelock grace period after the transaction ETA ended.
+/// Anyone can update a proposal.
 /// Returns [`ContractError`] on failure, otherwise returns a [`Response`] with the specified
 /// attributes if the operation was successful.
 /// ## Params
@@ -457,31 +1652,257 @@ pub fn execute_proposal(
 /// * **_info** is an object of type [`MessageInfo`].
 ///
 /// * **proposal_id** is a parameter of type `u64`. This is the proposal identifier.
-pub fn remove_completed_proposal(
+pub fn sync_queued_proposal(
     deps: DepsMut,
     env: Env,
     _info: MessageInfo,
     proposal_id: u64,
 ) -> Result<Response, ContractError> {
+    let mut proposal = proposals().load(deps.storage, U64Key::new(proposal_id))?;
+
+    if proposal.status != ProposalStatus::Queued {
+        return Err(ContractError::ProposalNotQueued {});
+    }
+
     let config = CONFIG.load(deps.storage)?;
 
-    let mut proposal = PROPOSALS.load(deps.storage, U64Key::new(proposal_id))?;
+    let (timelock_addr, transaction) =
+        query_timelock_transaction(deps.as_ref(), &config, proposal_id)?;
+
+    proposal.status = match transaction.status {
+        TransactionStatus::Executed => ProposalStatus::Executed,
+        TransactionStatus::Cancelled => ProposalStatus::Cancelled,
+        TransactionStatus::Queued => {
+            let timelock_config: TimelockConfig = deps
+                .querier
+                .query_wasm_smart(&timelock_addr, &TimelockQueryMsg::Config {})?;
+
+            if env.block.time.seconds() <= transaction.eta + timelock_config.grace_period {
+                return Err(ContractError::TimelockTransactionQueued {});
+            }
+
+            ProposalStatus::Expired
+        }
+    };
+
+    proposals().save(deps.storage, U64Key::new(proposal_id), &proposal)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "sync_queued_proposal")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("proposal_status", proposal.status.to_string()))
+}
+
+/// ## Description
+/// Returns the Timelock address and the latest Timelock transaction queued for the specified proposal.
+/// Returns a [`ContractError`] if the Timelock is not set or the transaction doesn't exist.
+/// ## Params
+/// * **deps** is an object of type [`Deps`].
+///
+/// * **config** is an object of type [`Config`].
+///
+/// * **proposal_id** is a parameter of type `u64`. This is the proposal identifier.
+fn query_timelock_transaction(
+    deps: Deps,
+    config: &Config,
+    proposal_id: u64,
+) -> Result<(Addr, Transaction), ContractError> {
+    let timelock_addr = config
+        .timelock_addr
+        .clone()
+        .ok_or(ContractError::TimelockNotSet {})?;
+
+    let transaction: Transaction = deps.querier.query_wasm_smart(
+        &timelock_addr,
+        &TimelockQueryMsg::ProposalTransaction { proposal_id },
+    )?;
+
+    Ok((timelock_addr, transaction))
+}
+
+/// ## Description
+/// Removes an expired, rejected or cancelled proposal from the general proposal list.
+/// A summary of the proposal is kept in [`ARCHIVED_PROPOSALS`]. The votes and delegations stored
+/// for the proposal are deleted in batches of [`PROPOSAL_CLEANUP_LIMIT`] entries; calling this again
+/// for an archived proposal continues the cleanup.
+/// Returns [`ContractError`] on failure, otherwise returns a [`Response`] with the specified
+/// attributes if the operation was successful.
+/// ## Params
+/// * **deps** is an object of type [`DepsMut`].
+///
+/// * **env** is an object of type [`Env`].
+///
+/// * **_info** is an object of type [`MessageInfo`].
+///
+/// * **proposal_id** is a parameter of type `u64`. This is the proposal identifier.
+pub fn remove_completed_proposal(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    if !ARCHIVED_PROPOSALS.has(deps.storage, U64Key::new(proposal_id)) {
+        archive_proposal(deps.storage, &env, proposal_id)?;
+    }
+
+    let cleanup_finished =
+        remove_proposal_votes(deps.storage, proposal_id, PROPOSAL_CLEANUP_LIMIT)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_completed_proposal")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("cleanup_finished", cleanup_finished.to_string()))
+}
 
-    if env.block.height
-        > (proposal.end_block + config.proposal_effective_delay + config.proposal_expiration_period)
+/// ## Description
+/// Moves a completed proposal from the general proposal list to [`ARCHIVED_PROPOSALS`].
+/// ## Params
+/// * **storage** is an object of type [`Storage`].
+///
+/// * **env** is an object of type [`Env`].
+///
+/// * **proposal_id** is a parameter of type `u64`. This is the proposal identifier.
+fn archive_proposal(
+    storage: &mut dyn Storage,
+    env: &Env,
+    proposal_id: u64,
+) -> Result<(), ContractError> {
+    let mut proposal = proposals().load(storage, U64Key::new(proposal_id))?;
+
+    let expired = env.block.time.seconds() > proposal.expiration_time;
+
+    if !expired
+        && proposal.status != ProposalStatus::Expired
+        && proposal.status != ProposalStatus::Rejected
+        && proposal.status != ProposalStatus::Cancelled
+    {
+        return Err(ContractError::ProposalNotCompleted {});
+    }
+
+    // Proposals that were still pending when they expired are archived as expired
+    if expired
+        && (proposal.status == ProposalStatus::Active || proposal.status == ProposalStatus::Passed)
     {
         proposal.status = ProposalStatus::Expired;
     }
 
-    if proposal.status != ProposalStatus::Expired && proposal.status != ProposalStatus::Rejected {
-        return Err(ContractError::ProposalNotCompleted {});
+    proposals().remove(storage, U64Key::new(proposal_id))?;
+
+    ARCHIVED_PROPOSALS.save(
+        storage,
+        U64Key::new(proposal_id),
+        &ArchivedProposal {
+            proposal_id: proposal.proposal_id,
+            submitter: proposal.submitter,
+            title: proposal.title,
+            status: proposal.status,
+            for_power: proposal.for_power,
+            against_power: proposal.against_power,
+            abstain_power: proposal.abstain_power,
+            no_with_veto_power: proposal.no_with_veto_power,
+            start_time: proposal.start_time,
+            end_time: proposal.end_time,
+            archived_time: env.block.time.seconds(),
+        },
+    )?;
+
+    Ok(())
+}
+
+/// ## Description
+/// Deletes up to `limit` vote and delegation entries stored for a removed proposal, along with
+/// the entries that depend on them. [`USER_VOTES`] is kept, so the voting history of every user
+/// stays available through the `user_vote` and `user_votes` queries.
+/// Returns `true` once no entries are left.
+/// ## Params
+/// * **storage** is an object of type [`Storage`].
+///
+/// * **proposal_id** is a parameter of type `u64`. This is the proposal identifier.
+///
+/// * **limit** is a parameter of type `usize`. This is the maximum number of entries to delete.
+pub(crate) fn remove_proposal_votes(
+    storage: &mut dyn Storage,
+    proposal_id: u64,
+    limit: usize,
+) -> StdResult<bool> {
+    let mut remaining = limit;
+
+    let votes = PROPOSAL_VOTES
+        .prefix(U64Key::new(proposal_id))
+        .range(storage, None, None, Order::Ascending)
+        .take(remaining)
+        .map(|item| {
+            let (voter, vote) = item?;
+            Ok((Addr::unchecked(String::from_utf8(voter)?), vote))
+        })
+        .collect::<StdResult<Vec<(Addr, ProposalVote)>>>()?;
+
+    remaining -= votes.len();
+
+    for (voter, vote) in votes {
+        PROPOSAL_VOTES.remove(storage, (U64Key::new(proposal_id), &voter));
+        PROPOSAL_VOTERS.remove(
+            storage,
+            (U64Key::new(proposal_id), vote.option.to_string(), &voter),
+        );
     }
 
-    PROPOSALS.remove(deps.storage, U64Key::new(proposal_id));
+    let delegated_votes = DELEGATED_VOTES
+        .prefix(U64Key::new(proposal_id))
+        .range(storage, None, None, Order::Ascending)
+        .take(remaining)
+        .map(|item| {
+            let (delegator, delegatee) = item?;
+            Ok((Addr::unchecked(String::from_utf8(delegator)?), delegatee))
+        })
+        .collect::<StdResult<Vec<(Addr, Addr)>>>()?;
 
-    Ok(Response::new()
-        .add_attribute("action", "remove_completed_proposal")
-        .add_attribute("proposal_id", proposal_id.to_string()))
+    remaining -= delegated_votes.len();
+
+    for (delegator, delegatee) in delegated_votes {
+        DELEGATED_VOTES.remove(storage, (U64Key::new(proposal_id), &delegator));
+        DELEGATED_VOTE_POWERS.remove(storage, (U64Key::new(proposal_id), &delegatee, &delegator));
+    }
+
+    let delegations = PROPOSAL_DELEGATIONS
+        .prefix(U64Key::new(proposal_id))
+        .range(storage, None, None, Order::Ascending)
+        .take(remaining)
+        .map(|item| {
+            let (delegator, delegatee) = item?;
+            Ok((Addr::unchecked(String::from_utf8(delegator)?), delegatee))
+        })
+        .collect::<StdResult<Vec<(Addr, Addr)>>>()?;
+
+    for (delegator, delegatee) in delegations {
+        PROPOSAL_DELEGATIONS.remove(storage, (U64Key::new(proposal_id), &delegator));
+        PROPOSAL_DELEGATORS.remove(storage, (U64Key::new(proposal_id), &delegatee, &delegator));
+    }
+
+    // The limit may be reached exactly when the last entry is deleted, so check what is left
+    let entries_left = PROPOSAL_VOTES
+        .prefix(U64Key::new(proposal_id))
+        .keys(storage, None, None, Order::Ascending)
+        .next()
+        .is_some()
+        || DELEGATED_VOTES
+            .prefix(U64Key::new(proposal_id))
+            .keys(storage, None, None, Order::Ascending)
+            .next()
+            .is_some()
+        || PROPOSAL_DELEGATIONS
+            .prefix(U64Key::new(proposal_id))
+            .keys(storage, None, None, Order::Ascending)
+            .next()
+            .is_some();
+
+    if entries_left {
+        return Ok(false);
+    }
+
+    LEGACY_VOTERS.remove(storage, U64Key::new(proposal_id));
+
+    Ok(true)
 }
 
 /// ## Description
@@ -504,8 +1925,9 @@ pub fn update_config(
 ) -> Result<Response, ContractError> {
     let mut config = CONFIG.load(deps.storage)?;
 
-    // Only the Assembly is allowed to update its own parameters (through a successful proposal)
-    if info.sender != env.contract.address {
+    // Only the Assembly is allowed to update its own parameters (through a successful proposal).
+    // If a Timelock is set, proposal messages are executed by the Timelock on behalf of the Assembly
+    if info.sender != env.contract.address && Some(&info.sender) != config.timelock_addr.as_ref() {
         return Err(ContractError::Unauthorized {});
     }
 
@@ -537,12 +1959,79 @@ pub fn update_config(
         config.proposal_required_deposit = Uint128::from(proposal_required_deposit);
     }
 
-    if let Some(proposal_required_quorum) = updated_config.proposal_required_quorum {
-        config.proposal_required_quorum = Decimal::from_str(&proposal_required_quorum)?;
+    if let Some(proposal_required_quorum) = updated_config.proposal_required_quorum {
+        config.proposal_required_quorum = Decimal::from_str(&proposal_required_quorum)?;
+    }
+
+    if let Some(proposal_required_threshold) = updated_config.proposal_required_threshold {
+        config.proposal_required_threshold = Decimal::from_str(&proposal_required_threshold)?;
+    }
+
+    if let Some(proposal_required_veto_threshold) = updated_config.proposal_required_veto_threshold
+    {
+        config.proposal_required_veto_threshold =
+            Decimal::from_str(&proposal_required_veto_threshold)?;
+    }
+
+    if let Some(expedited_proposal_required_deposit) =
+        updated_config.expedited_proposal_required_deposit
+    {
+        config.expedited_proposal_required_deposit =
+            Uint128::from(expedited_proposal_required_deposit);
+    }
+
+    if let Some(expedited_proposal_voting_period) = updated_config.expedited_proposal_voting_period
+    {
+        config.expedited_proposal_voting_period = expedited_proposal_voting_period;
+    }
+
+    if let Some(expedited_proposal_required_threshold) =
+        updated_config.expedited_proposal_required_threshold
+    {
+        config.expedited_proposal_required_threshold =
+            Decimal::from_str(&expedited_proposal_required_threshold)?;
+    }
+
+    if let Some(early_finalization_enabled) = updated_config.early_finalization_enabled {
+        config.early_finalization_enabled = early_finalization_enabled;
+    }
+
+    if let Some(proposal_required_vxastro_power) = updated_config.proposal_required_vxastro_power {
+        config.proposal_required_vxastro_power = if proposal_required_vxastro_power == 0 {
+            None
+        } else {
+            Some(Uint128::from(proposal_required_vxastro_power))
+        };
+    }
+
+    if let Some(quorum_failure_deposit_slash) = updated_config.quorum_failure_deposit_slash {
+        config.quorum_failure_deposit_slash = Decimal::from_str(&quorum_failure_deposit_slash)?;
+    }
+
+    if let Some(veto_deposit_slash) = updated_config.veto_deposit_slash {
+        config.veto_deposit_slash = Decimal::from_str(&veto_deposit_slash)?;
+    }
+
+    if let Some(slashed_deposit_receiver) = updated_config.slashed_deposit_receiver {
+        config.slashed_deposit_receiver =
+            Some(addr_validate_to_lower(deps.api, &slashed_deposit_receiver)?);
+    }
+
+    if let Some(guardian) = updated_config.guardian {
+        config.guardian = Some(addr_validate_to_lower(deps.api, &guardian)?);
+    }
+
+    if let Some(timelock_addr) = updated_config.timelock_addr {
+        config.timelock_addr = Some(addr_validate_to_lower(deps.api, &timelock_addr)?);
+    }
+
+    if let Some(proposal_validation) = updated_config.proposal_validation {
+        config.proposal_validation = proposal_validation;
     }
 
-    if let Some(proposal_required_threshold) = updated_config.proposal_required_threshold {
-        config.proposal_required_threshold = Decimal::from_str(&proposal_required_threshold)?;
+    if let Some(voting_power_sources) = updated_config.voting_power_sources {
+        config.voting_power_sources =
+            validate_voting_power_sources(deps.as_ref(), voting_power_sources)?;
     }
 
     if let Some(whitelist_add) = updated_config.whitelist_add {
@@ -556,6 +2045,35 @@ pub fn update_config(
         );
     }
 
+    if let Some(proposal_types_add) = updated_config.proposal_types_add {
+        for proposal_type in proposal_types_add {
+            config
+                .proposal_types
+                .retain(|t| t.name != proposal_type.name);
+            config.proposal_types.push(proposal_type);
+        }
+    }
+
+    if let Some(proposal_types_remove) = updated_config.proposal_types_remove {
+        config
+            .proposal_types
+            .retain(|t| !proposal_types_remove.contains(&t.name));
+    }
+
+    if let Some(ibc_satellites_add) = updated_config.ibc_satellites_add {
+        for satellite in ibc_satellites_add {
+            if !config.ibc_satellites.contains(&satellite) {
+                config.ibc_satellites.push(satellite);
+            }
+        }
+    }
+
+    if let Some(ibc_satellites_remove) = updated_config.ibc_satellites_remove {
+        config
+            .ibc_satellites
+            .retain(|s| !ibc_satellites_remove.contains(s));
+    }
+
     if let Some(whitelist_remove) = updated_config.whitelist_remove {
         config.whitelisted_links = config
             .whitelisted_links
@@ -575,6 +2093,47 @@ pub fn update_config(
     Ok(Response::new().add_attribute("action", "update_config"))
 }
 
+/// ## Description
+/// Converts the next batch of proposals created before v1.1.0 to the current layout.
+/// Returns a [`Response`] with the specified attributes if the operation was successful.
+/// ## Params
+/// * **deps** is an object of type [`DepsMut`].
+///
+/// * **env** is an object of type [`Env`].
+///
+/// * **limit** is an [`Option`] of type `u32`. This is the maximum number of proposals to convert.
+pub fn continue_proposal_migration(
+    deps: DepsMut,
+    env: Env,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let limit = limit.map_or(PROPOSAL_MIGRATION_LIMIT, u64::from);
+
+    let migration_finished = migrate_proposals(deps.storage, &env, limit)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "migrate_proposals")
+        .add_attribute("migration_finished", migration_finished.to_string()))
+}
+
+/// ## Description
+/// Handles submessage replies. A reply to the last message dispatched by [`check_messages`]
+/// always returns [`ContractError::MessagesCheckPassed`] to revert the check.
+/// Any other reply records the execution outcome of a proposal message.
+/// ## Params
+/// * **deps** is an object of type [`DepsMut`].
+///
+/// * **env** is an object of type [`Env`].
+///
+/// * **msg** is an object of type [`Reply`].
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        CHECK_MESSAGES_REPLY_ID => Err(ContractError::MessagesCheckPassed {}),
+        proposal_id => process_proposal_message_reply(deps, env, proposal_id, msg.result),
+    }
+}
+
 /// ## Description
 /// Expose available contract queries.
 /// ## Params
@@ -587,38 +2146,118 @@ pub fn update_config(
 /// ## Queries
 /// * **QueryMsg::Config {}** Returns core contract settings stored in the [`Config`] structure.
 ///
-/// * **QueryMsg::Proposals { start, limit }** Returns a [`ProposalListResponse`] according to the specified input parameters.
+/// * **QueryMsg::Proposals { start, limit, status, submitter, descending }** Returns a [`ProposalListResponse`] according to the specified input parameters.
 ///
 /// * **QueryMsg::Proposal { proposal_id }** Returns a [`Proposal`] according to the specified `proposal_id`.
 ///
 /// * **QueryMsg::ProposalVotes { proposal_id }** Returns proposal vote counts that are stored in the [`ProposalVotesResponse`] structure.
 ///
+/// * **QueryMsg::ProposalVoters { proposal_id, option, start_after, limit }** Returns the list of voters that chose a specific option on a proposal.
+///
+/// * **QueryMsg::UserVote { proposal_id, user }** Returns the vote cast by a user on a specific proposal.
+///
+/// * **QueryMsg::UserVotes { user, start_after, limit }** Returns the votes cast by a user on all proposals.
+///
 /// * **QueryMsg::UserVotingPower { user, proposal_id }** Returns user voting power for a specific proposal.
 ///
+/// * **QueryMsg::Delegation { user, proposal_id }** Returns the address a user delegated their voting power to.
+///
+/// * **QueryMsg::DelegatedVotingPower { delegatee, proposal_id }** Returns the voting power delegated to an address.
+///
 /// * **QueryMsg::TotalVotingPower { proposal_id }** Returns total voting power for a specific proposal.
+///
+/// * **QueryMsg::IbcChannels {}** Returns the IBC channels opened by satellites.
+///
+/// * **QueryMsg::ArchivedProposals { start_after, limit }** Returns an [`ArchivedProposalListResponse`] according to the specified input parameters.
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => to_binary(&query_config(deps)?),
-        QueryMsg::Proposals { start, limit } => to_binary(&query_proposals(deps, start, limit)?),
+        QueryMsg::Proposals {
+            start,
+            limit,
+            status,
+            submitter,
+            descending,
+        } => to_binary(&query_proposals(
+            deps,
+            start,
+            limit,
+            status,
+            submitter,
+            descending.unwrap_or(false),
+        )?),
         QueryMsg::Proposal { proposal_id } => to_binary(&query_proposal(deps, proposal_id)?),
         QueryMsg::ProposalVotes { proposal_id } => {
             to_binary(&query_proposal_votes(deps, proposal_id)?)
         }
+        QueryMsg::ProposalVoters {
+            proposal_id,
+            option,
+            start_after,
+            limit,
+        } => to_binary(&query_proposal_voters(
+            deps,
+            proposal_id,
+            option,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::UserVote { proposal_id, user } => {
+            to_binary(&query_user_vote(deps, proposal_id, user)?)
+        }
+        QueryMsg::UserVotes {
+            user,
+            start_after,
+            limit,
+        } => to_binary(&query_user_votes(deps, user, start_after, limit)?),
         QueryMsg::UserVotingPower { user, proposal_id } => {
-            let proposal = PROPOSALS.load(deps.storage, U64Key::new(proposal_id))?;
+            let proposal = proposals().load(deps.storage, U64Key::new(proposal_id))?;
 
             addr_validate_to_lower(deps.api, &user)?;
 
             to_binary(&calc_voting_power(deps, user, &proposal)?)
         }
+        QueryMsg::Delegation { user, proposal_id } => {
+            to_binary(&query_delegation(deps, user, proposal_id)?)
+        }
+        QueryMsg::DelegatedVotingPower {
+            delegatee,
+            proposal_id,
+        } => {
+            let proposal = proposals().load(deps.storage, U64Key::new(proposal_id))?;
+            let delegatee = addr_validate_to_lower(deps.api, &delegatee)?;
+
+            let delegated_power = calc_delegated_voting_power(deps, &delegatee, &proposal)?
+                .into_iter()
+                .try_fold(Uint128::zero(), |total, (_, power)| {
+                    total.checked_add(power)
+                })?;
+
+            to_binary(&delegated_power)
+        }
         QueryMsg::TotalVotingPower { proposal_id } => {
-            let proposal = PROPOSALS.load(deps.storage, U64Key::new(proposal_id))?;
+            let proposal = proposals().load(deps.storage, U64Key::new(proposal_id))?;
             to_binary(&calc_total_voting_power_at(deps, &proposal)?)
         }
+        QueryMsg::IbcChannels {} => to_binary(&query_ibc_channels(deps)?),
+        QueryMsg::ArchivedProposals { start_after, limit } => {
+            to_binary(&query_archived_proposals(deps, start_after, limit)?)
+        }
     }
 }
 
+/// ## Description
+/// Returns the ids of the IBC channels opened by satellites.
+/// ## Params
+/// * **deps** is an object of type [`Deps`].
+pub fn query_ibc_channels(deps: Deps) -> StdResult<Vec<String>> {
+    IBC_CHANNELS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .map(|key| String::from_utf8(key).map_err(StdError::from))
+        .collect()
+}
+
 /// ## Description
 /// Returns the contract configuration stored in the [`Config`] structure.
 /// ## Params
@@ -629,25 +2268,60 @@ pub fn query_config(deps: Deps) -> StdResult<Config> {
 }
 
 /// ## Description
-/// Returns the current proposal list.
+/// Returns the current proposal list, optionally filtered by status and submitter.
 /// ## Params
 /// * **deps** is an object of type [`Deps`].
 ///
-/// * **start_after** is an [`Option`] type. Specifies the proposal list index to start reading from.
+/// * **start** is an [`Option`] type. Specifies the proposal id to start reading from.
 ///
 /// * **limit** is a [`Option`] type. Specifies the number of items to read.
+///
+/// * **status** is an [`Option`] of type [`ProposalStatus`]. Filters proposals by their status.
+///
+/// * **submitter** is an [`Option`] of type [`String`]. Filters proposals by their submitter.
+///
+/// * **descending** is a parameter of type `bool`. Returns proposals in descending order if set.
 pub fn query_proposals(
     deps: Deps,
     start: Option<u64>,
     limit: Option<u32>,
+    status: Option<ProposalStatus>,
+    submitter: Option<String>,
+    descending: bool,
 ) -> StdResult<ProposalListResponse> {
     let proposal_count = PROPOSAL_COUNT.load(deps.storage)?;
 
     let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
     let start = start.map(|start| Bound::inclusive(U64Key::new(start)));
+    let (min, max, order) = if descending {
+        (None, start, Order::Descending)
+    } else {
+        (start, None, Order::Ascending)
+    };
 
-    let proposals_list: StdResult<Vec<_>> = PROPOSALS
-        .range(deps.storage, start, None, Order::Ascending)
+    let proposals = proposals();
+    let range = match (&submitter, &status) {
+        (Some(submitter), _) => {
+            let submitter = addr_validate_to_lower(deps.api, submitter)?;
+            proposals
+                .idx
+                .submitter
+                .prefix(submitter.as_bytes().to_vec())
+                .range(deps.storage, min, max, order)
+        }
+        (None, Some(status)) => proposals
+            .idx
+            .status
+            .prefix(status.to_string().into_bytes())
+            .range(deps.storage, min, max, order),
+        (None, None) => proposals.range(deps.storage, min, max, order),
+    };
+
+    let proposals_list: StdResult<Vec<_>> = range
+        .filter(|item| match (item, &status) {
+            (Ok((_, proposal)), Some(status)) => proposal.status == *status,
+            _ => true,
+        })
         .take(limit)
         .map(|item| {
             let (_k, v) = item?;
@@ -661,6 +2335,39 @@ pub fn query_proposals(
     })
 }
 
+/// ## Description
+/// Returns the summaries of the proposals removed from the proposal list.
+/// ## Params
+/// * **deps** is an object of type [`Deps`].
+///
+/// * **start_after** is an [`Option`] type. Specifies the proposal id after which to start reading.
+///
+/// * **limit** is a [`Option`] type. Specifies the number of items to read.
+pub fn query_archived_proposals(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<ArchivedProposalListResponse> {
+    let proposal_count = PROPOSAL_COUNT.load(deps.storage)?;
+
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(|start| Bound::exclusive(U64Key::new(start)));
+
+    let archived_proposal_list = ARCHIVED_PROPOSALS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (_k, v) = item?;
+            Ok(v)
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(ArchivedProposalListResponse {
+        proposal_count,
+        archived_proposal_list,
+    })
+}
+
 /// ## Description
 /// Returns proposal information stored in the [`Proposal`] structure.
 /// ## Params
@@ -668,7 +2375,7 @@ pub fn query_proposals(
 ///
 /// * **proposal_id** is a parameter of type `u64`. This is the proposal identifier.
 pub fn query_proposal(deps: Deps, proposal_id: u64) -> StdResult<Proposal> {
-    let proposal = PROPOSALS.load(deps.storage, U64Key::new(proposal_id))?;
+    let proposal = proposals().load(deps.storage, U64Key::new(proposal_id))?;
     Ok(proposal)
 }
 
@@ -679,17 +2386,179 @@ pub fn query_proposal(deps: Deps, proposal_id: u64) -> StdResult<Proposal> {
 ///
 /// * **proposal_id** is a parameter of type `u64`. This is the proposal identifier.
 pub fn query_proposal_votes(deps: Deps, proposal_id: u64) -> StdResult<ProposalVotesResponse> {
-    let proposal = PROPOSALS.load(deps.storage, U64Key::from(proposal_id))?;
+    let proposal = proposals().load(deps.storage, U64Key::from(proposal_id))?;
 
     Ok(ProposalVotesResponse {
         proposal_id,
         for_power: proposal.for_power,
         against_power: proposal.against_power,
+        abstain_power: proposal.abstain_power,
+        no_with_veto_power: proposal.no_with_veto_power,
     })
 }
 
 /// ## Description
-/// Calculates an address' voting power at the specified block.
+/// Returns a list of voters (and their voting power) that chose a specific option on a proposal.
+/// ## Params
+/// * **deps** is an object of type [`Deps`].
+///
+/// * **proposal_id** is a parameter of type `u64`. This is the proposal identifier.
+///
+/// * **option** is an object of type [`ProposalVoteOption`]. This is the vote option to return voters for.
+///
+/// * **start_after** is an [`Option`] type. Specifies the voter address to start reading after.
+///
+/// * **limit** is a [`Option`] type. Specifies the number of items to read.
+pub fn query_proposal_voters(
+    deps: Deps,
+    proposal_id: u64,
+    option: ProposalVoteOption,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<(Addr, Uint128)>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_after = start_after
+        .map(|addr| addr_validate_to_lower(deps.api, &addr))
+        .transpose()?;
+    let start = start_after
+        .as_ref()
+        .map(|addr| Bound::exclusive(addr.as_str()));
+
+    let mut voters = PROPOSAL_VOTERS
+        .prefix((U64Key::new(proposal_id), option.to_string()))
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (voter, power) = item?;
+            Ok((
+                Addr::unchecked(String::from_utf8(voter).map_err(StdError::from)?),
+                power,
+            ))
+        })
+        .collect::<StdResult<Vec<(Addr, Uint128)>>>()?;
+
+    // Votes cast before v1.1.0 are listed without their voting power
+    if let Some(legacy_voters) = LEGACY_VOTERS.may_load(deps.storage, U64Key::new(proposal_id))? {
+        let legacy_voters = match option {
+            ProposalVoteOption::For => legacy_voters.for_voters,
+            ProposalVoteOption::Against => legacy_voters.against_voters,
+            _ => vec![],
+        };
+
+        voters.extend(
+            legacy_voters
+                .into_iter()
+                .filter(|voter| match &start_after {
+                    Some(start) => voter > start,
+                    None => true,
+                })
+                .map(|voter| (voter, Uint128::zero())),
+        );
+        voters.sort_by(|(a, _), (b, _)| a.cmp(b));
+        voters.truncate(limit);
+    }
+
+    Ok(voters)
+}
+
+/// ## Description
+/// Returns the vote cast by a user on a specific proposal or [`None`] if the user did not vote.
+/// ## Params
+/// * **deps** is an object of type [`Deps`].
+///
+/// * **proposal_id** is a parameter of type `u64`. This is the proposal identifier.
+///
+/// * **user** is an object of type [`String`]. This is the voter address.
+pub fn query_user_vote(
+    deps: Deps,
+    proposal_id: u64,
+    user: String,
+) -> StdResult<Option<ProposalVote>> {
+    let user = addr_validate_to_lower(deps.api, &user)?;
+
+    if let Some(vote) = USER_VOTES.may_load(deps.storage, (&user, U64Key::new(proposal_id)))? {
+        return Ok(Some(vote));
+    }
+
+    // The voting power of votes cast before v1.1.0 is unknown
+    Ok(
+        load_legacy_vote(deps.storage, proposal_id, &user)?.map(|option| ProposalVote {
+            option,
+            power: Uint128::zero(),
+        }),
+    )
+}
+
+/// ## Description
+/// Returns the votes cast by a user in ascending order of proposal ids.
+/// ## Params
+/// * **deps** is an object of type [`Deps`].
+///
+/// * **user** is an object of type [`String`]. This is the voter address.
+///
+/// * **start_after** is an [`Option`] type. Specifies the proposal id to start reading after.
+///
+/// * **limit** is a [`Option`] type. Specifies the number of items to read.
+pub fn query_user_votes(
+    deps: Deps,
+    user: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<UserVoteResponse>> {
+    let user = addr_validate_to_lower(deps.api, &user)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(|id| Bound::exclusive(U64Key::new(id)));
+
+    USER_VOTES
+        .prefix(&user)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (proposal_id, vote) = item?;
+            Ok(UserVoteResponse {
+                proposal_id: u64::from_be_bytes(
+                    proposal_id
+                        .try_into()
+                        .map_err(|_| StdError::generic_err("Deserialization error"))?,
+                ),
+                option: vote.option,
+                power: vote.power,
+            })
+        })
+        .collect()
+}
+
+/// ## Description
+/// Returns the address a user delegated their voting power to. If `proposal_id` is set,
+/// returns the delegation that applies to that proposal.
+/// ## Params
+/// * **deps** is an object of type [`Deps`].
+///
+/// * **user** is an object of type [`String`]. This is the delegator address.
+///
+/// * **proposal_id** is an [`Option`] of type `u64`. This is the proposal identifier.
+pub fn query_delegation(
+    deps: Deps,
+    user: String,
+    proposal_id: Option<u64>,
+) -> StdResult<Option<Addr>> {
+    let user = addr_validate_to_lower(deps.api, &user)?;
+
+    if let Some(proposal_id) = proposal_id {
+        let delegatee =
+            PROPOSAL_DELEGATIONS.may_load(deps.storage, (U64Key::new(proposal_id), &user))?;
+
+        if delegatee.is_some() {
+            return Ok(delegatee);
+        }
+    }
+
+    DELEGATIONS.may_load(deps.storage, &user)
+}
+
+/// ## Description
+/// Calculates an address' voting power at the specified block, including the voting power
+/// delegated to it.
 /// ## Params
 /// * **deps** is an object of type [`Deps`].
 ///
@@ -697,60 +2566,115 @@ pub fn query_proposal_votes(deps: Deps, proposal_id: u64) -> StdResult<ProposalV
 ///
 /// * **proposal** is an object of type [`Proposal`]. This is the proposal for which we want to compute the `sender` (voter) voting power.
 pub fn calc_voting_power(deps: Deps, sender: String, proposal: &Proposal) -> StdResult<Uint128> {
-    let config = CONFIG.load(deps.storage)?;
-
-    // xASTRO balance of the specified user at previous block(proposal.start_block - 1),
-    // because the previous block always has an up-to-date checkpoint and more secured.
-    // BalanceAt will always return the balance information in the previous block,
-    // so you shouldn't subtract block because of the specific logic of the SnapshotMap.
-    let xastro_amount: BalanceResponse = deps.querier.query_wasm_smart(
-        config.xastro_token_addr,
-        &XAstroTokenQueryMsg::BalanceAt {
-            address: sender.clone(),
-            block: proposal.start_block,
-        },
-    )?;
+    let delegatee = addr_validate_to_lower(deps.api, &sender)?;
+    let own_power = calc_own_voting_power(deps, sender, proposal)?;
 
-    let mut total = xastro_amount.balance;
+    calc_delegated_voting_power(deps, &delegatee, proposal)?
+        .into_iter()
+        .try_fold(own_power, |total, (_, power)| total.checked_add(power))
+        .map_err(StdError::from)
+}
 
-    let locked_amount: AllocationResponse = deps.querier.query_wasm_smart(
-        config.builder_unlock_addr,
-        &BuilderUnlockQueryMsg::Allocation {
-            account: sender.clone(),
-        },
-    )?;
+/// ## Description
+/// Returns the delegators (and their voting power) whose voting power can be used by `delegatee`
+/// on a proposal. Delegators that voted on the proposal themselves, or whose voting power was
+/// already used by another delegatee, are skipped. The proposal submitter's voting power can't be used.
+/// ## Params
+/// * **deps** is an object of type [`Deps`].
+///
+/// * **delegatee** is an object of type [`Addr`]. This is the address that received the voting power.
+///
+/// * **proposal** is an object of type [`Proposal`].
+pub fn calc_delegated_voting_power(
+    deps: Deps,
+    delegatee: &Addr,
+    proposal: &Proposal,
+) -> StdResult<Vec<(Addr, Uint128)>> {
+    let proposal_id = proposal.proposal_id.u64();
+
+    let mut delegators = PROPOSAL_DELEGATORS
+        .prefix((U64Key::new(proposal_id), delegatee))
+        .keys(deps.storage, None, None, Order::Ascending)
+        .map(|key| Ok(Addr::unchecked(String::from_utf8(key)?)))
+        .collect::<StdResult<Vec<Addr>>>()?;
+
+    for key in DELEGATORS
+        .prefix(delegatee)
+        .keys(deps.storage, None, None, Order::Ascending)
+    {
+        let delegator = Addr::unchecked(String::from_utf8(key)?);
 
-    if !locked_amount.params.amount.is_zero() {
-        total = total
-            .checked_add(locked_amount.params.amount)?
-            .checked_sub(locked_amount.status.rct_withdrawn)?;
+        // A delegation for a specific proposal overrides the general one
+        if !PROPOSAL_DELEGATIONS.has(deps.storage, (U64Key::new(proposal_id), &delegator)) {
+            delegators.push(delegator);
+        }
     }
 
-    if let Some(vxastro_token_addr) = config.vxastro_token_addr {
-        let vxastro_amount: VotingPowerResponse = deps.querier.query_wasm_smart(
-            &vxastro_token_addr,
-            &VotingEscrowQueryMsg::UserVotingPowerAt {
-                user: sender.clone(),
-                time: proposal.start_time - 1,
-            },
-        )?;
+    let legacy_voters = LEGACY_VOTERS.may_load(deps.storage, U64Key::new(proposal_id))?;
+    let mut delegated_power = vec![];
 
-        if !vxastro_amount.voting_power.is_zero() {
-            total = total.checked_add(vxastro_amount.voting_power)?;
+    for delegator in delegators {
+        if delegator == proposal.submitter
+            || PROPOSAL_VOTES.has(deps.storage, (U64Key::new(proposal_id), &delegator))
+            || matches!(&legacy_voters, Some(voters) if voters.vote(&delegator).is_some())
+        {
+            continue;
         }
 
-        let locked_xastro: Uint128 = deps.querier.query_wasm_smart(
-            vxastro_token_addr,
-            &VotingEscrowQueryMsg::UserDepositAtHeight {
-                user: sender,
-                height: proposal.start_block,
-            },
-        )?;
+        if let Some(user) =
+            DELEGATED_VOTES.may_load(deps.storage, (U64Key::new(proposal_id), &delegator))?
+        {
+            if user != *delegatee {
+                continue;
+            }
+        }
+
+        let power = calc_own_voting_power(deps, delegator.to_string(), proposal)?;
 
-        total = total.checked_add(locked_xastro)?;
+        if !power.is_zero() {
+            delegated_power.push((delegator, power));
+        }
     }
 
-    Ok(total)
+    Ok(delegated_power)
+}
+
+/// ## Description
+/// Calculates an address' own voting power at the specified block.
+/// ## Params
+/// * **deps** is an object of type [`Deps`].
+///
+/// * **sender** is an object of type [`String`]. This is the address whose voting power we calculate.
+///
+/// * **proposal** is an object of type [`Proposal`]. This is the proposal for which we want to compute the `sender` (voter) voting power.
+pub fn calc_own_voting_power(
+    deps: Deps,
+    sender: String,
+    proposal: &Proposal,
+) -> StdResult<Uint128> {
+    calc_voting_power_at(deps, &sender, proposal.start_block, proposal.start_time)
+}
+
+/// ## Description
+/// Calculates an address' own voting power at the specified block and time.
+/// ## Params
+/// * **deps** is an object of type [`Deps`].
+///
+/// * **sender** is an object of type [`str`]. This is the address whose voting power we calculate.
+///
+/// * **block** is a parameter of type `u64`. This is the block at which the voting power is read.
+///
+/// * **time** is a parameter of type `u64`. This is the time at which the voting power is read.
+fn calc_voting_power_at(deps: Deps, sender: &str, block: u64, time: u64) -> StdResult<Uint128> {
+    let config = CONFIG.load(deps.storage)?;
+
+    config
+        .voting_power_sources
+        .iter()
+        .try_fold(Uint128::zero(), |total, source| {
+            let power = query_source_voting_power(deps, source, sender, block, time)?;
+            Ok(total.checked_add(power * source.weight)?)
+        })
 }
 
 /// ## Description
@@ -762,38 +2686,231 @@ pub fn calc_voting_power(deps: Deps, sender: String, proposal: &Proposal) -> Std
 pub fn calc_total_voting_power_at(deps: Deps, proposal: &Proposal) -> StdResult<Uint128> {
     let config = CONFIG.load(deps.storage)?;
 
-    // Total xASTRO supply at a previous block(proposal.start_block - 1),
-    // because the previous block always has an up-to-date checkpoint and more secured
-    let mut total: Uint128 = deps.querier.query_wasm_smart(
-        &config.xastro_token_addr,
-        &XAstroTokenQueryMsg::TotalSupplyAt {
-            block: proposal.start_block - 1,
-        },
-    )?;
+    config
+        .voting_power_sources
+        .iter()
+        .try_fold(Uint128::zero(), |total, source| {
+            let power = query_source_total_voting_power(deps, source, proposal)?;
+            Ok(total.checked_add(power * source.weight)?)
+        })
+}
 
-    // Total amount of ASTRO locked in the initial builder's unlock schedule
-    let builder_state: StateResponse = deps
-        .querier
-        .query_wasm_smart(config.builder_unlock_addr, &BuilderUnlockQueryMsg::State {})?;
+/// ## Description
+/// Returns the timestamp at which vxASTRO voting power is read for a proposal. vxASTRO voting power
+/// is tracked per week and the current week can still change (e.g. when a lock position is
+/// transferred), so the last second of the week before the proposal start is used.
+/// Returns [`None`] if no week ended before the proposal start.
+/// ## Params
+/// * **start_time** is a parameter of type `u64`. This is the proposal start time.
+fn vxastro_snapshot_time(start_time: u64) -> StdResult<Option<u64>> {
+    if start_time < EPOCH_START + WEEK {
+        return Ok(None);
+    }
+
+    Ok(Some(EPOCH_START + get_period(start_time)? * WEEK - 1))
+}
 
-    if !builder_state.remaining_rct_tokens.is_zero() {
-        total = total.checked_add(builder_state.remaining_rct_tokens)?;
+/// ## Description
+/// Returns the voting power of a user read from a single voting power source, before the source
+/// weight is applied.
+/// ## Params
+/// * **deps** is an object of type [`Deps`].
+///
+/// * **source** is an object of type [`VotingPowerSource`]. This is the source to query.
+///
+/// * **user** is an object of type [`str`]. This is the address whose voting power we read.
+///
+/// * **block** is a parameter of type `u64`. This is the snapshot block, e.g. the proposal start block.
+///
+/// * **time** is a parameter of type `u64`. This is the snapshot time, e.g. the proposal start time.
+fn query_source_voting_power(
+    deps: Deps,
+    source: &VotingPowerSource,
+    user: &str,
+    block: u64,
+    time: u64,
+) -> StdResult<Uint128> {
+    match source.adapter {
+        VotingPowerAdapter::Cw20Snapshot => {
+            // Balance of the specified user at previous block(block - 1),
+            // because the previous block always has an up-to-date checkpoint and more secured.
+            // BalanceAt will always return the balance information in the previous block,
+            // so you shouldn't subtract block because of the specific logic of the SnapshotMap.
+            let response: BalanceResponse = deps.querier.query_wasm_smart(
+                &source.contract_addr,
+                &XAstroTokenQueryMsg::BalanceAt {
+                    address: user.to_string(),
+                    block,
+                },
+            )?;
+
+            Ok(response.balance)
+        }
+        VotingPowerAdapter::BuilderUnlock => {
+            // Allocation of the specified user at the beginning of the snapshot block
+            let allocation: AllocationResponse = deps.querier.query_wasm_smart(
+                &source.contract_addr,
+                &BuilderUnlockQueryMsg::AllocationAt {
+                    account: user.to_string(),
+                    height: block,
+                },
+            )?;
+
+            Ok(allocation
+                .params
+                .amount
+                .checked_sub(allocation.status.rct_withdrawn)?)
+        }
+        VotingPowerAdapter::VotingEscrow => {
+            let vxastro = match vxastro_snapshot_time(time)? {
+                Some(time) => {
+                    let response: VotingPowerResponse = deps.querier.query_wasm_smart(
+                        &source.contract_addr,
+                        &VotingEscrowQueryMsg::UserVotingPowerAt {
+                            user: user.to_string(),
+                            time,
+                        },
+                    )?;
+
+                    response.voting_power
+                }
+                None => Uint128::zero(),
+            };
+
+            let locked_xastro: Uint128 = deps.querier.query_wasm_smart(
+                &source.contract_addr,
+                &VotingEscrowQueryMsg::UserDepositAtHeight {
+                    user: user.to_string(),
+                    height: block,
+                },
+            )?;
+
+            Ok(vxastro.checked_add(locked_xastro)?)
+        }
+        VotingPowerAdapter::VotingPowerAt => {
+            let response: VotingPowerResponse = deps.querier.query_wasm_smart(
+                &source.contract_addr,
+                &VotingPowerQueryMsg::VotingPowerAt {
+                    user: user.to_string(),
+                    time: time - 1,
+                },
+            )?;
+
+            Ok(response.voting_power)
+        }
     }
+}
 
-    if let Some(vxastro_token_addr) = config.vxastro_token_addr {
-        // Total vxASTRO voting power
-        let vxastro: VotingPowerResponse = deps.querier.query_wasm_smart(
-            &vxastro_token_addr,
-            &VotingEscrowQueryMsg::TotalVotingPowerAt {
-                time: proposal.start_time - 1,
-            },
-        )?;
-        if !vxastro.voting_power.is_zero() {
-            total = total.checked_add(vxastro.voting_power)?;
+/// ## Description
+/// Returns the total voting power read from a single voting power source, before the source
+/// weight is applied.
+/// ## Params
+/// * **deps** is an object of type [`Deps`].
+///
+/// * **source** is an object of type [`VotingPowerSource`]. This is the source to query.
+///
+/// * **proposal** is an object of type [`Proposal`]. This is the proposal that defines the snapshot.
+fn query_source_total_voting_power(
+    deps: Deps,
+    source: &VotingPowerSource,
+    proposal: &Proposal,
+) -> StdResult<Uint128> {
+    match source.adapter {
+        VotingPowerAdapter::Cw20Snapshot => {
+            // Total supply at a previous block(proposal.start_block - 1),
+            // because the previous block always has an up-to-date checkpoint and more secured
+            deps.querier.query_wasm_smart(
+                &source.contract_addr,
+                &XAstroTokenQueryMsg::TotalSupplyAt {
+                    block: proposal.start_block - 1,
+                },
+            )
+        }
+        VotingPowerAdapter::BuilderUnlock => {
+            // Total amount of ASTRO locked in the initial builder's unlock schedule
+            // at the beginning of the proposal start block
+            let state: StateResponse = deps.querier.query_wasm_smart(
+                &source.contract_addr,
+                &BuilderUnlockQueryMsg::StateAt {
+                    height: proposal.start_block,
+                },
+            )?;
+
+            Ok(state.remaining_rct_tokens)
         }
+        VotingPowerAdapter::VotingEscrow => match vxastro_snapshot_time(proposal.start_time)? {
+            Some(time) => {
+                let vxastro: VotingPowerResponse = deps.querier.query_wasm_smart(
+                    &source.contract_addr,
+                    &VotingEscrowQueryMsg::TotalVotingPowerAt { time },
+                )?;
+
+                Ok(vxastro.voting_power)
+            }
+            None => Ok(Uint128::zero()),
+        },
+        VotingPowerAdapter::VotingPowerAt => {
+            let response: VotingPowerResponse = deps.querier.query_wasm_smart(
+                &source.contract_addr,
+                &VotingPowerQueryMsg::TotalVotingPowerAt {
+                    time: proposal.start_time - 1,
+                },
+            )?;
+
+            Ok(response.voting_power)
+        }
+    }
+}
+
+/// ## Description
+/// Returns the voting power sources used when none are specified: xASTRO, the builder unlock
+/// contract and vxASTRO (if set), all with a weight of one.
+/// ## Params
+/// * **config** is an object of type [`Config`].
+pub(crate) fn default_voting_power_sources(config: &Config) -> Vec<VotingPowerSource> {
+    let mut sources = vec![
+        VotingPowerSource {
+            contract_addr: config.xastro_token_addr.clone(),
+            adapter: VotingPowerAdapter::Cw20Snapshot,
+            weight: Decimal::one(),
+        },
+        VotingPowerSource {
+            contract_addr: config.builder_unlock_addr.clone(),
+            adapter: VotingPowerAdapter::BuilderUnlock,
+            weight: Decimal::one(),
+        },
+    ];
+
+    if let Some(vxastro_token_addr) = &config.vxastro_token_addr {
+        sources.push(VotingPowerSource {
+            contract_addr: vxastro_token_addr.clone(),
+            adapter: VotingPowerAdapter::VotingEscrow,
+            weight: Decimal::one(),
+        });
     }
 
-    Ok(total)
+    sources
+}
+
+/// ## Description
+/// Validates the contract addresses of the specified voting power sources.
+/// ## Params
+/// * **deps** is an object of type [`Deps`].
+///
+/// * **sources** is a vector of type [`VotingPowerSource`].
+fn validate_voting_power_sources(
+    deps: Deps,
+    sources: Vec<VotingPowerSource>,
+) -> StdResult<Vec<VotingPowerSource>> {
+    sources
+        .into_iter()
+        .map(|source| {
+            Ok(VotingPowerSource {
+                contract_addr: addr_validate_to_lower(deps.api, source.contract_addr.as_str())?,
+                ..source
+            })
+        })
+        .collect()
 }
 
 /// ## Description
@@ -805,7 +2922,7 @@ pub fn calc_total_voting_power_at(deps: Deps, proposal: &Proposal) -> StdResult<
 ///
 /// * **msg** is an object of type [`MigrateMsg`].
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+pub fn migrate(mut deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
     let contract_version = get_contract_version(deps.storage)?;
 
     match contract_version.contract.as_ref() {
@@ -818,7 +2935,7 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, Co
                 }
                 validate_links(&msg.whitelisted_links)?;
 
-                let config = Config {
+                let config = ConfigV102 {
                     xastro_token_addr: config_v100.xastro_token_addr,
                     vxastro_token_addr: Some(config_v100.vxastro_token_addr),
                     builder_unlock_addr: config_v100.builder_unlock_addr,
@@ -831,14 +2948,14 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, Co
                     whitelisted_links: msg.whitelisted_links,
                 };
 
-                config.validate()?;
+                CONFIGV102.save(deps.storage, &config)?;
 
-                CONFIG.save(deps.storage, &config)?;
+                migrate_to_v110(deps.branch(), &env)?;
             }
             "1.0.1" => {
                 let config_v101 = CONFIGV101.load(deps.storage)?;
 
-                let config = Config {
+                let config = ConfigV102 {
                     xastro_token_addr: config_v101.xastro_token_addr,
                     vxastro_token_addr: Some(config_v101.vxastro_token_addr),
                     builder_unlock_addr: config_v101.builder_unlock_addr,
@@ -851,7 +2968,12 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, Co
                     whitelisted_links: config_v101.whitelisted_links,
                 };
 
-                CONFIG.save(deps.storage, &config)?;
+                CONFIGV102.save(deps.storage, &config)?;
+
+                migrate_to_v110(deps.branch(), &env)?;
+            }
+            "1.0.2" => {
+                migrate_to_v110(deps.branch(), &env)?;
             }
             _ => return Err(ContractError::MigrationError {}),
         },
@@ -38,15 +38,78 @@ pub enum ContractError {
     #[error("Insufficient token deposit!")]
     InsufficientDeposit {},
 
+    #[error("Proposal type not found!")]
+    ProposalTypeNotFound {},
+
+    #[error("Proposal submission with vxASTRO voting power is disabled!")]
+    VotingPowerSubmissionDisabled {},
+
+    #[error("Insufficient vxASTRO voting power!")]
+    InsufficientVotingPower {},
+
+    #[error("The submitter has too many active proposals!")]
+    TooManyActiveProposals {},
+
     #[error("Proposal not passed!")]
     ProposalNotPassed {},
 
     #[error("Proposal not completed!")]
     ProposalNotCompleted {},
 
+    #[error("Proposal can't be cancelled!")]
+    ProposalNotCancellable {},
+
+    #[error("Proposal already has votes!")]
+    ProposalHasVotes {},
+
     #[error("Proposal delay not ended!")]
     ProposalDelayNotEnded {},
 
+    #[error("You can't delegate voting power to yourself!")]
+    SelfDelegation {},
+
+    #[error("Delegation not found!")]
+    DelegationNotFound {},
+
+    #[error("The delegatee has too many delegators!")]
+    TooManyDelegators {},
+
+    #[error("Delegated voting power was already used on this proposal!")]
+    DelegatedPowerAlreadyUsed {},
+
+    #[error("Messages executed successfully!")]
+    MessagesCheckPassed {},
+
+    #[error("IBC channel not found: {0}")]
+    IbcChannelNotFound(String),
+
+    #[error("Only unordered IBC channels are supported!")]
+    InvalidIbcOrder {},
+
+    #[error("Invalid IBC channel version!")]
+    InvalidIbcVersion {},
+
+    #[error("IBC channels can only be opened by allowed satellites!")]
+    IbcSatelliteNotAllowed {},
+
+    #[error("Proposals created before v1.1.0 are still being migrated!")]
+    ProposalMigrationPending {},
+
+    #[error("All proposals are already migrated!")]
+    ProposalMigrationFinished {},
+
+    #[error("Proposal messages sent to satellites can't be queued in the Timelock!")]
+    RemoteMessagesWithTimelock {},
+
+    #[error("Timelock is not set!")]
+    TimelockNotSet {},
+
+    #[error("Proposal not queued!")]
+    ProposalNotQueued {},
+
+    #[error("Timelock transaction is still queued!")]
+    TimelockTransactionQueued {},
+
     #[error("Contract can't be migrated!")]
     MigrationError {},
 
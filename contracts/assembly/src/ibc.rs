@@ -0,0 +1,243 @@
+use cosmwasm_std::{
+    entry_point, from_binary, to_binary, Deps, DepsMut, Env, IbcBasicResponse, IbcChannel,
+    IbcChannelCloseMsg, IbcChannelConnectMsg, IbcChannelOpenMsg, IbcOrder, IbcPacketAckMsg,
+    IbcPacketReceiveMsg, IbcPacketTimeoutMsg, IbcReceiveResponse, Uint64,
+};
+use cw_storage_plus::U64Key;
+
+use astroport_governance::assembly::IbcMessageStatus;
+use astroport_governance::satellite::{SatelliteAck, SatellitePacket, IBC_APP_VERSION};
+
+use crate::error::ContractError;
+use crate::state::{proposals, CONFIG, IBC_CHANNELS};
+
+/// ## Description
+/// Validates a channel opened by a satellite. Only unordered channels with the
+/// [`IBC_APP_VERSION`] version opened by an allowed satellite are accepted.
+/// ## Params
+/// * **deps** is an object of type [`DepsMut`].
+///
+/// * **_env** is an object of type [`Env`].
+///
+/// * **msg** is an object of type [`IbcChannelOpenMsg`].
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_channel_open(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelOpenMsg,
+) -> Result<(), ContractError> {
+    validate_channel(deps.as_ref(), msg.channel(), msg.counterparty_version())
+}
+
+/// ## Description
+/// Saves a channel once the handshake with a satellite is completed.
+/// ## Params
+/// * **deps** is an object of type [`DepsMut`].
+///
+/// * **_env** is an object of type [`Env`].
+///
+/// * **msg** is an object of type [`IbcChannelConnectMsg`].
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_channel_connect(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelConnectMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let channel = msg.channel();
+
+    validate_channel(deps.as_ref(), channel, msg.counterparty_version())?;
+
+    IBC_CHANNELS.save(deps.storage, &channel.endpoint.channel_id, channel)?;
+
+    Ok(IbcBasicResponse::new()
+        .add_attribute("action", "ibc_channel_connect")
+        .add_attribute("channel_id", &channel.endpoint.channel_id))
+}
+
+/// ## Description
+/// Removes a closed channel. Proposal messages can no longer be sent to it.
+/// ## Params
+/// * **deps** is an object of type [`DepsMut`].
+///
+/// * **_env** is an object of type [`Env`].
+///
+/// * **msg** is an object of type [`IbcChannelCloseMsg`].
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_channel_close(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelCloseMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let channel = msg.channel();
+
+    IBC_CHANNELS.remove(deps.storage, &channel.endpoint.channel_id);
+
+    Ok(IbcBasicResponse::new()
+        .add_attribute("action", "ibc_channel_close")
+        .add_attribute("channel_id", &channel.endpoint.channel_id))
+}
+
+/// ## Description
+/// Satellites never send packets to the Assembly, so every received packet is acknowledged with an error.
+/// ## Params
+/// * **_deps** is an object of type [`DepsMut`].
+///
+/// * **_env** is an object of type [`Env`].
+///
+/// * **_msg** is an object of type [`IbcPacketReceiveMsg`].
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_packet_receive(
+    _deps: DepsMut,
+    _env: Env,
+    _msg: IbcPacketReceiveMsg,
+) -> Result<IbcReceiveResponse, ContractError> {
+    Ok(IbcReceiveResponse::new()
+        .set_ack(to_binary(&SatelliteAck::Error(
+            "The Assembly doesn't accept IBC packets".to_string(),
+        ))?)
+        .add_attribute("action", "ibc_packet_receive"))
+}
+
+/// ## Description
+/// Records the acknowledgement of a proposal message sent to a satellite.
+/// ## Params
+/// * **deps** is an object of type [`DepsMut`].
+///
+/// * **_env** is an object of type [`Env`].
+///
+/// * **msg** is an object of type [`IbcPacketAckMsg`].
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_packet_ack(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcPacketAckMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let (status, error) = match from_binary(&msg.acknowledgement.data)? {
+        SatelliteAck::Result(_) => (IbcMessageStatus::Succeeded, None),
+        SatelliteAck::Error(error) => (IbcMessageStatus::Failed, Some(error)),
+    };
+
+    let SatellitePacket::ExecuteProposalMessage {
+        proposal_id, order, ..
+    } = from_binary(&msg.original_packet.data)?;
+
+    update_ibc_message_status(
+        deps,
+        proposal_id,
+        order,
+        &msg.original_packet.src.channel_id,
+        status,
+        error,
+    )
+}
+
+/// ## Description
+/// Records that a proposal message sent to a satellite timed out.
+/// ## Params
+/// * **deps** is an object of type [`DepsMut`].
+///
+/// * **_env** is an object of type [`Env`].
+///
+/// * **msg** is an object of type [`IbcPacketTimeoutMsg`].
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_packet_timeout(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcPacketTimeoutMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let SatellitePacket::ExecuteProposalMessage {
+        proposal_id, order, ..
+    } = from_binary(&msg.packet.data)?;
+
+    update_ibc_message_status(
+        deps,
+        proposal_id,
+        order,
+        &msg.packet.src.channel_id,
+        IbcMessageStatus::TimedOut,
+        None,
+    )
+}
+
+/// ## Description
+/// Checks that a channel is unordered, uses the [`IBC_APP_VERSION`] version and that its connection
+/// and counterparty port belong to one of the allowed satellites.
+/// ## Params
+/// * **deps** is an object of type [`Deps`].
+///
+/// * **channel** is an object of type [`IbcChannel`].
+///
+/// * **counterparty_version** is an [`Option`] of type [`str`]. This is the version proposed by the satellite.
+fn validate_channel(
+    deps: Deps,
+    channel: &IbcChannel,
+    counterparty_version: Option<&str>,
+) -> Result<(), ContractError> {
+    if channel.order != IbcOrder::Unordered {
+        return Err(ContractError::InvalidIbcOrder {});
+    }
+
+    if channel.version != IBC_APP_VERSION
+        || matches!(counterparty_version, Some(version) if version != IBC_APP_VERSION)
+    {
+        return Err(ContractError::InvalidIbcVersion {});
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+    if !config.ibc_satellites.iter().any(|satellite| {
+        satellite.connection_id == channel.connection_id
+            && satellite.port_id == channel.counterparty_endpoint.port_id
+    }) {
+        return Err(ContractError::IbcSatelliteNotAllowed {});
+    }
+
+    Ok(())
+}
+
+/// ## Description
+/// Updates the acknowledgement status of a proposal message. Acknowledgements of removed proposals
+/// are ignored, so that they can always be relayed.
+/// ## Params
+/// * **deps** is an object of type [`DepsMut`].
+///
+/// * **proposal_id** is a parameter of type `u64`. This is the proposal identifier.
+///
+/// * **order** is an object of type [`Uint64`]. This is the execution order of the message.
+///
+/// * **channel** is an object of type [`str`]. This is the channel the message was sent to.
+///
+/// * **status** is an object of type [`IbcMessageStatus`]. This is the new message status.
+///
+/// * **error** is an [`Option`] of type [`String`]. This is the error returned by the satellite.
+fn update_ibc_message_status(
+    deps: DepsMut,
+    proposal_id: u64,
+    order: Uint64,
+    channel: &str,
+    status: IbcMessageStatus,
+    error: Option<String>,
+) -> Result<IbcBasicResponse, ContractError> {
+    let response = IbcBasicResponse::new()
+        .add_attribute("action", "ibc_message_status")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("order", order.to_string())
+        .add_attribute("status", status.to_string());
+
+    let mut proposal = match proposals().may_load(deps.storage, U64Key::new(proposal_id))? {
+        Some(proposal) => proposal,
+        None => return Ok(response),
+    };
+
+    if let Some(message_status) = proposal
+        .ibc_message_statuses
+        .iter_mut()
+        .find(|message_status| message_status.order == order && message_status.channel == channel)
+    {
+        message_status.status = status;
+        message_status.error = error;
+
+        proposals().save(deps.storage, U64Key::new(proposal_id), &proposal)?;
+    }
+
+    Ok(response)
+}
@@ -1,5 +1,9 @@
 pub mod contract;
 pub mod error;
+pub mod ibc;
 pub mod state;
 
 mod migration;
+
+#[cfg(test)]
+mod testing;
@@ -1,8 +1,21 @@
-use cosmwasm_std::{Addr, Decimal, Uint128};
-use cw_storage_plus::Item;
+use astroport_governance::assembly::{
+    Config, Proposal, ProposalMessage, ProposalStatus, ProposalValidationConfig,
+    ProposalVoteOption, MINIMUM_DELAY, MINIMUM_EXPIRATION_PERIOD,
+};
+use cosmwasm_std::{Addr, Decimal, DepsMut, Env, StdResult, Storage, Uint128, Uint64};
+use cw_storage_plus::{Item, Map, U64Key};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::contract::default_voting_power_sources;
+use crate::state::{proposals, CONFIG, PROPOSAL_COUNT};
+
+/// The block time (in seconds) assumed by the block-based periods used up to v1.0.2
+const LEGACY_BLOCK_TIME: u64 = 7;
+
+/// The maximum number of proposals converted in one transaction
+pub const PROPOSAL_MIGRATION_LIMIT: u64 = 30;
+
 /// This structure describes a migration message.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct MigrateMsg {
@@ -62,3 +75,272 @@ pub struct ConfigV101 {
 }
 
 pub const CONFIGV101: Item<ConfigV101> = Item::new("config");
+
+/// This structure stores general parameters for the Assembly contract.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ConfigV102 {
+    /// xASTRO token address
+    pub xastro_token_addr: Addr,
+    /// vxASTRO token address
+    pub vxastro_token_addr: Option<Addr>,
+    /// Builder unlock contract address
+    pub builder_unlock_addr: Addr,
+    /// Proposal voting period
+    pub proposal_voting_period: u64,
+    /// Proposal effective delay
+    pub proposal_effective_delay: u64,
+    /// Proposal expiration period
+    pub proposal_expiration_period: u64,
+    /// Proposal required deposit
+    pub proposal_required_deposit: Uint128,
+    /// Proposal required quorum
+    pub proposal_required_quorum: Decimal,
+    /// Proposal required threshold
+    pub proposal_required_threshold: Decimal,
+    /// Whitelisted links
+    pub whitelisted_links: Vec<String>,
+}
+
+pub const CONFIGV102: Item<ConfigV102> = Item::new("config");
+
+/// This structure stores data for a proposal.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ProposalV102 {
+    /// Unique proposal ID
+    pub proposal_id: Uint64,
+    /// The address of the proposal submitter
+    pub submitter: Addr,
+    /// Status of the proposal
+    pub status: ProposalStatus,
+    /// `For` power of proposal
+    pub for_power: Uint128,
+    /// `Against` power of proposal
+    pub against_power: Uint128,
+    /// `For` votes for the proposal
+    pub for_voters: Vec<Addr>,
+    /// `Against` votes for the proposal
+    pub against_voters: Vec<Addr>,
+    /// Start block of proposal
+    pub start_block: u64,
+    /// Start time of proposal
+    pub start_time: u64,
+    /// End block of proposal
+    pub end_block: u64,
+    /// Proposal title
+    pub title: String,
+    /// Proposal description
+    pub description: String,
+    /// Proposal link
+    pub link: Option<String>,
+    /// Proposal messages
+    pub messages: Option<Vec<ProposalMessage>>,
+    /// Amount of xASTRO deposited in order to post the proposal
+    pub deposit_amount: Uint128,
+}
+
+pub const PROPOSALSV102: Map<U64Key, ProposalV102> = Map::new("proposals");
+
+/// This structure stores the voters of a proposal created before v1.1.0.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LegacyVoters {
+    /// `For` votes for the proposal
+    pub for_voters: Vec<Addr>,
+    /// `Against` votes for the proposal
+    pub against_voters: Vec<Addr>,
+}
+
+impl LegacyVoters {
+    /// Returns the option a user voted for or [`None`] if the user didn't vote.
+    pub fn vote(&self, voter: &Addr) -> Option<ProposalVoteOption> {
+        if self.for_voters.contains(voter) {
+            Some(ProposalVoteOption::For)
+        } else if self.against_voters.contains(voter) {
+            Some(ProposalVoteOption::Against)
+        } else {
+            None
+        }
+    }
+}
+
+/// ## Description
+/// This is a map that contains the voters of every proposal created before v1.1.0.
+/// v1.0.2 didn't store the voting power of each voter, so these votes are kept without it.
+pub const LEGACY_VOTERS: Map<U64Key, LegacyVoters> = Map::new("legacy_voters");
+
+/// ## Description
+/// Stores the identifier of the last proposal converted to the v1.1.0 layout while the migration
+/// of proposals is in progress. It is removed once all proposals are converted.
+pub const PROPOSAL_MIGRATION_CURSOR: Item<u64> = Item::new("proposal_migration_cursor");
+
+/// ## Description
+/// Returns the option a user voted for on a proposal before v1.1.0 or [`None`] if the user
+/// didn't vote before the migration.
+/// ## Params
+/// * **storage** is an object of type [`Storage`].
+///
+/// * **proposal_id** is a parameter of type `u64`. This is the proposal identifier.
+///
+/// * **voter** is an object of type [`Addr`]. This is the voter address.
+pub fn load_legacy_vote(
+    storage: &dyn Storage,
+    proposal_id: u64,
+    voter: &Addr,
+) -> StdResult<Option<ProposalVoteOption>> {
+    Ok(LEGACY_VOTERS
+        .may_load(storage, U64Key::new(proposal_id))?
+        .and_then(|voters| voters.vote(voter)))
+}
+
+/// ## Description
+/// Migrates the config from the v1.0.2 layout to the current one and converts the first batch of
+/// proposals with [`migrate_proposals`]. The remaining proposals are converted in later transactions.
+/// Block-based periods are converted to seconds.
+/// ## Params
+/// * **deps** is an object of type [`DepsMut`].
+///
+/// * **env** is an object of type [`Env`].
+pub(crate) fn migrate_to_v110(deps: DepsMut, env: &Env) -> StdResult<()> {
+    let config_v102 = CONFIGV102.load(deps.storage)?;
+
+    let mut config = Config {
+        xastro_token_addr: config_v102.xastro_token_addr,
+        vxastro_token_addr: config_v102.vxastro_token_addr,
+        builder_unlock_addr: config_v102.builder_unlock_addr,
+        proposal_voting_period: config_v102.proposal_voting_period * LEGACY_BLOCK_TIME,
+        proposal_effective_delay: (config_v102.proposal_effective_delay * LEGACY_BLOCK_TIME)
+            .max(MINIMUM_DELAY),
+        proposal_expiration_period: (config_v102.proposal_expiration_period * LEGACY_BLOCK_TIME)
+            .max(MINIMUM_EXPIRATION_PERIOD),
+        proposal_required_deposit: config_v102.proposal_required_deposit,
+        proposal_required_quorum: config_v102.proposal_required_quorum,
+        proposal_required_threshold: config_v102.proposal_required_threshold,
+        proposal_required_veto_threshold: Decimal::permille(334),
+        // Expedited proposals need twice the deposit, half the voting period and a 2/3 supermajority
+        expedited_proposal_required_deposit: config_v102
+            .proposal_required_deposit
+            .checked_mul(Uint128::new(2))?,
+        expedited_proposal_voting_period: config_v102.proposal_voting_period * LEGACY_BLOCK_TIME
+            / 2,
+        expedited_proposal_required_threshold: config_v102
+            .proposal_required_threshold
+            .max(Decimal::percent(67)),
+        early_finalization_enabled: false,
+        proposal_required_vxastro_power: None,
+        quorum_failure_deposit_slash: Decimal::zero(),
+        veto_deposit_slash: Decimal::one(),
+        slashed_deposit_receiver: None,
+        guardian: None,
+        timelock_addr: None,
+        proposal_validation: ProposalValidationConfig::default(),
+        voting_power_sources: vec![],
+        whitelisted_links: config_v102.whitelisted_links,
+        proposal_types: vec![],
+        ibc_satellites: vec![],
+    };
+
+    config.voting_power_sources = default_voting_power_sources(&config);
+
+    config.validate()?;
+
+    CONFIG.save(deps.storage, &config)?;
+
+    PROPOSAL_MIGRATION_CURSOR.save(deps.storage, &0)?;
+
+    migrate_proposals(deps.storage, env, PROPOSAL_MIGRATION_LIMIT)?;
+
+    Ok(())
+}
+
+/// ## Description
+/// Converts up to `limit` proposals from the v1.0.2 layout to the current one, starting after
+/// [`PROPOSAL_MIGRATION_CURSOR`]. Proposals keep their vote tallies. The voter lists are moved
+/// out of proposals into [`LEGACY_VOTERS`] as they are, because v1.0.2 does not store the voting
+/// power used by each voter. The end block of every proposal is converted to a timestamp relative
+/// to the current block.
+/// Returns `true` once all proposals are converted.
+/// ## Params
+/// * **storage** is an object of type [`Storage`].
+///
+/// * **env** is an object of type [`Env`].
+///
+/// * **limit** is a parameter of type `u64`. This is the maximum number of proposals to convert.
+pub(crate) fn migrate_proposals(
+    storage: &mut dyn Storage,
+    env: &Env,
+    limit: u64,
+) -> StdResult<bool> {
+    let config = CONFIG.load(storage)?;
+    let cursor = PROPOSAL_MIGRATION_CURSOR.load(storage)?;
+    let proposal_count = PROPOSAL_COUNT.may_load(storage)?.unwrap_or_default().u64();
+    let last_proposal_id = proposal_count.min(cursor + limit);
+    let block_time = env.block.time.seconds();
+
+    for proposal_id in cursor + 1..=last_proposal_id {
+        let proposal_v102 = match PROPOSALSV102.may_load(storage, U64Key::new(proposal_id))? {
+            Some(proposal) => proposal,
+            None => continue,
+        };
+
+        let end_time = if proposal_v102.end_block >= env.block.height {
+            block_time + (proposal_v102.end_block - env.block.height) * LEGACY_BLOCK_TIME
+        } else {
+            block_time
+                .saturating_sub((env.block.height - proposal_v102.end_block) * LEGACY_BLOCK_TIME)
+        };
+        let effective_time = end_time + config.proposal_effective_delay;
+
+        let proposal = Proposal {
+            proposal_id: proposal_v102.proposal_id,
+            submitter: proposal_v102.submitter,
+            status: proposal_v102.status,
+            proposal_type: None,
+            expedited: false,
+            converted_from_expedited: false,
+            for_power: proposal_v102.for_power,
+            against_power: proposal_v102.against_power,
+            abstain_power: Uint128::zero(),
+            no_with_veto_power: Uint128::zero(),
+            start_block: proposal_v102.start_block,
+            start_time: proposal_v102.start_time,
+            end_time,
+            effective_time,
+            expiration_time: effective_time + config.proposal_expiration_period,
+            title: proposal_v102.title,
+            description: proposal_v102.description,
+            link: proposal_v102.link,
+            content_hash: None,
+            messages: proposal_v102.messages,
+            deposit_amount: proposal_v102.deposit_amount,
+            execution_results: vec![],
+            ibc_message_statuses: vec![],
+        };
+
+        // The stored value has the v1.0.2 layout and no index entries yet
+        proposals().replace(
+            storage,
+            U64Key::new(proposal.proposal_id.u64()),
+            Some(&proposal),
+            None,
+        )?;
+
+        if !proposal_v102.for_voters.is_empty() || !proposal_v102.against_voters.is_empty() {
+            LEGACY_VOTERS.save(
+                storage,
+                U64Key::new(proposal_id),
+                &LegacyVoters {
+                    for_voters: proposal_v102.for_voters,
+                    against_voters: proposal_v102.against_voters,
+                },
+            )?;
+        }
+    }
+
+    if last_proposal_id == proposal_count {
+        PROPOSAL_MIGRATION_CURSOR.remove(storage);
+        return Ok(true);
+    }
+
+    PROPOSAL_MIGRATION_CURSOR.save(storage, &last_proposal_id)?;
+
+    Ok(false)
+}
@@ -1,6 +1,6 @@
-use astroport_governance::assembly::{Config, Proposal};
-use cosmwasm_std::Uint64;
-use cw_storage_plus::{Item, Map, U64Key};
+use astroport_governance::assembly::{ArchivedProposal, Config, Proposal, ProposalVote};
+use cosmwasm_std::{Addr, Empty, IbcChannel, Uint128, Uint64};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex, U64Key};
 
 /// ## Description
 /// Stores the config for the Assembly contract
@@ -11,5 +11,86 @@ pub const CONFIG: Item<Config> = Item::new("config");
 pub const PROPOSAL_COUNT: Item<Uint64> = Item::new("proposal_count");
 
 /// ## Description
-/// This is a map that contains information about all proposals
-pub const PROPOSALS: Map<U64Key, Proposal> = Map::new("proposals");
+/// This structure holds the secondary indexes of the proposal map.
+pub struct ProposalIndexes<'a> {
+    /// Index by proposal status. The key is (status, proposal_id)
+    pub status: MultiIndex<'a, (Vec<u8>, Vec<u8>), Proposal>,
+    /// Index by proposal submitter. The key is (submitter, proposal_id)
+    pub submitter: MultiIndex<'a, (Vec<u8>, Vec<u8>), Proposal>,
+}
+
+impl<'a> IndexList<Proposal> for ProposalIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Proposal>> + '_> {
+        let v: Vec<&dyn Index<Proposal>> = vec![&self.status, &self.submitter];
+        Box::new(v.into_iter())
+    }
+}
+
+/// ## Description
+/// Returns a map that contains information about all proposals, indexed by status and submitter.
+pub fn proposals<'a>() -> IndexedMap<'a, U64Key, Proposal, ProposalIndexes<'a>> {
+    let indexes = ProposalIndexes {
+        status: MultiIndex::new(
+            |proposal, pk| (proposal.status.to_string().into_bytes(), pk),
+            "proposals",
+            "proposals__status",
+        ),
+        submitter: MultiIndex::new(
+            |proposal, pk| (proposal.submitter.as_bytes().to_vec(), pk),
+            "proposals",
+            "proposals__submitter",
+        ),
+    };
+
+    IndexedMap::new("proposals", indexes)
+}
+
+/// ## Description
+/// This is a map that contains the vote cast by every voter on a specific proposal
+pub const PROPOSAL_VOTES: Map<(U64Key, &Addr), ProposalVote> = Map::new("proposal_votes");
+
+/// ## Description
+/// This is a map that contains the voting history of every user. The key is (voter, proposal_id).
+pub const USER_VOTES: Map<(&Addr, U64Key), ProposalVote> = Map::new("user_votes");
+
+/// ## Description
+/// This is a map that contains the voting power of all voters on a specific proposal, grouped by
+/// the vote option. The key is (proposal_id, vote option, voter).
+pub const PROPOSAL_VOTERS: Map<(U64Key, String, &Addr), Uint128> = Map::new("proposal_voters");
+
+/// ## Description
+/// This is a map that contains the address every user delegated their voting power to
+pub const DELEGATIONS: Map<&Addr, Addr> = Map::new("delegations");
+
+/// ## Description
+/// This is a map that contains the delegators of every delegatee. The key is (delegatee, delegator).
+pub const DELEGATORS: Map<(&Addr, &Addr), Empty> = Map::new("delegators");
+
+/// ## Description
+/// This is a map that contains delegations which override [`DELEGATIONS`] on a specific proposal.
+/// The key is (proposal_id, delegator).
+pub const PROPOSAL_DELEGATIONS: Map<(U64Key, &Addr), Addr> = Map::new("proposal_delegations");
+
+/// ## Description
+/// This is a map that contains the delegators of every delegatee on a specific proposal.
+/// The key is (proposal_id, delegatee, delegator).
+pub const PROPOSAL_DELEGATORS: Map<(U64Key, &Addr, &Addr), Empty> = Map::new("proposal_delegators");
+
+/// ## Description
+/// This is a map that contains the delegatee that used a delegator's voting power on a specific
+/// proposal. The key is (proposal_id, delegator).
+pub const DELEGATED_VOTES: Map<(U64Key, &Addr), Addr> = Map::new("delegated_votes");
+
+/// ## Description
+/// This is a map that contains the voting power of every delegator used by a delegatee on a specific
+/// proposal. The key is (proposal_id, delegatee, delegator).
+pub const DELEGATED_VOTE_POWERS: Map<(U64Key, &Addr, &Addr), Uint128> =
+    Map::new("delegated_vote_powers");
+
+/// ## Description
+/// This is a map that contains the summaries of the proposals removed from the proposal list
+pub const ARCHIVED_PROPOSALS: Map<U64Key, ArchivedProposal> = Map::new("archived_proposals");
+
+/// ## Description
+/// This is a map that contains the IBC channels opened by satellites. The key is the channel id.
+pub const IBC_CHANNELS: Map<&str, IbcChannel> = Map::new("ibc_channels");
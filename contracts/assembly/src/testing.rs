@@ -0,0 +1,460 @@
+use crate::contract::{
+    execute, execute_proposal, instantiate, migrate, query, remove_proposal_votes, reply,
+};
+use crate::ibc::{
+    ibc_channel_close, ibc_channel_connect, ibc_channel_open, ibc_packet_ack, ibc_packet_timeout,
+};
+use crate::migration::{
+    ConfigV102, MigrateMsg, ProposalV102, CONFIGV102, LEGACY_VOTERS, PROPOSALSV102,
+    PROPOSAL_MIGRATION_LIMIT,
+};
+use crate::state::{proposals, CONFIG, PROPOSAL_COUNT, PROPOSAL_VOTERS, PROPOSAL_VOTES};
+use astroport_governance::assembly::{
+    ExecuteMsg, IbcMessageStatus, IbcSatellite, InstantiateMsg, Proposal, ProposalIbcMessageStatus,
+    ProposalMessage, ProposalStatus, ProposalVote, ProposalVoteOption, QueryMsg,
+};
+use astroport_governance::satellite::{SatelliteAck, SatellitePacket, IBC_APP_VERSION};
+
+use cosmwasm_std::testing::{
+    mock_dependencies, mock_env, mock_ibc_channel, mock_ibc_channel_close_init,
+    mock_ibc_channel_connect_ack, mock_ibc_channel_open_try, mock_ibc_packet_ack,
+    mock_ibc_packet_timeout, mock_info, MockApi, MockQuerier, MockStorage,
+};
+use cosmwasm_std::{
+    attr, from_binary, to_binary, Addr, BankMsg, ContractResult, CosmosMsg, Decimal,
+    IbcAcknowledgement, IbcChannelOpenMsg, IbcMsg, IbcOrder, OwnedDeps, Reply, SubMsg,
+    SubMsgExecutionResponse, Uint128, Uint64,
+};
+use cw2::set_contract_version;
+use cw_storage_plus::U64Key;
+
+fn setup() -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+    let mut deps = mock_dependencies(&[]);
+
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        InstantiateMsg {
+            xastro_token_addr: "xastro".to_string(),
+            vxastro_token_addr: None,
+            builder_unlock_addr: "builder_unlock".to_string(),
+            proposal_voting_period: 500,
+            proposal_effective_delay: 86_400,
+            proposal_expiration_period: 604_800,
+            proposal_required_deposit: Uint128::from(1000u128),
+            proposal_required_quorum: "0.50".to_string(),
+            proposal_required_threshold: "0.60".to_string(),
+            proposal_required_veto_threshold: "0.334".to_string(),
+            expedited_proposal_required_deposit: Uint128::from(2000u128),
+            expedited_proposal_voting_period: 100,
+            expedited_proposal_required_threshold: "0.80".to_string(),
+            early_finalization_enabled: None,
+            proposal_required_vxastro_power: None,
+            quorum_failure_deposit_slash: "0".to_string(),
+            veto_deposit_slash: "1".to_string(),
+            slashed_deposit_receiver: None,
+            guardian: None,
+            timelock_addr: None,
+            proposal_validation: None,
+            voting_power_sources: None,
+            whitelisted_links: vec!["https://some.link/".to_string()],
+            proposal_types: None,
+        },
+    )
+    .unwrap();
+
+    // Allow the satellite used by the mock IBC channels
+    let mut config = CONFIG.load(deps.as_ref().storage).unwrap();
+    config.ibc_satellites.push(IbcSatellite {
+        connection_id: "connection-2".to_string(),
+        port_id: "their_port".to_string(),
+    });
+    CONFIG.save(deps.as_mut().storage, &config).unwrap();
+
+    deps
+}
+
+fn passed_proposal(messages: Vec<ProposalMessage>) -> Proposal {
+    let now = mock_env().block.time.seconds();
+
+    Proposal {
+        proposal_id: Uint64::from(1u64),
+        submitter: Addr::unchecked("submitter"),
+        status: ProposalStatus::Passed,
+        proposal_type: None,
+        expedited: false,
+        converted_from_expedited: false,
+        for_power: Uint128::from(1000u128),
+        against_power: Uint128::zero(),
+        abstain_power: Uint128::zero(),
+        no_with_veto_power: Uint128::zero(),
+        start_block: 1,
+        start_time: now - 1000,
+        end_time: now - 500,
+        effective_time: now,
+        expiration_time: now + 1000,
+        title: "Remote proposal".to_string(),
+        description: "Remote proposal description".to_string(),
+        link: None,
+        content_hash: None,
+        messages: Some(messages),
+        deposit_amount: Uint128::from(1000u128),
+        execution_results: vec![],
+        ibc_message_statuses: vec![],
+    }
+}
+
+#[test]
+fn satellite_channel_handshake() {
+    let mut deps = setup();
+
+    let mut channel = mock_ibc_channel("channel-1", IbcOrder::Unordered, IBC_APP_VERSION);
+    channel.connection_id = "connection-3".to_string();
+    let err = ibc_channel_open(
+        deps.as_mut(),
+        mock_env(),
+        IbcChannelOpenMsg::new_try(channel, IBC_APP_VERSION),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "IBC channels can only be opened by allowed satellites!"
+    );
+
+    let mut channel = mock_ibc_channel("channel-1", IbcOrder::Unordered, IBC_APP_VERSION);
+    channel.counterparty_endpoint.port_id = "other_port".to_string();
+    let err = ibc_channel_open(
+        deps.as_mut(),
+        mock_env(),
+        IbcChannelOpenMsg::new_try(channel, IBC_APP_VERSION),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "IBC channels can only be opened by allowed satellites!"
+    );
+
+    ibc_channel_open(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_open_try("channel-1", IbcOrder::Unordered, IBC_APP_VERSION),
+    )
+    .unwrap();
+
+    ibc_channel_connect(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_connect_ack("channel-1", IbcOrder::Unordered, IBC_APP_VERSION),
+    )
+    .unwrap();
+
+    let channels: Vec<String> =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::IbcChannels {}).unwrap()).unwrap();
+    assert_eq!(channels, vec!["channel-1".to_string()]);
+}
+
+#[test]
+fn execute_remote_proposal_messages() {
+    let mut deps = setup();
+
+    ibc_channel_connect(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_connect_ack("channel-1", IbcOrder::Unordered, IBC_APP_VERSION),
+    )
+    .unwrap();
+
+    let channels: Vec<String> =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::IbcChannels {}).unwrap()).unwrap();
+    assert_eq!(channels, vec!["channel-1".to_string()]);
+
+    let remote_msg = CosmosMsg::Bank(BankMsg::Send {
+        to_address: "recipient".to_string(),
+        amount: vec![],
+    });
+    let messages = vec![
+        ProposalMessage {
+            order: Uint64::from(1u64),
+            msg: remote_msg.clone(),
+            ibc_channel: Some("channel-1".to_string()),
+        },
+        ProposalMessage {
+            order: Uint64::from(2u64),
+            msg: remote_msg.clone(),
+            ibc_channel: Some("channel-1".to_string()),
+        },
+    ];
+    proposals()
+        .save(
+            &mut deps.storage,
+            U64Key::new(1),
+            &passed_proposal(messages),
+        )
+        .unwrap();
+
+    let res = execute_proposal(deps.as_mut(), mock_env(), mock_info("user", &[]), 1).unwrap();
+    let packet = SatellitePacket::ExecuteProposalMessage {
+        proposal_id: 1,
+        order: Uint64::from(1u64),
+        msg: remote_msg.clone(),
+    };
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::reply_always(
+            IbcMsg::SendPacket {
+                channel_id: "channel-1".to_string(),
+                data: to_binary(&packet).unwrap(),
+                timeout: mock_env().block.time.plus_seconds(86_400).into(),
+            },
+            1
+        )]
+    );
+
+    // The next message is sent once the first packet is dispatched
+    let res = reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: 1,
+            result: ContractResult::Ok(SubMsgExecutionResponse {
+                events: vec![],
+                data: None,
+            }),
+        },
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 1);
+
+    reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: 1,
+            result: ContractResult::Ok(SubMsgExecutionResponse {
+                events: vec![],
+                data: None,
+            }),
+        },
+    )
+    .unwrap();
+
+    let pending = |order: u64| ProposalIbcMessageStatus {
+        order: Uint64::from(order),
+        channel: "channel-1".to_string(),
+        status: IbcMessageStatus::Pending,
+        error: None,
+    };
+    let proposal = proposals().load(&deps.storage, U64Key::new(1)).unwrap();
+    assert_eq!(proposal.status, ProposalStatus::Executed);
+    assert_eq!(proposal.ibc_message_statuses, vec![pending(1), pending(2)]);
+
+    ibc_packet_ack(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_packet_ack(
+            "channel-1",
+            &packet,
+            IbcAcknowledgement::new(
+                to_binary(&SatelliteAck::Error("Insufficient funds".to_string())).unwrap(),
+            ),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    ibc_packet_timeout(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_packet_timeout(
+            "channel-1",
+            &SatellitePacket::ExecuteProposalMessage {
+                proposal_id: 1,
+                order: Uint64::from(2u64),
+                msg: remote_msg,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    let proposal = proposals().load(&deps.storage, U64Key::new(1)).unwrap();
+    assert_eq!(
+        proposal
+            .ibc_message_statuses
+            .iter()
+            .map(|message_status| (message_status.status.clone(), message_status.error.clone()))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                IbcMessageStatus::Failed,
+                Some("Insufficient funds".to_string())
+            ),
+            (IbcMessageStatus::TimedOut, None)
+        ]
+    );
+
+    ibc_channel_close(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_close_init("channel-1", IbcOrder::Unordered, IBC_APP_VERSION),
+    )
+    .unwrap();
+
+    let channels: Vec<String> =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::IbcChannels {}).unwrap()).unwrap();
+    assert!(channels.is_empty());
+}
+
+#[test]
+fn remove_proposal_votes_in_batches() {
+    let mut deps = setup();
+
+    for &(proposal_id, voters) in [(1u64, 2u64), (2u64, 3u64)].iter() {
+        for i in 0..voters {
+            let voter = Addr::unchecked(format!("voter{}", i));
+            PROPOSAL_VOTES
+                .save(
+                    deps.as_mut().storage,
+                    (U64Key::new(proposal_id), &voter),
+                    &ProposalVote {
+                        option: ProposalVoteOption::For,
+                        power: Uint128::from(100u128),
+                    },
+                )
+                .unwrap();
+            PROPOSAL_VOTERS
+                .save(
+                    deps.as_mut().storage,
+                    (U64Key::new(proposal_id), "for".to_string(), &voter),
+                    &Uint128::from(100u128),
+                )
+                .unwrap();
+        }
+    }
+
+    // Exactly `limit` entries are deleted
+    assert!(remove_proposal_votes(deps.as_mut().storage, 1, 2).unwrap());
+
+    assert!(!remove_proposal_votes(deps.as_mut().storage, 2, 2).unwrap());
+    assert!(remove_proposal_votes(deps.as_mut().storage, 2, 2).unwrap());
+}
+
+#[test]
+fn migrate_proposals_in_batches() {
+    let mut deps = mock_dependencies(&[]);
+    let env = mock_env();
+
+    set_contract_version(deps.as_mut().storage, "astro-assembly", "1.0.2").unwrap();
+    CONFIGV102
+        .save(
+            deps.as_mut().storage,
+            &ConfigV102 {
+                xastro_token_addr: Addr::unchecked("xastro"),
+                vxastro_token_addr: None,
+                builder_unlock_addr: Addr::unchecked("builder_unlock"),
+                proposal_voting_period: 100,
+                proposal_effective_delay: 100,
+                proposal_expiration_period: 100,
+                proposal_required_deposit: Uint128::from(1000u128),
+                proposal_required_quorum: Decimal::percent(10),
+                proposal_required_threshold: Decimal::percent(60),
+                whitelisted_links: vec!["https://some.link/".to_string()],
+            },
+        )
+        .unwrap();
+
+    let proposal_count = PROPOSAL_MIGRATION_LIMIT + 5;
+    for proposal_id in 1..=proposal_count {
+        PROPOSALSV102
+            .save(
+                deps.as_mut().storage,
+                U64Key::new(proposal_id),
+                &ProposalV102 {
+                    proposal_id: Uint64::from(proposal_id),
+                    submitter: Addr::unchecked("submitter"),
+                    status: ProposalStatus::Rejected,
+                    for_power: Uint128::from(100u128),
+                    against_power: Uint128::zero(),
+                    for_voters: vec![Addr::unchecked("voter")],
+                    against_voters: vec![],
+                    start_block: env.block.height - 200,
+                    start_time: env.block.time.seconds() - 1400,
+                    end_block: env.block.height - 100,
+                    title: "Legacy proposal".to_string(),
+                    description: "Legacy proposal description".to_string(),
+                    link: None,
+                    messages: None,
+                    deposit_amount: Uint128::from(1000u128),
+                },
+            )
+            .unwrap();
+    }
+    PROPOSAL_COUNT
+        .save(deps.as_mut().storage, &Uint64::from(proposal_count))
+        .unwrap();
+
+    migrate(
+        deps.as_mut(),
+        env.clone(),
+        MigrateMsg {
+            proposal_voting_period: 100,
+            proposal_effective_delay: 100,
+            whitelisted_links: vec![],
+        },
+    )
+    .unwrap();
+
+    let proposal = proposals()
+        .load(&deps.storage, U64Key::new(PROPOSAL_MIGRATION_LIMIT))
+        .unwrap();
+    assert_eq!(proposal.end_time, env.block.time.seconds() - 700);
+    assert!(LEGACY_VOTERS.has(&deps.storage, U64Key::new(PROPOSAL_MIGRATION_LIMIT)));
+    assert!(proposals()
+        .load(&deps.storage, U64Key::new(PROPOSAL_MIGRATION_LIMIT + 1))
+        .is_err());
+
+    // Other messages are rejected until all proposals are migrated
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("user", &[]),
+        ExecuteMsg::RemoveCompletedProposal { proposal_id: 1 },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Proposals created before v1.1.0 are still being migrated!"
+    );
+
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("user", &[]),
+        ExecuteMsg::MigrateProposals { limit: None },
+    )
+    .unwrap();
+    assert!(res.attributes.contains(&attr("migration_finished", "true")));
+
+    let proposal = proposals()
+        .load(&deps.storage, U64Key::new(proposal_count))
+        .unwrap();
+    assert_eq!(proposal.status, ProposalStatus::Rejected);
+
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("user", &[]),
+        ExecuteMsg::MigrateProposals { limit: None },
+    )
+    .unwrap_err();
+    assert_eq!(err.to_string(), "All proposals are already migrated!");
+
+    execute(
+        deps.as_mut(),
+        env,
+        mock_info("user", &[]),
+        ExecuteMsg::RemoveCompletedProposal { proposal_id: 1 },
+    )
+    .unwrap();
+}
@@ -4,24 +4,29 @@ use astroport::{
 use std::str::FromStr;
 
 use astroport_governance::assembly::{
-    Config, Cw20HookMsg, ExecuteMsg, InstantiateMsg, Proposal, ProposalListResponse,
-    ProposalMessage, ProposalStatus, ProposalVoteOption, ProposalVotesResponse, QueryMsg,
-    UpdateConfig,
+    ArchivedProposal, ArchivedProposalListResponse, Config, Cw20HookMsg, ExecuteMsg,
+    InstantiateMsg, Proposal, ProposalListResponse, ProposalMessage, ProposalMessageResult,
+    ProposalStatus, ProposalType, ProposalValidationConfig, ProposalVote, ProposalVoteOption,
+    ProposalVotesResponse, QueryMsg, UpdateConfig, UserVoteResponse, VotingPowerAdapter,
+    VotingPowerQueryMsg, VotingPowerSource, MAX_ACTIVE_PROPOSALS_PER_SUBMITTER, MAX_DELEGATORS,
 };
 
 use astroport_governance::voting_escrow::{
-    Cw20HookMsg as VXAstroCw20HookMsg, InstantiateMsg as VXAstroInstantiateMsg,
+    Cw20HookMsg as VXAstroCw20HookMsg, ExecuteMsg as VXAstroExecuteMsg,
+    InstantiateMsg as VXAstroInstantiateMsg, VotingPowerResponse,
 };
 
 use astroport_governance::builder_unlock::msg::{
     InstantiateMsg as BuilderUnlockInstantiateMsg, ReceiveMsg as BuilderUnlockReceiveMsg,
 };
 use astroport_governance::builder_unlock::{AllocationParams, Schedule};
+use astroport_governance::timelock::TransactionStatus;
 use astroport_governance::utils::{EPOCH_START, WEEK};
+use astroport_tests::timelock_helper::{TimelockHelper, TIMELOCK_DELAY};
 use cosmwasm_std::{
     testing::{mock_env, MockApi, MockStorage},
-    to_binary, Addr, CosmosMsg, Decimal, QueryRequest, StdResult, Timestamp, Uint128, Uint64,
-    WasmMsg, WasmQuery,
+    to_binary, Addr, Binary, CosmosMsg, Decimal, Deps, DepsMut, Empty, Env, MessageInfo,
+    QueryRequest, Response, StdResult, Timestamp, Uint128, Uint64, WasmMsg, WasmQuery,
 };
 use cw20::{BalanceResponse, Cw20ExecuteMsg, MinterResponse};
 use terra_multi_test::{
@@ -29,11 +34,17 @@ use terra_multi_test::{
 };
 
 const PROPOSAL_VOTING_PERIOD: u64 = 500;
-const PROPOSAL_EFFECTIVE_DELAY: u64 = 12_342;
-const PROPOSAL_EXPIRATION_PERIOD: u64 = 86_399;
+const PROPOSAL_EFFECTIVE_DELAY: u64 = 86_400;
+const PROPOSAL_EXPIRATION_PERIOD: u64 = 604_800;
 const PROPOSAL_REQUIRED_DEPOSIT: u128 = 1000u128;
 const PROPOSAL_REQUIRED_QUORUM: &str = "0.50";
 const PROPOSAL_REQUIRED_THRESHOLD: &str = "0.60";
+const PROPOSAL_REQUIRED_VETO_THRESHOLD: &str = "0.334";
+const EXPEDITED_PROPOSAL_REQUIRED_DEPOSIT: u128 = 2000u128;
+const EXPEDITED_PROPOSAL_VOTING_PERIOD: u64 = 100;
+const EXPEDITED_PROPOSAL_REQUIRED_THRESHOLD: &str = "0.80";
+const QUORUM_FAILURE_DEPOSIT_SLASH: &str = "0";
+const VETO_DEPOSIT_SLASH: &str = "1";
 
 #[test]
 fn test_contract_instantiation() {
@@ -47,11 +58,14 @@ fn test_contract_instantiation() {
     let vxastro_token_addr = instantiate_vxastro_token(&mut app, &owner, &xastro_token_addr);
     let builder_unlock_addr = instantiate_builder_unlock_contract(&mut app, &owner, &token_addr);
 
-    let assembly_contract = Box::new(ContractWrapper::new_with_empty(
-        astro_assembly::contract::execute,
-        astro_assembly::contract::instantiate,
-        astro_assembly::contract::query,
-    ));
+    let assembly_contract = Box::new(
+        ContractWrapper::new_with_empty(
+            astro_assembly::contract::execute,
+            astro_assembly::contract::instantiate,
+            astro_assembly::contract::query,
+        )
+        .with_reply_empty(astro_assembly::contract::reply),
+    );
 
     let assembly_code = app.store_code(assembly_contract);
 
@@ -65,7 +79,21 @@ fn test_contract_instantiation() {
         proposal_required_deposit: Uint128::from(PROPOSAL_REQUIRED_DEPOSIT),
         proposal_required_quorum: String::from(PROPOSAL_REQUIRED_QUORUM),
         proposal_required_threshold: String::from(PROPOSAL_REQUIRED_THRESHOLD),
+        proposal_required_veto_threshold: String::from(PROPOSAL_REQUIRED_VETO_THRESHOLD),
+        expedited_proposal_required_deposit: Uint128::from(EXPEDITED_PROPOSAL_REQUIRED_DEPOSIT),
+        expedited_proposal_voting_period: EXPEDITED_PROPOSAL_VOTING_PERIOD,
+        expedited_proposal_required_threshold: String::from(EXPEDITED_PROPOSAL_REQUIRED_THRESHOLD),
+        early_finalization_enabled: None,
+        proposal_required_vxastro_power: None,
+        quorum_failure_deposit_slash: String::from(QUORUM_FAILURE_DEPOSIT_SLASH),
+        veto_deposit_slash: String::from(VETO_DEPOSIT_SLASH),
+        slashed_deposit_receiver: None,
+        guardian: None,
+        timelock_addr: None,
+        proposal_validation: None,
+        voting_power_sources: None,
         whitelisted_links: vec!["https://some.link/".to_string()],
+        proposal_types: None,
     };
 
     // Try to instantiate assembly with wrong threshold
@@ -126,6 +154,83 @@ fn test_contract_instantiation() {
         "Generic error: The required quorum for a proposal cannot be higher than 100%"
     );
 
+    let res = app
+        .instantiate_contract(
+            assembly_code,
+            owner.clone(),
+            &InstantiateMsg {
+                proposal_required_veto_threshold: "1.1".to_string(),
+                ..assembly_default_instantiate_msg.clone()
+            },
+            &[],
+            "Assembly".to_string(),
+            Some(owner.to_string()),
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        res.to_string(),
+        "Generic error: The required veto threshold for a proposal cannot be lower than 10% or higher than 100%"
+    );
+
+    // A veto threshold close to zero would let a tiny minority veto any proposal
+    let res = app
+        .instantiate_contract(
+            assembly_code,
+            owner.clone(),
+            &InstantiateMsg {
+                proposal_required_veto_threshold: "0.05".to_string(),
+                ..assembly_default_instantiate_msg.clone()
+            },
+            &[],
+            "Assembly".to_string(),
+            Some(owner.to_string()),
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        res.to_string(),
+        "Generic error: The required veto threshold for a proposal cannot be lower than 10% or higher than 100%"
+    );
+
+    let res = app
+        .instantiate_contract(
+            assembly_code,
+            owner.clone(),
+            &InstantiateMsg {
+                expedited_proposal_required_threshold: "0.5".to_string(),
+                ..assembly_default_instantiate_msg.clone()
+            },
+            &[],
+            "Assembly".to_string(),
+            Some(owner.to_string()),
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        res.to_string(),
+        "Generic error: The required threshold for an expedited proposal cannot be lower than the regular threshold or higher than 100%"
+    );
+
+    let res = app
+        .instantiate_contract(
+            assembly_code,
+            owner.clone(),
+            &InstantiateMsg {
+                quorum_failure_deposit_slash: "1.1".to_string(),
+                ..assembly_default_instantiate_msg.clone()
+            },
+            &[],
+            "Assembly".to_string(),
+            Some(owner.to_string()),
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        res.to_string(),
+        "Generic error: The slashed share of a proposal deposit cannot be higher than 100%"
+    );
+
     let res = app
         .instantiate_contract(
             assembly_code,
@@ -142,7 +247,7 @@ fn test_contract_instantiation() {
 
     assert_eq!(
         res.to_string(),
-        "Generic error: The expiration period for a proposal cannot be less than 86399 blocks."
+        "Generic error: The expiration period for a proposal cannot be less than 604800 seconds."
     );
 
     let res = app
@@ -161,7 +266,51 @@ fn test_contract_instantiation() {
 
     assert_eq!(
         res.to_string(),
-        "Generic error: The effective delay for a proposal cannot be less than 12342 blocks."
+        "Generic error: The effective delay for a proposal cannot be less than 86400 seconds."
+    );
+
+    let res = app
+        .instantiate_contract(
+            assembly_code,
+            owner.clone(),
+            &InstantiateMsg {
+                proposal_validation: Some(ProposalValidationConfig {
+                    max_description_length: 16_385,
+                    ..ProposalValidationConfig::default()
+                }),
+                ..assembly_default_instantiate_msg.clone()
+            },
+            &[],
+            "Assembly".to_string(),
+            Some(owner.to_string()),
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        res.to_string(),
+        "Generic error: The description length limits must be between 1 and 16384 with the minimum not exceeding the maximum"
+    );
+
+    let res = app
+        .instantiate_contract(
+            assembly_code,
+            owner.clone(),
+            &InstantiateMsg {
+                proposal_validation: Some(ProposalValidationConfig {
+                    min_title_length: 65,
+                    ..ProposalValidationConfig::default()
+                }),
+                ..assembly_default_instantiate_msg.clone()
+            },
+            &[],
+            "Assembly".to_string(),
+            Some(owner.to_string()),
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        res.to_string(),
+        "Generic error: The title length limits must be between 1 and 256 with the minimum not exceeding the maximum"
     );
 
     let assembly_instance = app
@@ -197,6 +346,33 @@ fn test_contract_instantiation() {
         res.proposal_required_threshold,
         Decimal::from_str(PROPOSAL_REQUIRED_THRESHOLD).unwrap()
     );
+    assert_eq!(
+        res.proposal_required_veto_threshold,
+        Decimal::from_str(PROPOSAL_REQUIRED_VETO_THRESHOLD).unwrap()
+    );
+    assert_eq!(
+        res.quorum_failure_deposit_slash,
+        Decimal::from_str(QUORUM_FAILURE_DEPOSIT_SLASH).unwrap()
+    );
+    assert_eq!(
+        res.veto_deposit_slash,
+        Decimal::from_str(VETO_DEPOSIT_SLASH).unwrap()
+    );
+    assert_eq!(
+        res.expedited_proposal_required_deposit,
+        Uint128::from(EXPEDITED_PROPOSAL_REQUIRED_DEPOSIT)
+    );
+    assert_eq!(
+        res.expedited_proposal_voting_period,
+        EXPEDITED_PROPOSAL_VOTING_PERIOD
+    );
+    assert_eq!(
+        res.expedited_proposal_required_threshold,
+        Decimal::from_str(EXPEDITED_PROPOSAL_REQUIRED_THRESHOLD).unwrap()
+    );
+    assert_eq!(res.slashed_deposit_receiver, None);
+    assert_eq!(res.guardian, None);
+    assert_eq!(res.proposal_validation, ProposalValidationConfig::default());
     assert_eq!(
         res.whitelisted_links,
         vec!["https://some.link/".to_string(),]
@@ -205,6 +381,10 @@ fn test_contract_instantiation() {
 
 #[test]
 fn test_proposal_submitting() {
+    const DESCRIPTION: &str =
+        "Предложение\n\n| Parameter | Value |\n| --- | --- |\n| `proposal_voting_period` | 750 |";
+    const CONTENT_HASH: &str = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+
     let mut app = mock_app();
 
     let owner = Addr::unchecked("owner");
@@ -220,6 +400,9 @@ fn test_proposal_submitting() {
             &QueryMsg::Proposals {
                 start: None,
                 limit: None,
+                status: None,
+                submitter: None,
+                descending: None,
             },
         )
         .unwrap();
@@ -238,7 +421,10 @@ fn test_proposal_submitting() {
             title: String::from("Title"),
             description: String::from("Description"),
             link: Some(String::from("https://some.link")),
+            content_hash: None,
             messages: None,
+            proposal_type: None,
+            expedited: None,
         })
         .unwrap(),
         amount: Uint128::from(PROPOSAL_REQUIRED_DEPOSIT - 1),
@@ -261,7 +447,10 @@ fn test_proposal_submitting() {
                     title: String::from("X"),
                     description: String::from("Description"),
                     link: Some(String::from("https://some.link/")),
+                    content_hash: None,
                     messages: None,
+                    proposal_type: None,
+                    expedited: None,
                 })
                 .unwrap(),
                 amount: Uint128::from(1000u128),
@@ -282,7 +471,10 @@ fn test_proposal_submitting() {
                     title: String::from_utf8(vec![b'X'; 65]).unwrap(),
                     description: String::from("Description"),
                     link: Some(String::from("https://some.link/")),
+                    content_hash: None,
                     messages: None,
+                    proposal_type: None,
+                    expedited: None,
                 })
                 .unwrap(),
                 amount: Uint128::from(1000u128),
@@ -304,7 +496,10 @@ fn test_proposal_submitting() {
                     title: String::from("Title"),
                     description: String::from("X"),
                     link: Some(String::from("https://some.link/")),
+                    content_hash: None,
                     messages: None,
+                    proposal_type: None,
+                    expedited: None,
                 })
                 .unwrap(),
                 amount: Uint128::from(1000u128),
@@ -323,9 +518,12 @@ fn test_proposal_submitting() {
                 contract: assembly_addr.to_string(),
                 msg: to_binary(&Cw20HookMsg::SubmitProposal {
                     title: String::from("Title"),
-                    description: String::from_utf8(vec![b'X'; 1025]).unwrap(),
+                    description: String::from_utf8(vec![b'X'; 4097]).unwrap(),
                     link: Some(String::from("https://some.link/")),
+                    content_hash: None,
                     messages: None,
+                    proposal_type: None,
+                    expedited: None,
                 })
                 .unwrap(),
                 amount: Uint128::from(1000u128),
@@ -336,6 +534,89 @@ fn test_proposal_submitting() {
 
     assert_eq!(res.to_string(), "Generic error: Description too long!");
 
+    // Bidirectional override characters are not allowed
+    let res = app
+        .execute_contract(
+            user.clone(),
+            xastro_addr.clone(),
+            &Cw20ExecuteMsg::Send {
+                contract: assembly_addr.to_string(),
+                msg: to_binary(&Cw20HookMsg::SubmitProposal {
+                    title: String::from("Title"),
+                    description: String::from("Description \u{202E}txt.exe"),
+                    link: None,
+                    content_hash: None,
+                    messages: None,
+                    proposal_type: None,
+                    expedited: None,
+                })
+                .unwrap(),
+                amount: Uint128::from(1000u128),
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        res.to_string(),
+        "Generic error: Description contains unsafe characters!"
+    );
+
+    // Try to create a proposal with wrong content hash
+    let res = app
+        .execute_contract(
+            user.clone(),
+            xastro_addr.clone(),
+            &Cw20ExecuteMsg::Send {
+                contract: assembly_addr.to_string(),
+                msg: to_binary(&Cw20HookMsg::SubmitProposal {
+                    title: String::from("Title"),
+                    description: String::from("Description"),
+                    link: None,
+                    content_hash: Some(String::from(CONTENT_HASH)),
+                    messages: None,
+                    proposal_type: None,
+                    expedited: None,
+                })
+                .unwrap(),
+                amount: Uint128::from(1000u128),
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        res.to_string(),
+        "Generic error: Content hash can only be set along with a link!"
+    );
+
+    let res = app
+        .execute_contract(
+            user.clone(),
+            xastro_addr.clone(),
+            &Cw20ExecuteMsg::Send {
+                contract: assembly_addr.to_string(),
+                msg: to_binary(&Cw20HookMsg::SubmitProposal {
+                    title: String::from("Title"),
+                    description: String::from("Description"),
+                    link: Some(String::from("https://some.link/")),
+                    content_hash: Some(String::from("0x1234")),
+                    messages: None,
+                    proposal_type: None,
+                    expedited: None,
+                })
+                .unwrap(),
+                amount: Uint128::from(1000u128),
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        res.to_string(),
+        "Generic error: Content hash must be a hex-encoded SHA-256 hash!"
+    );
+
     // Try to create a proposal with wrong link
     let res = app
         .execute_contract(
@@ -347,7 +628,10 @@ fn test_proposal_submitting() {
                     title: String::from("Title"),
                     description: String::from("Description"),
                     link: Some(String::from("X")),
+                    content_hash: None,
                     messages: None,
+                    proposal_type: None,
+                    expedited: None,
                 })
                 .unwrap(),
                 amount: Uint128::from(1000u128),
@@ -368,7 +652,10 @@ fn test_proposal_submitting() {
                     title: String::from("Title"),
                     description: String::from("Description"),
                     link: Some(String::from_utf8(vec![b'X'; 129]).unwrap()),
+                    content_hash: None,
                     messages: None,
+                    proposal_type: None,
+                    expedited: None,
                 })
                 .unwrap(),
                 amount: Uint128::from(1000u128),
@@ -389,7 +676,10 @@ fn test_proposal_submitting() {
                     title: String::from("Title"),
                     description: String::from("Description"),
                     link: Some(String::from("https://some1.link")),
+                    content_hash: None,
                     messages: None,
+                    proposal_type: None,
+                    expedited: None,
                 })
                 .unwrap(),
                 amount: Uint128::from(1000u128),
@@ -412,7 +702,10 @@ fn test_proposal_submitting() {
                     link: Some(String::from(
                         "https://some.link/<script>alert('test');</script>",
                     )),
+                    content_hash: None,
                     messages: None,
+                    proposal_type: None,
+                    expedited: None,
                 })
                 .unwrap(),
                 amount: Uint128::from(1000u128),
@@ -434,10 +727,12 @@ fn test_proposal_submitting() {
             contract: assembly_addr.to_string(),
             msg: to_binary(&Cw20HookMsg::SubmitProposal {
                 title: String::from("Title"),
-                description: String::from("Description"),
+                description: String::from(DESCRIPTION),
                 link: Some(String::from("https://some.link/q/")),
+                content_hash: Some(String::from(CONTENT_HASH)),
                 messages: Some(vec![ProposalMessage {
                     order: Uint64::from(0u32),
+                    ibc_channel: None,
                     msg: CosmosMsg::Wasm(WasmMsg::Execute {
                         contract_addr: assembly_addr.to_string(),
                         msg: to_binary(&ExecuteMsg::UpdateConfig(UpdateConfig {
@@ -450,13 +745,32 @@ fn test_proposal_submitting() {
                             proposal_required_deposit: None,
                             proposal_required_quorum: None,
                             proposal_required_threshold: None,
+                            proposal_required_veto_threshold: None,
+                            expedited_proposal_required_deposit: None,
+                            expedited_proposal_voting_period: None,
+                            expedited_proposal_required_threshold: None,
+                            early_finalization_enabled: None,
+                            proposal_required_vxastro_power: None,
+                            quorum_failure_deposit_slash: None,
+                            veto_deposit_slash: None,
+                            slashed_deposit_receiver: None,
+                            guardian: None,
+                            timelock_addr: None,
+                            proposal_validation: None,
+                            voting_power_sources: None,
                             whitelist_add: None,
                             whitelist_remove: None,
+                            proposal_types_add: None,
+                            proposal_types_remove: None,
+                            ibc_satellites_add: None,
+                            ibc_satellites_remove: None,
                         }))
                         .unwrap(),
                         funds: vec![],
                     }),
                 }]),
+                proposal_type: None,
+                expedited: None,
             })
             .unwrap(),
             amount: Uint128::from(1000u128),
@@ -478,17 +792,27 @@ fn test_proposal_submitting() {
     assert_eq!(proposal.status, ProposalStatus::Active);
     assert_eq!(proposal.for_power, Uint128::zero());
     assert_eq!(proposal.against_power, Uint128::zero());
-    assert_eq!(proposal.for_voters, Vec::<Addr>::new());
-    assert_eq!(proposal.against_voters, Vec::<Addr>::new());
+    assert_eq!(proposal.abstain_power, Uint128::zero());
+    assert_eq!(proposal.no_with_veto_power, Uint128::zero());
     assert_eq!(proposal.start_block, 12_345);
-    assert_eq!(proposal.end_block, 12_345 + 500);
+    assert_eq!(proposal.end_time, proposal.start_time + 500);
+    assert_eq!(
+        proposal.effective_time,
+        proposal.end_time + PROPOSAL_EFFECTIVE_DELAY
+    );
+    assert_eq!(
+        proposal.expiration_time,
+        proposal.effective_time + PROPOSAL_EXPIRATION_PERIOD
+    );
     assert_eq!(proposal.title, String::from("Title"));
-    assert_eq!(proposal.description, String::from("Description"));
+    assert_eq!(proposal.description, String::from(DESCRIPTION));
     assert_eq!(proposal.link, Some(String::from("https://some.link/q/")));
+    assert_eq!(proposal.content_hash, Some(String::from(CONTENT_HASH)));
     assert_eq!(
         proposal.messages,
         Some(vec![ProposalMessage {
             order: Uint64::from(0u32),
+            ibc_channel: None,
             msg: CosmosMsg::Wasm(WasmMsg::Execute {
                 contract_addr: assembly_addr.to_string(),
                 msg: to_binary(&ExecuteMsg::UpdateConfig(UpdateConfig {
@@ -501,15 +825,128 @@ fn test_proposal_submitting() {
                     proposal_required_deposit: None,
                     proposal_required_quorum: None,
                     proposal_required_threshold: None,
+                    proposal_required_veto_threshold: None,
+                    expedited_proposal_required_deposit: None,
+                    expedited_proposal_voting_period: None,
+                    expedited_proposal_required_threshold: None,
+                    early_finalization_enabled: None,
+                    proposal_required_vxastro_power: None,
+                    quorum_failure_deposit_slash: None,
+                    veto_deposit_slash: None,
+                    slashed_deposit_receiver: None,
+                    guardian: None,
+                    timelock_addr: None,
+                    proposal_validation: None,
+                    voting_power_sources: None,
                     whitelist_add: None,
                     whitelist_remove: None,
+                    proposal_types_add: None,
+                    proposal_types_remove: None,
+                    ibc_satellites_add: None,
+                    ibc_satellites_remove: None,
                 }))
                 .unwrap(),
                 funds: vec![],
             }),
         }])
     );
-    assert_eq!(proposal.deposit_amount, Uint128::from(1000u64))
+    assert_eq!(proposal.deposit_amount, Uint128::from(1000u64));
+
+    // Text validation limits are updated through the config
+    let update_config = UpdateConfig {
+        xastro_token_addr: None,
+        vxastro_token_addr: None,
+        builder_unlock_addr: None,
+        proposal_voting_period: None,
+        proposal_effective_delay: None,
+        proposal_expiration_period: None,
+        proposal_required_deposit: None,
+        proposal_required_quorum: None,
+        proposal_required_threshold: None,
+        proposal_required_veto_threshold: None,
+        expedited_proposal_required_deposit: None,
+        expedited_proposal_voting_period: None,
+        expedited_proposal_required_threshold: None,
+        early_finalization_enabled: None,
+        proposal_required_vxastro_power: None,
+        quorum_failure_deposit_slash: None,
+        veto_deposit_slash: None,
+        slashed_deposit_receiver: None,
+        guardian: None,
+        timelock_addr: None,
+        proposal_validation: Some(ProposalValidationConfig {
+            min_link_length: 129,
+            ..ProposalValidationConfig::default()
+        }),
+        voting_power_sources: None,
+        whitelist_add: None,
+        whitelist_remove: None,
+        proposal_types_add: None,
+        proposal_types_remove: None,
+        ibc_satellites_add: None,
+        ibc_satellites_remove: None,
+    };
+
+    let res = app
+        .execute_contract(
+            assembly_addr.clone(),
+            assembly_addr.clone(),
+            &ExecuteMsg::UpdateConfig(update_config.clone()),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        res.to_string(),
+        "Generic error: The link length limits must be between 1 and 512 with the minimum not exceeding the maximum"
+    );
+
+    app.execute_contract(
+        assembly_addr.clone(),
+        assembly_addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfig {
+            proposal_validation: Some(ProposalValidationConfig {
+                max_title_length: 128,
+                ..ProposalValidationConfig::default()
+            }),
+            ..update_config
+        }),
+        &[],
+    )
+    .unwrap();
+
+    let title = String::from_utf8(vec![b'X'; 100]).unwrap();
+
+    app.execute_contract(
+        user.clone(),
+        xastro_addr.clone(),
+        &Cw20ExecuteMsg::Send {
+            contract: assembly_addr.to_string(),
+            msg: to_binary(&Cw20HookMsg::SubmitProposal {
+                title: title.clone(),
+                description: String::from("Description"),
+                link: None,
+                content_hash: None,
+                messages: None,
+                proposal_type: None,
+                expedited: None,
+            })
+            .unwrap(),
+            amount: Uint128::from(1000u128),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let proposal: Proposal = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::Proposal { proposal_id: 2 },
+        )
+        .unwrap();
+
+    assert_eq!(proposal.title, title);
 }
 
 #[test]
@@ -609,8 +1046,11 @@ fn test_successful_proposal() {
 
     create_allocations(&mut app, token_addr, builder_unlock_addr, locked_balances);
 
-    // Skip block
-    app.update_block(next_block);
+    // vxASTRO voting power is read at the end of the last week before the proposal start
+    app.update_block(|bi| {
+        bi.height += 1;
+        bi.time = bi.time.plus_seconds(WEEK);
+    });
 
     // Create default proposal
     create_proposal(
@@ -620,6 +1060,7 @@ fn test_successful_proposal() {
         Addr::unchecked("user0"),
         Some(vec![ProposalMessage {
             order: Uint64::from(0u32),
+            ibc_channel: None,
             msg: CosmosMsg::Wasm(WasmMsg::Execute {
                 contract_addr: assembly_addr.to_string(),
                 msg: to_binary(&ExecuteMsg::UpdateConfig(UpdateConfig {
@@ -632,11 +1073,28 @@ fn test_successful_proposal() {
                     proposal_required_deposit: None,
                     proposal_required_quorum: None,
                     proposal_required_threshold: None,
+                    proposal_required_veto_threshold: None,
+                    expedited_proposal_required_deposit: None,
+                    expedited_proposal_voting_period: None,
+                    expedited_proposal_required_threshold: None,
+                    early_finalization_enabled: None,
+                    proposal_required_vxastro_power: None,
+                    quorum_failure_deposit_slash: None,
+                    veto_deposit_slash: None,
+                    slashed_deposit_receiver: None,
+                    guardian: None,
+                    timelock_addr: None,
+                    proposal_validation: None,
+                    voting_power_sources: None,
                     whitelist_add: Some(vec![
                         "https://some1.link/".to_string(),
                         "https://some2.link/".to_string(),
                     ]),
                     whitelist_remove: Some(vec!["https://some.link/".to_string()]),
+                    proposal_types_add: None,
+                    proposal_types_remove: None,
+                    ibc_satellites_add: None,
+                    ibc_satellites_remove: None,
                 }))
                 .unwrap(),
                 funds: vec![],
@@ -690,31 +1148,123 @@ fn test_successful_proposal() {
     assert_eq!(proposal_votes.for_power, Uint128::from(2500u32));
     assert_eq!(proposal_votes.against_power, Uint128::from(650u32));
 
+    // Check proposal voters
+    let for_voters: Vec<(Addr, Uint128)> = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::ProposalVoters {
+                proposal_id: 1,
+                option: ProposalVoteOption::For,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+
     assert_eq!(
-        proposal.for_voters,
-        vec![
-            Addr::unchecked("user1"),
-            Addr::unchecked("user2"),
-            Addr::unchecked("user3"),
-            Addr::unchecked("user4"),
-            Addr::unchecked("user5"),
-            Addr::unchecked("user6"),
-            Addr::unchecked("user7")
-        ]
-    );
-    assert_eq!(
-        proposal.against_voters,
+        for_voters,
         vec![
-            Addr::unchecked("user8"),
-            Addr::unchecked("user9"),
-            Addr::unchecked("user10")
+            (Addr::unchecked("user1"), Uint128::from(280u32)),
+            (Addr::unchecked("user2"), Uint128::from(350u32)),
+            (Addr::unchecked("user3"), Uint128::from(550u32)),
+            (Addr::unchecked("user4"), Uint128::from(350u32)),
+            (Addr::unchecked("user5"), Uint128::from(240u32)),
+            (Addr::unchecked("user6"), Uint128::from(600u32)),
+            (Addr::unchecked("user7"), Uint128::from(130u32)),
         ]
     );
 
-    // Skip voting period
-    app.update_block(|bi| {
-        bi.height += PROPOSAL_VOTING_PERIOD + 1;
-        bi.time = bi.time.plus_seconds(5 * (PROPOSAL_VOTING_PERIOD + 1));
+    let against_voters: Vec<(Addr, Uint128)> = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::ProposalVoters {
+                proposal_id: 1,
+                option: ProposalVoteOption::Against,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+
+    // Voters are sorted by address
+    assert_eq!(
+        against_voters,
+        vec![
+            (Addr::unchecked("user10"), Uint128::from(270u32)),
+            (Addr::unchecked("user8"), Uint128::from(330u32)),
+            (Addr::unchecked("user9"), Uint128::from(50u32)),
+        ]
+    );
+
+    // Check pagination
+    let against_voters: Vec<(Addr, Uint128)> = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::ProposalVoters {
+                proposal_id: 1,
+                option: ProposalVoteOption::Against,
+                start_after: Some("user10".to_string()),
+                limit: Some(1),
+            },
+        )
+        .unwrap();
+
+    assert_eq!(
+        against_voters,
+        vec![(Addr::unchecked("user8"), Uint128::from(330u32))]
+    );
+
+    let user_vote: Option<ProposalVote> = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::UserVote {
+                proposal_id: 1,
+                user: "user9".to_string(),
+            },
+        )
+        .unwrap();
+
+    assert_eq!(
+        user_vote,
+        Some(ProposalVote {
+            option: ProposalVoteOption::Against,
+            power: Uint128::from(50u32),
+        })
+    );
+
+    let user_vote: Option<ProposalVote> = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::UserVote {
+                proposal_id: 1,
+                user: "user11".to_string(),
+            },
+        )
+        .unwrap();
+
+    assert_eq!(user_vote, None);
+
+    // Try to vote twice with the same option
+    let res = cast_vote(
+        &mut app,
+        assembly_addr.clone(),
+        1,
+        Addr::unchecked("user1"),
+        ProposalVoteOption::For,
+    )
+    .unwrap_err();
+
+    assert_eq!(res.to_string(), "User already voted!");
+
+    // Skip voting period
+    app.update_block(|bi| {
+        bi.height += (PROPOSAL_VOTING_PERIOD + 1) / 5;
+        bi.time = bi.time.plus_seconds(PROPOSAL_VOTING_PERIOD + 1);
     });
 
     // Try to vote after voting period
@@ -790,8 +1340,8 @@ fn test_successful_proposal() {
 
     // Skip blocks
     app.update_block(|bi| {
-        bi.height += PROPOSAL_EFFECTIVE_DELAY + 1;
-        bi.time = bi.time.plus_seconds(5 * (PROPOSAL_EFFECTIVE_DELAY + 1));
+        bi.height += (PROPOSAL_EFFECTIVE_DELAY + 1) / 5;
+        bi.time = bi.time.plus_seconds(PROPOSAL_EFFECTIVE_DELAY + 1);
     });
 
     // Try to execute the proposal after the delay
@@ -826,6 +1376,13 @@ fn test_successful_proposal() {
         ]
     );
     assert_eq!(proposal.status, ProposalStatus::Executed);
+    assert_eq!(
+        proposal.execution_results,
+        vec![ProposalMessageResult {
+            order: Uint64::from(0u32),
+            error: None,
+        }]
+    );
 
     // Try to remove proposal before expiration period
     let res = app
@@ -841,8 +1398,8 @@ fn test_successful_proposal() {
 
     // Remove expired proposal
     app.update_block(|bi| {
-        bi.height += PROPOSAL_EXPIRATION_PERIOD + 1;
-        bi.time = bi.time.plus_seconds(5 * (PROPOSAL_EXPIRATION_PERIOD + 1));
+        bi.height += (PROPOSAL_EXPIRATION_PERIOD + 1) / 5;
+        bi.time = bi.time.plus_seconds(PROPOSAL_EXPIRATION_PERIOD + 1);
     });
 
     app.execute_contract(
@@ -860,6 +1417,9 @@ fn test_successful_proposal() {
             &QueryMsg::Proposals {
                 start: None,
                 limit: None,
+                status: None,
+                submitter: None,
+                descending: None,
             },
         )
         .unwrap();
@@ -906,6 +1466,7 @@ fn test_voting_power_changes() {
         Addr::unchecked("user0"),
         Some(vec![ProposalMessage {
             order: Uint64::from(0u32),
+            ibc_channel: None,
             msg: CosmosMsg::Wasm(WasmMsg::Execute {
                 contract_addr: assembly_addr.to_string(),
                 msg: to_binary(&ExecuteMsg::UpdateConfig(UpdateConfig {
@@ -918,8 +1479,25 @@ fn test_voting_power_changes() {
                     proposal_required_deposit: None,
                     proposal_required_quorum: None,
                     proposal_required_threshold: None,
+                    proposal_required_veto_threshold: None,
+                    expedited_proposal_required_deposit: None,
+                    expedited_proposal_voting_period: None,
+                    expedited_proposal_required_threshold: None,
+                    early_finalization_enabled: None,
+                    proposal_required_vxastro_power: None,
+                    quorum_failure_deposit_slash: None,
+                    veto_deposit_slash: None,
+                    slashed_deposit_receiver: None,
+                    guardian: None,
+                    timelock_addr: None,
+                    proposal_validation: None,
+                    voting_power_sources: None,
                     whitelist_add: None,
                     whitelist_remove: None,
+                    proposal_types_add: None,
+                    proposal_types_remove: None,
+                    ibc_satellites_add: None,
+                    ibc_satellites_remove: None,
                 }))
                 .unwrap(),
                 funds: vec![],
@@ -964,10 +1542,10 @@ fn test_voting_power_changes() {
 
     // Skip voting period and delay
     app.update_block(|bi| {
-        bi.height += PROPOSAL_VOTING_PERIOD + PROPOSAL_EFFECTIVE_DELAY + 1;
+        bi.height += (PROPOSAL_VOTING_PERIOD + PROPOSAL_EFFECTIVE_DELAY + 1) / 5;
         bi.time = bi
             .time
-            .plus_seconds(5 * (PROPOSAL_VOTING_PERIOD + PROPOSAL_EFFECTIVE_DELAY + 1));
+            .plus_seconds(PROPOSAL_VOTING_PERIOD + PROPOSAL_EFFECTIVE_DELAY + 1);
     });
 
     // End proposal
@@ -1060,10 +1638,10 @@ fn test_block_height_selection() {
 
     // Skip voting period
     app.update_block(|bi| {
-        bi.height += PROPOSAL_VOTING_PERIOD + PROPOSAL_EFFECTIVE_DELAY + 1;
+        bi.height += (PROPOSAL_VOTING_PERIOD + PROPOSAL_EFFECTIVE_DELAY + 1) / 5;
         bi.time = bi
             .time
-            .plus_seconds(5 * (PROPOSAL_VOTING_PERIOD + PROPOSAL_EFFECTIVE_DELAY + 1));
+            .plus_seconds(PROPOSAL_VOTING_PERIOD + PROPOSAL_EFFECTIVE_DELAY + 1);
     });
 
     // End proposal
@@ -1164,8 +1742,8 @@ fn test_unsuccessful_proposal() {
 
     // Skip voting period
     app.update_block(|bi| {
-        bi.height += PROPOSAL_VOTING_PERIOD + 1;
-        bi.time = bi.time.plus_seconds(5 * (PROPOSAL_VOTING_PERIOD + 1));
+        bi.height += (PROPOSAL_VOTING_PERIOD + 1) / 5;
+        bi.time = bi.time.plus_seconds(PROPOSAL_VOTING_PERIOD + 1);
     });
 
     // Check balance of submitter before and after proposal completion
@@ -1194,10 +1772,10 @@ fn test_unsuccessful_proposal() {
 
     // Remove expired proposal
     app.update_block(|bi| {
-        bi.height += PROPOSAL_EXPIRATION_PERIOD + PROPOSAL_EFFECTIVE_DELAY + 1;
+        bi.height += (PROPOSAL_EXPIRATION_PERIOD + PROPOSAL_EFFECTIVE_DELAY + 1) / 5;
         bi.time = bi
             .time
-            .plus_seconds(5 * (PROPOSAL_EXPIRATION_PERIOD + PROPOSAL_EFFECTIVE_DELAY + 1));
+            .plus_seconds(PROPOSAL_EXPIRATION_PERIOD + PROPOSAL_EFFECTIVE_DELAY + 1);
     });
 
     app.execute_contract(
@@ -1215,6 +1793,9 @@ fn test_unsuccessful_proposal() {
             &QueryMsg::Proposals {
                 start: None,
                 limit: None,
+                status: None,
+                submitter: None,
+                descending: None,
             },
         )
         .unwrap();
@@ -1222,140 +1803,3539 @@ fn test_unsuccessful_proposal() {
     assert_eq!(res.proposal_list, vec![]);
     // proposal_count should not be changed after removing
     assert_eq!(res.proposal_count, Uint64::from(1u32));
-}
 
-fn mock_app() -> TerraApp {
-    let mut env = mock_env();
-    env.block.time = Timestamp::from_seconds(EPOCH_START);
-    let api = MockApi::default();
-    let bank = BankKeeper::new();
-    let storage = MockStorage::new();
-    let custom = TerraMock::luna_ust_case();
+    // The votes of the removed proposal are deleted, but the voting history of users is kept
+    let for_voters: Vec<(Addr, Uint128)> = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::ProposalVoters {
+                proposal_id: 1,
+                option: ProposalVoteOption::For,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
 
-    AppBuilder::new()
-        .with_api(api)
-        .with_block(env.block)
-        .with_bank(bank)
-        .with_storage(storage)
-        .with_custom(custom)
-        .build()
-}
+    assert_eq!(for_voters, vec![]);
 
-fn instantiate_contracts(
-    router: &mut TerraApp,
-    owner: Addr,
-) -> (Addr, Addr, Addr, Addr, Addr, Addr) {
-    let token_addr = instantiate_astro_token(router, &owner);
-    let (staking_instance, xastro_token_addr) =
-        instantiate_xastro_token(router, &owner, &token_addr);
-    let vxastro_token_addr = instantiate_vxastro_token(router, &owner, &xastro_token_addr);
-    let builder_unlock_addr = instantiate_builder_unlock_contract(router, &owner, &token_addr);
-    let assembly_addr = instantiate_assembly_contract(
-        router,
-        &owner,
-        &xastro_token_addr,
-        &vxastro_token_addr,
-        &builder_unlock_addr,
+    let user_vote: Option<ProposalVote> = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::UserVote {
+                proposal_id: 1,
+                user: "user1".to_string(),
+            },
+        )
+        .unwrap();
+
+    assert_eq!(
+        user_vote,
+        Some(ProposalVote {
+            option: ProposalVoteOption::For,
+            power: Uint128::from(100u32),
+        })
     );
 
-    assert_eq!("contract #0", token_addr);
-    assert_eq!("contract #2", xastro_token_addr);
-    assert_eq!("contract #3", vxastro_token_addr);
-    assert_eq!("contract #4", builder_unlock_addr);
-    assert_eq!("contract #5", assembly_addr);
+    // The summary of the removed proposal is kept in the archive
+    let res: ArchivedProposalListResponse = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.to_string(),
+            &QueryMsg::ArchivedProposals {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
 
-    (
-        token_addr,
-        staking_instance,
-        xastro_token_addr,
-        vxastro_token_addr,
-        builder_unlock_addr,
-        assembly_addr,
-    )
+    assert_eq!(res.proposal_count, Uint64::from(1u32));
+    assert_eq!(
+        res.archived_proposal_list,
+        vec![ArchivedProposal {
+            proposal_id: Uint64::from(1u32),
+            submitter: Addr::unchecked("user0"),
+            title: proposal.title,
+            status: ProposalStatus::Rejected,
+            for_power: Uint128::from(700u32),
+            against_power: Uint128::from(990u32),
+            abstain_power: Uint128::zero(),
+            no_with_veto_power: Uint128::zero(),
+            start_time: proposal.start_time,
+            end_time: proposal.end_time,
+            archived_time: app.block_info().time.seconds(),
+        }]
+    );
+
+    let res: ArchivedProposalListResponse = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.to_string(),
+            &QueryMsg::ArchivedProposals {
+                start_after: Some(1),
+                limit: None,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(res.archived_proposal_list, vec![]);
 }
 
-fn instantiate_astro_token(router: &mut TerraApp, owner: &Addr) -> Addr {
-    let astro_token_contract = Box::new(ContractWrapper::new_with_empty(
-        astroport_token::contract::execute,
-        astroport_token::contract::instantiate,
-        astroport_token::contract::query,
-    ));
+#[test]
+fn test_abstain_and_veto_votes() {
+    let mut app = mock_app();
 
-    let astro_token_code_id = router.store_code(astro_token_contract);
+    let owner = Addr::unchecked("owner");
 
-    let msg = TokenInstantiateMsg {
-        name: String::from("Astro token"),
-        symbol: String::from("ASTRO"),
-        decimals: 6,
-        initial_balances: vec![],
-        mint: Some(MinterResponse {
-            minter: owner.to_string(),
-            cap: None,
-        }),
-    };
+    let (_, staking_instance, xastro_addr, _, _, assembly_addr) =
+        instantiate_contracts(&mut app, owner);
 
-    router
-        .instantiate_contract(
-            astro_token_code_id,
-            owner.clone(),
-            &msg,
-            &[],
-            String::from("ASTRO"),
-            None,
-        )
-        .unwrap()
-}
+    // Init voting power for users
+    let xastro_balances: Vec<(&str, u128)> = vec![
+        ("user0", PROPOSAL_REQUIRED_DEPOSIT * 2), // proposal submitter
+        ("user1", 1000),
+        ("user2", 50),
+        ("user3", 1050),
+        ("user4", 900),
+    ];
 
-fn instantiate_xastro_token(
-    router: &mut TerraApp,
-    owner: &Addr,
-    astro_token: &Addr,
-) -> (Addr, Addr) {
-    let xastro_contract = Box::new(ContractWrapper::new_with_empty(
-        astroport_xastro_token::contract::execute,
-        astroport_xastro_token::contract::instantiate,
-        astroport_xastro_token::contract::query,
-    ));
+    for (addr, xastro) in xastro_balances {
+        mint_tokens(
+            &mut app,
+            &staking_instance,
+            &xastro_addr,
+            &Addr::unchecked(addr),
+            xastro,
+        );
+    }
 
-    let xastro_code_id = router.store_code(xastro_contract);
+    // Skip block
+    app.update_block(next_block);
 
-    let staking_contract = Box::new(
-        ContractWrapper::new_with_empty(
-            astroport_staking::contract::execute,
-            astroport_staking::contract::instantiate,
-            astroport_staking::contract::query,
-        )
-        .with_reply_empty(astroport_staking::contract::reply),
+    create_proposal(
+        &mut app,
+        &xastro_addr,
+        &assembly_addr,
+        Addr::unchecked("user0"),
+        None,
     );
 
-    let staking_code_id = router.store_code(staking_contract);
+    create_proposal(
+        &mut app,
+        &xastro_addr,
+        &assembly_addr,
+        Addr::unchecked("user0"),
+        None,
+    );
 
-    let msg = astroport::staking::InstantiateMsg {
-        owner: owner.to_string(),
-        token_code_id: xastro_code_id,
-        deposit_token_addr: astro_token.to_string(),
-    };
-    let staking_instance = router
-        .instantiate_contract(
-            staking_code_id,
-            owner.clone(),
-            &msg,
-            &[],
-            String::from("xASTRO"),
-            None,
+    // Total voting power is 5000, so the quorum is reached with 3000 votes while abstain votes
+    // are not taken into account by the threshold
+    let votes: Vec<(&str, ProposalVoteOption)> = vec![
+        ("user1", ProposalVoteOption::For),
+        ("user2", ProposalVoteOption::Against),
+        ("user3", ProposalVoteOption::Abstain),
+        ("user4", ProposalVoteOption::Abstain),
+    ];
+
+    for (addr, option) in votes {
+        cast_vote(
+            &mut app,
+            assembly_addr.clone(),
+            1,
+            Addr::unchecked(addr),
+            option,
         )
         .unwrap();
+    }
 
-    let res = router
-        .wrap()
-        .query::<astroport::staking::ConfigResponse>(&QueryRequest::Wasm(WasmQuery::Smart {
-            contract_addr: staking_instance.to_string(),
-            msg: to_binary(&astroport::staking::QueryMsg::Config {}).unwrap(),
-        }))
+    // 1050 out of 3000 votes are vetoing the second proposal
+    let votes: Vec<(&str, ProposalVoteOption)> = vec![
+        ("user1", ProposalVoteOption::For),
+        ("user2", ProposalVoteOption::Against),
+        ("user3", ProposalVoteOption::NoWithVeto),
+        ("user4", ProposalVoteOption::For),
+    ];
+
+    for (addr, option) in votes {
+        cast_vote(
+            &mut app,
+            assembly_addr.clone(),
+            2,
+            Addr::unchecked(addr),
+            option,
+        )
         .unwrap();
+    }
 
-    (staking_instance, res.share_token_addr)
-}
+    let proposal_votes: ProposalVotesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::ProposalVotes { proposal_id: 1 },
+        )
+        .unwrap();
+
+    assert_eq!(proposal_votes.for_power, Uint128::from(1000u32));
+    assert_eq!(proposal_votes.against_power, Uint128::from(50u32));
+    assert_eq!(proposal_votes.abstain_power, Uint128::from(1950u32));
+    assert_eq!(proposal_votes.no_with_veto_power, Uint128::zero());
+
+    let proposal_votes: ProposalVotesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::ProposalVotes { proposal_id: 2 },
+        )
+        .unwrap();
+
+    assert_eq!(proposal_votes.for_power, Uint128::from(1900u32));
+    assert_eq!(proposal_votes.against_power, Uint128::from(50u32));
+    assert_eq!(proposal_votes.abstain_power, Uint128::zero());
+    assert_eq!(proposal_votes.no_with_veto_power, Uint128::from(1050u32));
+
+    // Skip voting period
+    app.update_block(|bi| {
+        bi.height += (PROPOSAL_VOTING_PERIOD + 1) / 5;
+        bi.time = bi.time.plus_seconds(PROPOSAL_VOTING_PERIOD + 1);
+    });
+
+    check_token_balance(&mut app, &xastro_addr, &Addr::unchecked("user0"), 0);
+
+    for proposal_id in [1, 2] {
+        app.execute_contract(
+            Addr::unchecked("user0"),
+            assembly_addr.clone(),
+            &ExecuteMsg::EndProposal { proposal_id },
+            &[],
+        )
+        .unwrap();
+    }
+
+    // Only the deposit of the first proposal is returned, the vetoed deposit is burned
+    check_token_balance(&mut app, &xastro_addr, &Addr::unchecked("user0"), 1000);
+    check_token_balance(&mut app, &xastro_addr, &assembly_addr, 0);
+
+    // The first proposal is passed as 1000 out of 1050 non-abstain votes are for it
+    let proposal: Proposal = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::Proposal { proposal_id: 1 },
+        )
+        .unwrap();
+
+    assert_eq!(proposal.status, ProposalStatus::Passed);
+
+    let abstain_voters: Vec<(Addr, Uint128)> = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::ProposalVoters {
+                proposal_id: 1,
+                option: ProposalVoteOption::Abstain,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(
+        abstain_voters,
+        vec![
+            (Addr::unchecked("user3"), Uint128::from(1050u32)),
+            (Addr::unchecked("user4"), Uint128::from(900u32)),
+        ]
+    );
+
+    // The second proposal is rejected although the threshold was reached
+    let proposal: Proposal = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::Proposal { proposal_id: 2 },
+        )
+        .unwrap();
+
+    assert_eq!(proposal.status, ProposalStatus::Rejected);
+
+    let veto_voters: Vec<(Addr, Uint128)> = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::ProposalVoters {
+                proposal_id: 2,
+                option: ProposalVoteOption::NoWithVeto,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(
+        veto_voters,
+        vec![(Addr::unchecked("user3"), Uint128::from(1050u32))]
+    );
+}
+
+#[test]
+fn test_vote_change() {
+    let mut app = mock_app();
+
+    let owner = Addr::unchecked("owner");
+
+    let (_, staking_instance, xastro_addr, _, _, assembly_addr) =
+        instantiate_contracts(&mut app, owner);
+
+    let xastro_balances: Vec<(&str, u128)> = vec![
+        ("user0", PROPOSAL_REQUIRED_DEPOSIT * 2), // proposal submitter
+        ("user1", 3000),
+        ("user2", 1000),
+    ];
+
+    for (addr, xastro) in xastro_balances {
+        mint_tokens(
+            &mut app,
+            &staking_instance,
+            &xastro_addr,
+            &Addr::unchecked(addr),
+            xastro,
+        );
+    }
+
+    // Skip block
+    app.update_block(next_block);
+
+    create_proposal(
+        &mut app,
+        &xastro_addr,
+        &assembly_addr,
+        Addr::unchecked("user0"),
+        None,
+    );
+
+    cast_vote(
+        &mut app,
+        assembly_addr.clone(),
+        1,
+        Addr::unchecked("user1"),
+        ProposalVoteOption::Against,
+    )
+    .unwrap();
+
+    cast_vote(
+        &mut app,
+        assembly_addr.clone(),
+        1,
+        Addr::unchecked("user2"),
+        ProposalVoteOption::For,
+    )
+    .unwrap();
+
+    // Minting more xASTRO doesn't increase the voting power used when changing the vote
+    mint_tokens(
+        &mut app,
+        &staking_instance,
+        &xastro_addr,
+        &Addr::unchecked("user1"),
+        5000,
+    );
+
+    app.update_block(next_block);
+
+    // user1 changes their mind
+    let res = cast_vote(
+        &mut app,
+        assembly_addr.clone(),
+        1,
+        Addr::unchecked("user1"),
+        ProposalVoteOption::For,
+    )
+    .unwrap();
+
+    assert!(res.events.iter().any(|event| event
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "previous_vote" && attr.value == "against")));
+
+    let proposal_votes: ProposalVotesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::ProposalVotes { proposal_id: 1 },
+        )
+        .unwrap();
+
+    assert_eq!(proposal_votes.for_power, Uint128::from(4000u32));
+    assert_eq!(proposal_votes.against_power, Uint128::zero());
+
+    let against_voters: Vec<(Addr, Uint128)> = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::ProposalVoters {
+                proposal_id: 1,
+                option: ProposalVoteOption::Against,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(against_voters, vec![]);
+
+    let user_vote: Option<ProposalVote> = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::UserVote {
+                proposal_id: 1,
+                user: "user1".to_string(),
+            },
+        )
+        .unwrap();
+
+    assert_eq!(
+        user_vote,
+        Some(ProposalVote {
+            option: ProposalVoteOption::For,
+            power: Uint128::from(3000u32),
+        })
+    );
+
+    create_proposal(
+        &mut app,
+        &xastro_addr,
+        &assembly_addr,
+        Addr::unchecked("user0"),
+        None,
+    );
+
+    app.update_block(next_block);
+
+    cast_vote(
+        &mut app,
+        assembly_addr.clone(),
+        2,
+        Addr::unchecked("user1"),
+        ProposalVoteOption::Against,
+    )
+    .unwrap();
+
+    // The voting history keeps the latest vote cast on every proposal
+    let user_votes: Vec<UserVoteResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::UserVotes {
+                user: "user1".to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(
+        user_votes,
+        vec![
+            UserVoteResponse {
+                proposal_id: 1,
+                option: ProposalVoteOption::For,
+                power: Uint128::from(3000u32),
+            },
+            UserVoteResponse {
+                proposal_id: 2,
+                option: ProposalVoteOption::Against,
+                power: Uint128::from(8000u32),
+            }
+        ]
+    );
+
+    let user_votes: Vec<UserVoteResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::UserVotes {
+                user: "user1".to_string(),
+                start_after: Some(1),
+                limit: Some(1),
+            },
+        )
+        .unwrap();
+
+    assert_eq!(
+        user_votes
+            .iter()
+            .map(|vote| vote.proposal_id)
+            .collect::<Vec<_>>(),
+        vec![2]
+    );
+
+    // Skip voting period
+    app.update_block(|bi| {
+        bi.height += (PROPOSAL_VOTING_PERIOD + 1) / 5;
+        bi.time = bi.time.plus_seconds(PROPOSAL_VOTING_PERIOD + 1);
+    });
+
+    // Try to change the vote after the voting period
+    let res = cast_vote(
+        &mut app,
+        assembly_addr.clone(),
+        1,
+        Addr::unchecked("user2"),
+        ProposalVoteOption::Against,
+    )
+    .unwrap_err();
+
+    assert_eq!(res.to_string(), "Voting period ended!");
+
+    app.execute_contract(
+        Addr::unchecked("user0"),
+        assembly_addr.clone(),
+        &ExecuteMsg::EndProposal { proposal_id: 1 },
+        &[],
+    )
+    .unwrap();
+
+    let proposal: Proposal = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::Proposal { proposal_id: 1 },
+        )
+        .unwrap();
+
+    assert_eq!(proposal.status, ProposalStatus::Passed);
+}
+
+#[test]
+fn test_deposit_slashing() {
+    let mut app = mock_app();
+
+    let owner = Addr::unchecked("owner");
+    let treasury = Addr::unchecked("treasury");
+
+    let (_, staking_instance, xastro_addr, _, _, assembly_addr) =
+        instantiate_contracts(&mut app, owner);
+
+    let xastro_balances: Vec<(&str, u128)> = vec![
+        ("user0", PROPOSAL_REQUIRED_DEPOSIT * 2), // proposal submitter
+        ("user1", 2000),
+        ("user2", 1000),
+    ];
+
+    for (addr, xastro) in xastro_balances {
+        mint_tokens(
+            &mut app,
+            &staking_instance,
+            &xastro_addr,
+            &Addr::unchecked(addr),
+            xastro,
+        );
+    }
+
+    // Only the Assembly can update its config
+    let update_config_msg = ExecuteMsg::UpdateConfig(UpdateConfig {
+        xastro_token_addr: None,
+        vxastro_token_addr: None,
+        builder_unlock_addr: None,
+        proposal_voting_period: None,
+        proposal_effective_delay: None,
+        proposal_expiration_period: None,
+        proposal_required_deposit: None,
+        proposal_required_quorum: None,
+        proposal_required_threshold: None,
+        proposal_required_veto_threshold: None,
+        expedited_proposal_required_deposit: None,
+        expedited_proposal_voting_period: None,
+        expedited_proposal_required_threshold: None,
+        early_finalization_enabled: None,
+        proposal_required_vxastro_power: None,
+        quorum_failure_deposit_slash: Some("0.25".to_string()),
+        veto_deposit_slash: Some("0.5".to_string()),
+        slashed_deposit_receiver: Some(treasury.to_string()),
+        guardian: None,
+        timelock_addr: None,
+        proposal_validation: None,
+        voting_power_sources: None,
+        whitelist_add: None,
+        whitelist_remove: None,
+        proposal_types_add: None,
+        proposal_types_remove: None,
+        ibc_satellites_add: None,
+        ibc_satellites_remove: None,
+    });
+
+    let res = app
+        .execute_contract(
+            Addr::unchecked("user0"),
+            assembly_addr.clone(),
+            &update_config_msg,
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(res.to_string(), "Unauthorized");
+
+    app.execute_contract(
+        assembly_addr.clone(),
+        assembly_addr.clone(),
+        &update_config_msg,
+        &[],
+    )
+    .unwrap();
+
+    let config: Config = app
+        .wrap()
+        .query_wasm_smart(assembly_addr.clone(), &QueryMsg::Config {})
+        .unwrap();
+
+    assert_eq!(config.quorum_failure_deposit_slash, Decimal::percent(25));
+    assert_eq!(config.veto_deposit_slash, Decimal::percent(50));
+    assert_eq!(config.slashed_deposit_receiver, Some(treasury.clone()));
+
+    // Skip block
+    app.update_block(next_block);
+
+    // The first proposal doesn't reach the quorum, the second one is vetoed
+    create_proposal(
+        &mut app,
+        &xastro_addr,
+        &assembly_addr,
+        Addr::unchecked("user0"),
+        None,
+    );
+
+    create_proposal(
+        &mut app,
+        &xastro_addr,
+        &assembly_addr,
+        Addr::unchecked("user0"),
+        None,
+    );
+
+    cast_vote(
+        &mut app,
+        assembly_addr.clone(),
+        1,
+        Addr::unchecked("user2"),
+        ProposalVoteOption::For,
+    )
+    .unwrap();
+
+    cast_vote(
+        &mut app,
+        assembly_addr.clone(),
+        2,
+        Addr::unchecked("user1"),
+        ProposalVoteOption::NoWithVeto,
+    )
+    .unwrap();
+
+    cast_vote(
+        &mut app,
+        assembly_addr.clone(),
+        2,
+        Addr::unchecked("user2"),
+        ProposalVoteOption::For,
+    )
+    .unwrap();
+
+    // Skip voting period
+    app.update_block(|bi| {
+        bi.height += (PROPOSAL_VOTING_PERIOD + 1) / 5;
+        bi.time = bi.time.plus_seconds(PROPOSAL_VOTING_PERIOD + 1);
+    });
+
+    app.execute_contract(
+        Addr::unchecked("user0"),
+        assembly_addr.clone(),
+        &ExecuteMsg::EndProposal { proposal_id: 1 },
+        &[],
+    )
+    .unwrap();
+
+    check_token_balance(&mut app, &xastro_addr, &Addr::unchecked("user0"), 750);
+    check_token_balance(&mut app, &xastro_addr, &treasury, 250);
+
+    app.execute_contract(
+        Addr::unchecked("user0"),
+        assembly_addr.clone(),
+        &ExecuteMsg::EndProposal { proposal_id: 2 },
+        &[],
+    )
+    .unwrap();
+
+    check_token_balance(&mut app, &xastro_addr, &Addr::unchecked("user0"), 1250);
+    check_token_balance(&mut app, &xastro_addr, &treasury, 750);
+    check_token_balance(&mut app, &xastro_addr, &assembly_addr, 0);
+}
+
+#[test]
+fn test_proposal_cancellation() {
+    let mut app = mock_app();
+
+    let owner = Addr::unchecked("owner");
+
+    let (_, staking_instance, xastro_addr, _, _, assembly_addr) =
+        instantiate_contracts(&mut app, owner);
+
+    let xastro_balances: Vec<(&str, u128)> = vec![
+        ("user0", PROPOSAL_REQUIRED_DEPOSIT * 2), // proposal submitter
+        ("user1", 3000),
+    ];
+
+    for (addr, xastro) in xastro_balances {
+        mint_tokens(
+            &mut app,
+            &staking_instance,
+            &xastro_addr,
+            &Addr::unchecked(addr),
+            xastro,
+        );
+    }
+
+    // Skip block
+    app.update_block(next_block);
+
+    create_proposal(
+        &mut app,
+        &xastro_addr,
+        &assembly_addr,
+        Addr::unchecked("user0"),
+        None,
+    );
+
+    create_proposal(
+        &mut app,
+        &xastro_addr,
+        &assembly_addr,
+        Addr::unchecked("user0"),
+        None,
+    );
+
+    check_token_balance(&mut app, &xastro_addr, &Addr::unchecked("user0"), 0);
+
+    // Only the submitter or the guardian can cancel a proposal
+    let res = app
+        .execute_contract(
+            Addr::unchecked("user1"),
+            assembly_addr.clone(),
+            &ExecuteMsg::CancelProposal { proposal_id: 1 },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(res.to_string(), "Unauthorized");
+
+    // The submitter cancels the proposal before any votes are cast
+    app.execute_contract(
+        Addr::unchecked("user0"),
+        assembly_addr.clone(),
+        &ExecuteMsg::CancelProposal { proposal_id: 1 },
+        &[],
+    )
+    .unwrap();
+
+    check_token_balance(
+        &mut app,
+        &xastro_addr,
+        &Addr::unchecked("user0"),
+        PROPOSAL_REQUIRED_DEPOSIT,
+    );
+
+    let proposal: Proposal = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::Proposal { proposal_id: 1 },
+        )
+        .unwrap();
+
+    assert_eq!(proposal.status, ProposalStatus::Cancelled);
+
+    // Voting on a cancelled proposal is not possible
+    let res = cast_vote(
+        &mut app,
+        assembly_addr.clone(),
+        1,
+        Addr::unchecked("user1"),
+        ProposalVoteOption::For,
+    )
+    .unwrap_err();
+
+    assert_eq!(res.to_string(), "Proposal not active!");
+
+    cast_vote(
+        &mut app,
+        assembly_addr.clone(),
+        2,
+        Addr::unchecked("user1"),
+        ProposalVoteOption::For,
+    )
+    .unwrap();
+
+    // The submitter can't cancel a proposal that already has votes
+    let res = app
+        .execute_contract(
+            Addr::unchecked("user0"),
+            assembly_addr.clone(),
+            &ExecuteMsg::CancelProposal { proposal_id: 2 },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(res.to_string(), "Proposal already has votes!");
+
+    // Skip voting period
+    app.update_block(|bi| {
+        bi.height += (PROPOSAL_VOTING_PERIOD + 1) / 5;
+        bi.time = bi.time.plus_seconds(PROPOSAL_VOTING_PERIOD + 1);
+    });
+
+    app.execute_contract(
+        Addr::unchecked("user0"),
+        assembly_addr.clone(),
+        &ExecuteMsg::EndProposal { proposal_id: 2 },
+        &[],
+    )
+    .unwrap();
+
+    check_token_balance(
+        &mut app,
+        &xastro_addr,
+        &Addr::unchecked("user0"),
+        PROPOSAL_REQUIRED_DEPOSIT * 2,
+    );
+
+    // The guardian cancels the passed proposal before it gets executed
+    app.execute_contract(
+        Addr::unchecked("guardian"),
+        assembly_addr.clone(),
+        &ExecuteMsg::CancelProposal { proposal_id: 2 },
+        &[],
+    )
+    .unwrap();
+
+    // The deposit of a passed proposal was already returned
+    check_token_balance(
+        &mut app,
+        &xastro_addr,
+        &Addr::unchecked("user0"),
+        PROPOSAL_REQUIRED_DEPOSIT * 2,
+    );
+    check_token_balance(&mut app, &xastro_addr, &assembly_addr, 0);
+
+    // Skip effective delay
+    app.update_block(|bi| {
+        bi.height += (PROPOSAL_EFFECTIVE_DELAY + 1) / 5;
+        bi.time = bi.time.plus_seconds(PROPOSAL_EFFECTIVE_DELAY + 1);
+    });
+
+    let res = app
+        .execute_contract(
+            Addr::unchecked("user0"),
+            assembly_addr.clone(),
+            &ExecuteMsg::ExecuteProposal { proposal_id: 2 },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(res.to_string(), "Proposal not passed!");
+
+    let res = app
+        .execute_contract(
+            Addr::unchecked("guardian"),
+            assembly_addr.clone(),
+            &ExecuteMsg::CancelProposal { proposal_id: 2 },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(res.to_string(), "Proposal can't be cancelled!");
+
+    // Cancelled proposals can be removed
+    for proposal_id in [1, 2] {
+        app.execute_contract(
+            Addr::unchecked("user0"),
+            assembly_addr.clone(),
+            &ExecuteMsg::RemoveCompletedProposal { proposal_id },
+            &[],
+        )
+        .unwrap();
+    }
+
+    let res: ProposalListResponse = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.to_string(),
+            &QueryMsg::Proposals {
+                start: None,
+                limit: None,
+                status: None,
+                submitter: None,
+                descending: None,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(res.proposal_list, vec![]);
+}
+
+#[test]
+fn test_proposals_query_filters() {
+    let mut app = mock_app();
+
+    let owner = Addr::unchecked("owner");
+
+    let (_, staking_instance, xastro_addr, _, _, assembly_addr) =
+        instantiate_contracts(&mut app, owner);
+
+    let xastro_balances: Vec<(&str, u128)> = vec![
+        ("user0", PROPOSAL_REQUIRED_DEPOSIT * 2),
+        ("user1", PROPOSAL_REQUIRED_DEPOSIT * 2),
+    ];
+
+    for (addr, xastro) in xastro_balances {
+        mint_tokens(
+            &mut app,
+            &staking_instance,
+            &xastro_addr,
+            &Addr::unchecked(addr),
+            xastro,
+        );
+    }
+
+    // Skip block
+    app.update_block(next_block);
+
+    // Proposals 1 and 3 are posted by user0, proposals 2 and 4 by user1
+    for submitter in ["user0", "user1", "user0", "user1"] {
+        create_proposal(
+            &mut app,
+            &xastro_addr,
+            &assembly_addr,
+            Addr::unchecked(submitter),
+            None,
+        );
+    }
+
+    for proposal_id in [3, 4] {
+        app.execute_contract(
+            Addr::unchecked("guardian"),
+            assembly_addr.clone(),
+            &ExecuteMsg::CancelProposal { proposal_id },
+            &[],
+        )
+        .unwrap();
+    }
+
+    let query_ids = |app: &mut TerraApp,
+                     start: Option<u64>,
+                     status: Option<ProposalStatus>,
+                     submitter: Option<&str>,
+                     descending: Option<bool>|
+     -> Vec<u64> {
+        let res: ProposalListResponse = app
+            .wrap()
+            .query_wasm_smart(
+                assembly_addr.clone(),
+                &QueryMsg::Proposals {
+                    start,
+                    limit: None,
+                    status,
+                    submitter: submitter.map(String::from),
+                    descending,
+                },
+            )
+            .unwrap();
+
+        assert_eq!(res.proposal_count, Uint64::from(4u64));
+
+        res.proposal_list
+            .into_iter()
+            .map(|proposal| proposal.proposal_id.u64())
+            .collect()
+    };
+
+    assert_eq!(
+        query_ids(&mut app, None, None, None, None),
+        vec![1, 2, 3, 4]
+    );
+    assert_eq!(
+        query_ids(&mut app, None, None, None, Some(true)),
+        vec![4, 3, 2, 1]
+    );
+    assert_eq!(
+        query_ids(&mut app, Some(3), None, None, Some(true)),
+        vec![3, 2, 1]
+    );
+    assert_eq!(
+        query_ids(&mut app, None, Some(ProposalStatus::Active), None, None),
+        vec![1, 2]
+    );
+    assert_eq!(
+        query_ids(
+            &mut app,
+            None,
+            Some(ProposalStatus::Cancelled),
+            None,
+            Some(true)
+        ),
+        vec![4, 3]
+    );
+    assert_eq!(
+        query_ids(&mut app, Some(2), None, Some("user0"), None),
+        vec![3]
+    );
+    assert_eq!(
+        query_ids(
+            &mut app,
+            None,
+            Some(ProposalStatus::Active),
+            Some("user1"),
+            None
+        ),
+        vec![2]
+    );
+    assert_eq!(
+        query_ids(&mut app, None, Some(ProposalStatus::Passed), None, None),
+        Vec::<u64>::new()
+    );
+
+    // Removed proposals are dropped from the indexes as well
+    app.execute_contract(
+        Addr::unchecked("user0"),
+        assembly_addr.clone(),
+        &ExecuteMsg::RemoveCompletedProposal { proposal_id: 3 },
+        &[],
+    )
+    .unwrap();
+
+    assert_eq!(
+        query_ids(&mut app, None, None, Some("user0"), None),
+        vec![1]
+    );
+    assert_eq!(
+        query_ids(&mut app, None, Some(ProposalStatus::Cancelled), None, None),
+        vec![4]
+    );
+}
+
+#[test]
+fn test_check_messages() {
+    let mut app = mock_app();
+
+    let owner = Addr::unchecked("owner");
+
+    let (_, _, xastro_addr, _, _, assembly_addr) = instantiate_contracts(&mut app, owner);
+
+    let update_config_msg = ProposalMessage {
+        order: Uint64::from(0u32),
+        ibc_channel: None,
+        msg: CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: assembly_addr.to_string(),
+            msg: to_binary(&ExecuteMsg::UpdateConfig(UpdateConfig {
+                xastro_token_addr: None,
+                vxastro_token_addr: None,
+                builder_unlock_addr: None,
+                proposal_voting_period: Some(750),
+                proposal_effective_delay: None,
+                proposal_expiration_period: None,
+                proposal_required_deposit: None,
+                proposal_required_quorum: None,
+                proposal_required_threshold: None,
+                proposal_required_veto_threshold: None,
+                expedited_proposal_required_deposit: None,
+                expedited_proposal_voting_period: None,
+                expedited_proposal_required_threshold: None,
+                early_finalization_enabled: None,
+                proposal_required_vxastro_power: None,
+                quorum_failure_deposit_slash: None,
+                veto_deposit_slash: None,
+                slashed_deposit_receiver: None,
+                guardian: None,
+                timelock_addr: None,
+                proposal_validation: None,
+                voting_power_sources: None,
+                whitelist_add: None,
+                whitelist_remove: None,
+                proposal_types_add: None,
+                proposal_types_remove: None,
+                ibc_satellites_add: None,
+                ibc_satellites_remove: None,
+            }))
+            .unwrap(),
+            funds: vec![],
+        }),
+    };
+
+    // The Assembly doesn't hold any xASTRO, so this message fails
+    let transfer_msg = ProposalMessage {
+        order: Uint64::from(1u32),
+        ibc_channel: None,
+        msg: CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: xastro_addr.to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: "user0".to_string(),
+                amount: Uint128::from(1u32),
+            })
+            .unwrap(),
+            funds: vec![],
+        }),
+    };
+
+    let res = app
+        .execute_contract(
+            Addr::unchecked("user0"),
+            assembly_addr.clone(),
+            &ExecuteMsg::CheckMessages {
+                messages: vec![update_config_msg.clone()],
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(res.to_string(), "Messages executed successfully!");
+
+    let res = app
+        .execute_contract(
+            Addr::unchecked("user0"),
+            assembly_addr.clone(),
+            &ExecuteMsg::CheckMessages {
+                messages: vec![transfer_msg, update_config_msg],
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_ne!(res.to_string(), "Messages executed successfully!");
+
+    let res = app
+        .execute_contract(
+            Addr::unchecked("user0"),
+            assembly_addr.clone(),
+            &ExecuteMsg::CheckMessages { messages: vec![] },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(res.to_string(), "Messages executed successfully!");
+
+    // The checks didn't change the config
+    let config: Config = app
+        .wrap()
+        .query_wasm_smart(assembly_addr, &QueryMsg::Config {})
+        .unwrap();
+
+    assert_eq!(config.proposal_voting_period, PROPOSAL_VOTING_PERIOD);
+}
+
+#[test]
+fn test_failed_proposal_execution() {
+    let mut app = mock_app();
+
+    let owner = Addr::unchecked("owner");
+
+    let (_, staking_instance, xastro_addr, _, _, assembly_addr) =
+        instantiate_contracts(&mut app, owner);
+
+    let xastro_balances: Vec<(&str, u128)> = vec![
+        ("user0", PROPOSAL_REQUIRED_DEPOSIT), // proposal submitter
+        ("user1", 3000),
+    ];
+
+    for (addr, xastro) in xastro_balances {
+        mint_tokens(
+            &mut app,
+            &staking_instance,
+            &xastro_addr,
+            &Addr::unchecked(addr),
+            xastro,
+        );
+    }
+
+    // Skip block
+    app.update_block(next_block);
+
+    let update_config_msg = |proposal_voting_period: u64| {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: assembly_addr.to_string(),
+            msg: to_binary(&ExecuteMsg::UpdateConfig(UpdateConfig {
+                xastro_token_addr: None,
+                vxastro_token_addr: None,
+                builder_unlock_addr: None,
+                proposal_voting_period: Some(proposal_voting_period),
+                proposal_effective_delay: None,
+                proposal_expiration_period: None,
+                proposal_required_deposit: None,
+                proposal_required_quorum: None,
+                proposal_required_threshold: None,
+                proposal_required_veto_threshold: None,
+                expedited_proposal_required_deposit: None,
+                expedited_proposal_voting_period: None,
+                expedited_proposal_required_threshold: None,
+                early_finalization_enabled: None,
+                proposal_required_vxastro_power: None,
+                quorum_failure_deposit_slash: None,
+                veto_deposit_slash: None,
+                slashed_deposit_receiver: None,
+                guardian: None,
+                timelock_addr: None,
+                proposal_validation: None,
+                voting_power_sources: None,
+                whitelist_add: None,
+                whitelist_remove: None,
+                proposal_types_add: None,
+                proposal_types_remove: None,
+                ibc_satellites_add: None,
+                ibc_satellites_remove: None,
+            }))
+            .unwrap(),
+            funds: vec![],
+        })
+    };
+
+    // The second message fails as the Assembly doesn't hold any xASTRO after the deposit is returned
+    create_proposal(
+        &mut app,
+        &xastro_addr,
+        &assembly_addr,
+        Addr::unchecked("user0"),
+        Some(vec![
+            ProposalMessage {
+                order: Uint64::from(3u32),
+                ibc_channel: None,
+                msg: update_config_msg(1000),
+            },
+            ProposalMessage {
+                order: Uint64::from(1u32),
+                ibc_channel: None,
+                msg: update_config_msg(750),
+            },
+            ProposalMessage {
+                order: Uint64::from(2u32),
+                ibc_channel: None,
+                msg: CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr: xastro_addr.to_string(),
+                    msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                        recipient: "user1".to_string(),
+                        amount: Uint128::from(PROPOSAL_REQUIRED_DEPOSIT),
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                }),
+            },
+        ]),
+    );
+
+    cast_vote(
+        &mut app,
+        assembly_addr.clone(),
+        1,
+        Addr::unchecked("user1"),
+        ProposalVoteOption::For,
+    )
+    .unwrap();
+
+    // Skip voting period
+    app.update_block(|bi| {
+        bi.height += (PROPOSAL_VOTING_PERIOD + 1) / 5;
+        bi.time = bi.time.plus_seconds(PROPOSAL_VOTING_PERIOD + 1);
+    });
+
+    app.execute_contract(
+        Addr::unchecked("user0"),
+        assembly_addr.clone(),
+        &ExecuteMsg::EndProposal { proposal_id: 1 },
+        &[],
+    )
+    .unwrap();
+
+    // Skip effective delay
+    app.update_block(|bi| {
+        bi.height += (PROPOSAL_EFFECTIVE_DELAY + 1) / 5;
+        bi.time = bi.time.plus_seconds(PROPOSAL_EFFECTIVE_DELAY + 1);
+    });
+
+    // The execution itself succeeds and records the outcome of every executed message
+    app.execute_contract(
+        Addr::unchecked("user0"),
+        assembly_addr.clone(),
+        &ExecuteMsg::ExecuteProposal { proposal_id: 1 },
+        &[],
+    )
+    .unwrap();
+
+    let proposal: Proposal = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::Proposal { proposal_id: 1 },
+        )
+        .unwrap();
+
+    assert_eq!(proposal.status, ProposalStatus::FailedExecution);
+    assert_eq!(proposal.execution_results.len(), 2);
+    assert_eq!(proposal.execution_results[0].order, Uint64::from(1u32));
+    assert_eq!(proposal.execution_results[0].error, None);
+    assert_eq!(proposal.execution_results[1].order, Uint64::from(2u32));
+    assert!(proposal.execution_results[1].error.is_some());
+
+    // The first message was applied, the last one was never executed
+    let config: Config = app
+        .wrap()
+        .query_wasm_smart(assembly_addr.clone(), &QueryMsg::Config {})
+        .unwrap();
+
+    assert_eq!(config.proposal_voting_period, 750);
+
+    check_token_balance(&mut app, &xastro_addr, &Addr::unchecked("user1"), 3000);
+
+    // A failed proposal can't be executed again
+    let res = app
+        .execute_contract(
+            Addr::unchecked("user0"),
+            assembly_addr.clone(),
+            &ExecuteMsg::ExecuteProposal { proposal_id: 1 },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(res.to_string(), "Proposal not passed!");
+}
+
+#[test]
+fn test_vote_delegation() {
+    let mut app = mock_app();
+
+    let owner = Addr::unchecked("owner");
+
+    let (_, staking_instance, xastro_addr, _, _, assembly_addr) =
+        instantiate_contracts(&mut app, owner);
+
+    let xastro_balances: Vec<(&str, u128)> = vec![
+        ("user0", PROPOSAL_REQUIRED_DEPOSIT), // proposal submitter
+        ("user1", 1000),
+        ("user2", 2000),
+        ("user3", 3000),
+        ("user4", 500),
+    ];
+
+    for (addr, xastro) in xastro_balances {
+        mint_tokens(
+            &mut app,
+            &staking_instance,
+            &xastro_addr,
+            &Addr::unchecked(addr),
+            xastro,
+        );
+    }
+
+    // Users can't delegate to themselves
+    let res = app
+        .execute_contract(
+            Addr::unchecked("user1"),
+            assembly_addr.clone(),
+            &ExecuteMsg::Delegate {
+                delegatee: "user1".to_string(),
+                proposal_id: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        res.to_string(),
+        "You can't delegate voting power to yourself!"
+    );
+
+    for delegator in ["user1", "user2"].iter() {
+        app.execute_contract(
+            Addr::unchecked(*delegator),
+            assembly_addr.clone(),
+            &ExecuteMsg::Delegate {
+                delegatee: "user3".to_string(),
+                proposal_id: None,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    // Skip block
+    app.update_block(next_block);
+
+    create_proposal(
+        &mut app,
+        &xastro_addr,
+        &assembly_addr,
+        Addr::unchecked("user0"),
+        None,
+    );
+
+    check_user_vp(&mut app, &assembly_addr, &Addr::unchecked("user3"), 1, 6000);
+
+    // user2 delegates to user4 for this proposal only
+    app.execute_contract(
+        Addr::unchecked("user2"),
+        assembly_addr.clone(),
+        &ExecuteMsg::Delegate {
+            delegatee: "user4".to_string(),
+            proposal_id: Some(1),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let delegatee: Option<Addr> = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::Delegation {
+                user: "user2".to_string(),
+                proposal_id: Some(1),
+            },
+        )
+        .unwrap();
+
+    assert_eq!(delegatee, Some(Addr::unchecked("user4")));
+
+    let delegatee: Option<Addr> = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::Delegation {
+                user: "user2".to_string(),
+                proposal_id: None,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(delegatee, Some(Addr::unchecked("user3")));
+
+    let delegated_power: Uint128 = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::DelegatedVotingPower {
+                delegatee: "user3".to_string(),
+                proposal_id: 1,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(delegated_power, Uint128::from(1000u32));
+
+    check_user_vp(&mut app, &assembly_addr, &Addr::unchecked("user3"), 1, 4000);
+    check_user_vp(&mut app, &assembly_addr, &Addr::unchecked("user4"), 1, 2500);
+
+    cast_vote(
+        &mut app,
+        assembly_addr.clone(),
+        1,
+        Addr::unchecked("user3"),
+        ProposalVoteOption::For,
+    )
+    .unwrap();
+
+    // user1's voting power was already used by user3
+    let res = app
+        .execute_contract(
+            Addr::unchecked("user1"),
+            assembly_addr.clone(),
+            &ExecuteMsg::Delegate {
+                delegatee: "user4".to_string(),
+                proposal_id: Some(1),
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        res.to_string(),
+        "Delegated voting power was already used on this proposal!"
+    );
+
+    // user1 overrides the vote of their delegatee
+    cast_vote(
+        &mut app,
+        assembly_addr.clone(),
+        1,
+        Addr::unchecked("user1"),
+        ProposalVoteOption::Against,
+    )
+    .unwrap();
+
+    cast_vote(
+        &mut app,
+        assembly_addr.clone(),
+        1,
+        Addr::unchecked("user4"),
+        ProposalVoteOption::Against,
+    )
+    .unwrap();
+
+    let proposal_votes: ProposalVotesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::ProposalVotes { proposal_id: 1 },
+        )
+        .unwrap();
+
+    assert_eq!(proposal_votes.for_power, Uint128::from(3000u32));
+    assert_eq!(proposal_votes.against_power, Uint128::from(3500u32));
+
+    let vote: ProposalVote = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::UserVote {
+                user: "user3".to_string(),
+                proposal_id: 1,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(vote.power, Uint128::from(3000u32));
+
+    app.execute_contract(
+        Addr::unchecked("user1"),
+        assembly_addr.clone(),
+        &ExecuteMsg::Undelegate { proposal_id: None },
+        &[],
+    )
+    .unwrap();
+
+    let delegatee: Option<Addr> = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::Delegation {
+                user: "user1".to_string(),
+                proposal_id: None,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(delegatee, None);
+
+    let res = app
+        .execute_contract(
+            Addr::unchecked("user1"),
+            assembly_addr.clone(),
+            &ExecuteMsg::Undelegate { proposal_id: None },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(res.to_string(), "Delegation not found!");
+}
+
+#[test]
+fn test_delegated_vote_after_revote() {
+    let mut app = mock_app();
+
+    let owner = Addr::unchecked("owner");
+
+    let (_, staking_instance, xastro_addr, _, _, assembly_addr) =
+        instantiate_contracts(&mut app, owner);
+
+    let xastro_balances: Vec<(&str, u128)> = vec![
+        ("user0", PROPOSAL_REQUIRED_DEPOSIT), // proposal submitter
+        ("user1", 1000),
+        ("user2", 2000),
+        ("user3", 3000),
+    ];
+
+    for (addr, xastro) in xastro_balances {
+        mint_tokens(
+            &mut app,
+            &staking_instance,
+            &xastro_addr,
+            &Addr::unchecked(addr),
+            xastro,
+        );
+    }
+
+    for delegator in ["user1", "user2"].iter() {
+        app.execute_contract(
+            Addr::unchecked(*delegator),
+            assembly_addr.clone(),
+            &ExecuteMsg::Delegate {
+                delegatee: "user3".to_string(),
+                proposal_id: None,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    // Skip block
+    app.update_block(next_block);
+
+    create_proposal(
+        &mut app,
+        &xastro_addr,
+        &assembly_addr,
+        Addr::unchecked("user0"),
+        None,
+    );
+
+    cast_vote(
+        &mut app,
+        assembly_addr.clone(),
+        1,
+        Addr::unchecked("user3"),
+        ProposalVoteOption::For,
+    )
+    .unwrap();
+
+    // user1's voting power is used by user3, so the general delegation can't be changed
+    let res = app
+        .execute_contract(
+            Addr::unchecked("user1"),
+            assembly_addr.clone(),
+            &ExecuteMsg::Undelegate { proposal_id: None },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        res.to_string(),
+        "Delegated voting power was already used on this proposal!"
+    );
+
+    let res = app
+        .execute_contract(
+            Addr::unchecked("user1"),
+            assembly_addr.clone(),
+            &ExecuteMsg::Delegate {
+                delegatee: "user2".to_string(),
+                proposal_id: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        res.to_string(),
+        "Delegated voting power was already used on this proposal!"
+    );
+
+    // user3 changes their vote together with the delegated voting power
+    cast_vote(
+        &mut app,
+        assembly_addr.clone(),
+        1,
+        Addr::unchecked("user3"),
+        ProposalVoteOption::Against,
+    )
+    .unwrap();
+
+    let proposal_votes: ProposalVotesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::ProposalVotes { proposal_id: 1 },
+        )
+        .unwrap();
+
+    assert_eq!(proposal_votes.for_power, Uint128::zero());
+    assert_eq!(proposal_votes.against_power, Uint128::from(6000u32));
+
+    // user1 overrides the re-vote of their delegatee
+    cast_vote(
+        &mut app,
+        assembly_addr.clone(),
+        1,
+        Addr::unchecked("user1"),
+        ProposalVoteOption::For,
+    )
+    .unwrap();
+
+    let proposal_votes: ProposalVotesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::ProposalVotes { proposal_id: 1 },
+        )
+        .unwrap();
+
+    assert_eq!(proposal_votes.for_power, Uint128::from(1000u32));
+    assert_eq!(proposal_votes.against_power, Uint128::from(5000u32));
+
+    let vote: ProposalVote = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::UserVote {
+                user: "user3".to_string(),
+                proposal_id: 1,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(vote.power, Uint128::from(5000u32));
+
+    // user1's voting power isn't used by a delegatee anymore
+    app.execute_contract(
+        Addr::unchecked("user1"),
+        assembly_addr.clone(),
+        &ExecuteMsg::Undelegate { proposal_id: None },
+        &[],
+    )
+    .unwrap();
+
+    // user3 can't use user1's voting power again after re-voting
+    cast_vote(
+        &mut app,
+        assembly_addr.clone(),
+        1,
+        Addr::unchecked("user3"),
+        ProposalVoteOption::For,
+    )
+    .unwrap();
+
+    let proposal_votes: ProposalVotesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::ProposalVotes { proposal_id: 1 },
+        )
+        .unwrap();
+
+    assert_eq!(proposal_votes.for_power, Uint128::from(6000u32));
+    assert_eq!(proposal_votes.against_power, Uint128::zero());
+}
+
+#[test]
+fn test_delegation_limits() {
+    let mut app = mock_app();
+
+    let owner = Addr::unchecked("owner");
+
+    let (_, staking_instance, xastro_addr, _, _, assembly_addr) =
+        instantiate_contracts(&mut app, owner);
+
+    // Addresses without voting power can't delegate
+    let err = app
+        .execute_contract(
+            Addr::unchecked("user0"),
+            assembly_addr.clone(),
+            &ExecuteMsg::Delegate {
+                delegatee: "delegatee".to_string(),
+                proposal_id: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(err.to_string(), "You don't have any voting power!");
+
+    for i in 0..=MAX_DELEGATORS {
+        mint_tokens(
+            &mut app,
+            &staking_instance,
+            &xastro_addr,
+            &Addr::unchecked(format!("user{}", i)),
+            100,
+        );
+    }
+
+    for i in 0..MAX_DELEGATORS {
+        app.execute_contract(
+            Addr::unchecked(format!("user{}", i)),
+            assembly_addr.clone(),
+            &ExecuteMsg::Delegate {
+                delegatee: "delegatee".to_string(),
+                proposal_id: None,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(format!("user{}", MAX_DELEGATORS)),
+            assembly_addr.clone(),
+            &ExecuteMsg::Delegate {
+                delegatee: "delegatee".to_string(),
+                proposal_id: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(err.to_string(), "The delegatee has too many delegators!");
+
+    // Delegating to the same delegatee again doesn't need a new slot
+    app.execute_contract(
+        Addr::unchecked("user0"),
+        assembly_addr.clone(),
+        &ExecuteMsg::Delegate {
+            delegatee: "delegatee".to_string(),
+            proposal_id: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    // A slot is released when a delegator undelegates
+    app.execute_contract(
+        Addr::unchecked("user0"),
+        assembly_addr.clone(),
+        &ExecuteMsg::Undelegate { proposal_id: None },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(format!("user{}", MAX_DELEGATORS)),
+        assembly_addr,
+        &ExecuteMsg::Delegate {
+            delegatee: "delegatee".to_string(),
+            proposal_id: None,
+        },
+        &[],
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_proposal_types() {
+    let mut app = mock_app();
+
+    let owner = Addr::unchecked("owner");
+
+    let (_, staking_instance, xastro_addr, _, _, assembly_addr) =
+        instantiate_contracts(&mut app, owner);
+
+    let xastro_balances: Vec<(&str, u128)> = vec![
+        ("user0", PROPOSAL_REQUIRED_DEPOSIT * 3), // proposal submitter
+        ("user1", 6000),
+        ("user2", 1000),
+    ];
+
+    for (addr, xastro) in xastro_balances {
+        mint_tokens(
+            &mut app,
+            &staking_instance,
+            &xastro_addr,
+            &Addr::unchecked(addr),
+            xastro,
+        );
+    }
+
+    let upgrade_type = ProposalType {
+        name: "upgrade".to_string(),
+        required_deposit: Uint128::from(PROPOSAL_REQUIRED_DEPOSIT * 2),
+        required_quorum: Decimal::from_str("0.7").unwrap(),
+        required_threshold: Decimal::from_str("0.75").unwrap(),
+        voting_period: PROPOSAL_VOTING_PERIOD * 2,
+        effective_delay: PROPOSAL_EFFECTIVE_DELAY * 2,
+    };
+
+    let update_config = |proposal_types_add: Vec<ProposalType>| {
+        ExecuteMsg::UpdateConfig(UpdateConfig {
+            xastro_token_addr: None,
+            vxastro_token_addr: None,
+            builder_unlock_addr: None,
+            proposal_voting_period: None,
+            proposal_effective_delay: None,
+            proposal_expiration_period: None,
+            proposal_required_deposit: None,
+            proposal_required_quorum: None,
+            proposal_required_threshold: None,
+            proposal_required_veto_threshold: None,
+            expedited_proposal_required_deposit: None,
+            expedited_proposal_voting_period: None,
+            expedited_proposal_required_threshold: None,
+            early_finalization_enabled: None,
+            proposal_required_vxastro_power: None,
+            quorum_failure_deposit_slash: None,
+            veto_deposit_slash: None,
+            slashed_deposit_receiver: None,
+            guardian: None,
+            timelock_addr: None,
+            proposal_validation: None,
+            voting_power_sources: None,
+            whitelist_add: None,
+            whitelist_remove: None,
+            proposal_types_add: Some(proposal_types_add),
+            proposal_types_remove: None,
+            ibc_satellites_add: None,
+            ibc_satellites_remove: None,
+        })
+    };
+
+    // Proposal types are validated like the default proposal rules
+    let res = app
+        .execute_contract(
+            assembly_addr.clone(),
+            assembly_addr.clone(),
+            &update_config(vec![ProposalType {
+                required_threshold: Decimal::percent(10),
+                ..upgrade_type.clone()
+            }]),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        res.to_string(),
+        "Generic error: The required threshold for a proposal cannot be lower than 33% or higher than 100%"
+    );
+
+    app.execute_contract(
+        assembly_addr.clone(),
+        assembly_addr.clone(),
+        &update_config(vec![upgrade_type.clone()]),
+        &[],
+    )
+    .unwrap();
+
+    let config: Config = app
+        .wrap()
+        .query_wasm_smart(assembly_addr.clone(), &QueryMsg::Config {})
+        .unwrap();
+
+    assert_eq!(config.proposal_types, vec![upgrade_type.clone()]);
+
+    // Skip block
+    app.update_block(next_block);
+
+    let submit_proposal = |app: &mut TerraApp, proposal_type: &str, amount: u128| {
+        app.execute_contract(
+            Addr::unchecked("user0"),
+            xastro_addr.clone(),
+            &Cw20ExecuteMsg::Send {
+                contract: assembly_addr.to_string(),
+                amount: Uint128::from(amount),
+                msg: to_binary(&Cw20HookMsg::SubmitProposal {
+                    title: "Test title!".to_string(),
+                    description: "Test description!".to_string(),
+                    link: None,
+                    content_hash: None,
+                    messages: None,
+                    proposal_type: Some(proposal_type.to_string()),
+                    expedited: None,
+                })
+                .unwrap(),
+            },
+            &[],
+        )
+    };
+
+    let res = submit_proposal(&mut app, "signaling", PROPOSAL_REQUIRED_DEPOSIT).unwrap_err();
+    assert_eq!(res.to_string(), "Proposal type not found!");
+
+    // The upgrade type requires a larger deposit
+    let res = submit_proposal(&mut app, "upgrade", PROPOSAL_REQUIRED_DEPOSIT).unwrap_err();
+    assert_eq!(res.to_string(), "Insufficient token deposit!");
+
+    submit_proposal(&mut app, "upgrade", PROPOSAL_REQUIRED_DEPOSIT * 2).unwrap();
+
+    create_proposal(
+        &mut app,
+        &xastro_addr,
+        &assembly_addr,
+        Addr::unchecked("user0"),
+        None,
+    );
+
+    let proposal: Proposal = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::Proposal { proposal_id: 1 },
+        )
+        .unwrap();
+
+    assert_eq!(proposal.proposal_type, Some("upgrade".to_string()));
+    assert_eq!(
+        proposal.end_time,
+        proposal.start_time + PROPOSAL_VOTING_PERIOD * 2
+    );
+    assert_eq!(
+        proposal.effective_time,
+        proposal.end_time + PROPOSAL_EFFECTIVE_DELAY * 2
+    );
+
+    check_total_vp(&mut app, &assembly_addr, 1, 10000);
+
+    for proposal_id in [1, 2].iter() {
+        cast_vote(
+            &mut app,
+            assembly_addr.clone(),
+            *proposal_id,
+            Addr::unchecked("user1"),
+            ProposalVoteOption::For,
+        )
+        .unwrap();
+    }
+
+    // The default voting period ends before the upgrade one
+    app.update_block(|bi| {
+        bi.height += (PROPOSAL_VOTING_PERIOD + 1) / 5;
+        bi.time = bi.time.plus_seconds(PROPOSAL_VOTING_PERIOD + 1);
+    });
+
+    let res = app
+        .execute_contract(
+            Addr::unchecked("user0"),
+            assembly_addr.clone(),
+            &ExecuteMsg::EndProposal { proposal_id: 1 },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(res.to_string(), "Voting period not ended yet!");
+
+    app.update_block(|bi| {
+        bi.height += PROPOSAL_VOTING_PERIOD / 5;
+        bi.time = bi.time.plus_seconds(PROPOSAL_VOTING_PERIOD);
+    });
+
+    for proposal_id in [1, 2].iter() {
+        app.execute_contract(
+            Addr::unchecked("user0"),
+            assembly_addr.clone(),
+            &ExecuteMsg::EndProposal {
+                proposal_id: *proposal_id,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    // 60% of the voting power reaches the default quorum but not the upgrade one
+    let proposal: Proposal = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::Proposal { proposal_id: 1 },
+        )
+        .unwrap();
+
+    assert_eq!(proposal.status, ProposalStatus::Rejected);
+
+    let proposal: Proposal = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::Proposal { proposal_id: 2 },
+        )
+        .unwrap();
+
+    assert_eq!(proposal.status, ProposalStatus::Passed);
+}
+
+#[test]
+fn test_expedited_proposal() {
+    let mut app = mock_app();
+
+    let owner = Addr::unchecked("owner");
+
+    let (_, staking_instance, xastro_addr, _, _, assembly_addr) =
+        instantiate_contracts(&mut app, owner);
+
+    let xastro_balances: Vec<(&str, u128)> = vec![
+        ("user0", EXPEDITED_PROPOSAL_REQUIRED_DEPOSIT * 2), // proposal submitter
+        ("user1", 6000),
+        ("user2", 2000),
+    ];
+
+    for (addr, xastro) in xastro_balances {
+        mint_tokens(
+            &mut app,
+            &staking_instance,
+            &xastro_addr,
+            &Addr::unchecked(addr),
+            xastro,
+        );
+    }
+
+    // Skip block
+    app.update_block(next_block);
+
+    let submit_expedited_proposal = |app: &mut TerraApp, amount: u128| {
+        app.execute_contract(
+            Addr::unchecked("user0"),
+            xastro_addr.clone(),
+            &Cw20ExecuteMsg::Send {
+                contract: assembly_addr.to_string(),
+                amount: Uint128::from(amount),
+                msg: to_binary(&Cw20HookMsg::SubmitProposal {
+                    title: "Test title!".to_string(),
+                    description: "Test description!".to_string(),
+                    link: None,
+                    content_hash: None,
+                    messages: None,
+                    proposal_type: None,
+                    expedited: Some(true),
+                })
+                .unwrap(),
+            },
+            &[],
+        )
+    };
+
+    // Expedited proposals require a larger deposit
+    let res = submit_expedited_proposal(&mut app, PROPOSAL_REQUIRED_DEPOSIT).unwrap_err();
+    assert_eq!(res.to_string(), "Insufficient token deposit!");
+
+    submit_expedited_proposal(&mut app, EXPEDITED_PROPOSAL_REQUIRED_DEPOSIT).unwrap();
+    submit_expedited_proposal(&mut app, EXPEDITED_PROPOSAL_REQUIRED_DEPOSIT).unwrap();
+
+    let proposal: Proposal = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::Proposal { proposal_id: 1 },
+        )
+        .unwrap();
+
+    assert!(proposal.expedited);
+    assert_eq!(
+        proposal.end_time,
+        proposal.start_time + EXPEDITED_PROPOSAL_VOTING_PERIOD
+    );
+
+    check_total_vp(&mut app, &assembly_addr, 1, 12000);
+
+    for proposal_id in [1, 2].iter() {
+        cast_vote(
+            &mut app,
+            assembly_addr.clone(),
+            *proposal_id,
+            Addr::unchecked("user1"),
+            ProposalVoteOption::For,
+        )
+        .unwrap();
+    }
+
+    // 75% of the votes is enough for a regular proposal but not for an expedited one
+    cast_vote(
+        &mut app,
+        assembly_addr.clone(),
+        2,
+        Addr::unchecked("user2"),
+        ProposalVoteOption::Against,
+    )
+    .unwrap();
+
+    app.update_block(|bi| {
+        bi.height += (EXPEDITED_PROPOSAL_VOTING_PERIOD + 1) / 5;
+        bi.time = bi.time.plus_seconds(EXPEDITED_PROPOSAL_VOTING_PERIOD + 1);
+    });
+
+    for proposal_id in [1, 2].iter() {
+        app.execute_contract(
+            Addr::unchecked("user0"),
+            assembly_addr.clone(),
+            &ExecuteMsg::EndProposal {
+                proposal_id: *proposal_id,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    let proposal: Proposal = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::Proposal { proposal_id: 1 },
+        )
+        .unwrap();
+
+    assert_eq!(proposal.status, ProposalStatus::Passed);
+    assert!(!proposal.converted_from_expedited);
+
+    // The second proposal is converted to a regular one and keeps its votes
+    let proposal: Proposal = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::Proposal { proposal_id: 2 },
+        )
+        .unwrap();
+
+    assert_eq!(proposal.status, ProposalStatus::Active);
+    assert!(!proposal.expedited);
+    assert!(proposal.converted_from_expedited);
+    assert_eq!(
+        proposal.end_time,
+        proposal.start_time + PROPOSAL_VOTING_PERIOD
+    );
+    assert_eq!(
+        proposal.effective_time,
+        proposal.end_time + PROPOSAL_EFFECTIVE_DELAY
+    );
+    assert_eq!(proposal.for_power, Uint128::from(6000u32));
+    assert_eq!(proposal.against_power, Uint128::from(2000u32));
+
+    let res = app
+        .execute_contract(
+            Addr::unchecked("user0"),
+            assembly_addr.clone(),
+            &ExecuteMsg::EndProposal { proposal_id: 2 },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(res.to_string(), "Voting period not ended yet!");
+
+    app.update_block(|bi| {
+        bi.height += PROPOSAL_VOTING_PERIOD / 5;
+        bi.time = bi.time.plus_seconds(PROPOSAL_VOTING_PERIOD);
+    });
+
+    app.execute_contract(
+        Addr::unchecked("user0"),
+        assembly_addr.clone(),
+        &ExecuteMsg::EndProposal { proposal_id: 2 },
+        &[],
+    )
+    .unwrap();
+
+    let proposal: Proposal = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::Proposal { proposal_id: 2 },
+        )
+        .unwrap();
+
+    assert_eq!(proposal.status, ProposalStatus::Passed);
+
+    // An expedited proposal that didn't reach quorum is rejected instead of being converted
+    mint_tokens(
+        &mut app,
+        &staking_instance,
+        &xastro_addr,
+        &Addr::unchecked("user0"),
+        EXPEDITED_PROPOSAL_REQUIRED_DEPOSIT,
+    );
+
+    app.update_block(next_block);
+
+    submit_expedited_proposal(&mut app, EXPEDITED_PROPOSAL_REQUIRED_DEPOSIT).unwrap();
+
+    app.update_block(|bi| {
+        bi.height += (EXPEDITED_PROPOSAL_VOTING_PERIOD + 1) / 5;
+        bi.time = bi.time.plus_seconds(EXPEDITED_PROPOSAL_VOTING_PERIOD + 1);
+    });
+
+    app.execute_contract(
+        Addr::unchecked("user0"),
+        assembly_addr.clone(),
+        &ExecuteMsg::EndProposal { proposal_id: 3 },
+        &[],
+    )
+    .unwrap();
+
+    let proposal: Proposal = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::Proposal { proposal_id: 3 },
+        )
+        .unwrap();
+
+    assert_eq!(proposal.status, ProposalStatus::Rejected);
+    assert!(proposal.expedited);
+    assert!(!proposal.converted_from_expedited);
+}
+
+#[test]
+fn test_expedited_proposal_type_threshold() {
+    let mut app = mock_app();
+
+    let owner = Addr::unchecked("owner");
+
+    let (_, staking_instance, xastro_addr, _, _, assembly_addr) =
+        instantiate_contracts(&mut app, owner);
+
+    let xastro_balances: Vec<(&str, u128)> = vec![
+        ("user0", EXPEDITED_PROPOSAL_REQUIRED_DEPOSIT), // proposal submitter
+        ("user1", 6000),
+        ("user2", 1000),
+    ];
+
+    for (addr, xastro) in xastro_balances {
+        mint_tokens(
+            &mut app,
+            &staking_instance,
+            &xastro_addr,
+            &Addr::unchecked(addr),
+            xastro,
+        );
+    }
+
+    // The upgrade type requires a stricter threshold than expedited proposals
+    let upgrade_type = ProposalType {
+        name: "upgrade".to_string(),
+        required_deposit: Uint128::from(PROPOSAL_REQUIRED_DEPOSIT),
+        required_quorum: Decimal::from_str(PROPOSAL_REQUIRED_QUORUM).unwrap(),
+        required_threshold: Decimal::from_str("0.9").unwrap(),
+        voting_period: PROPOSAL_VOTING_PERIOD,
+        effective_delay: PROPOSAL_EFFECTIVE_DELAY,
+    };
+
+    app.execute_contract(
+        assembly_addr.clone(),
+        assembly_addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfig {
+            xastro_token_addr: None,
+            vxastro_token_addr: None,
+            builder_unlock_addr: None,
+            proposal_voting_period: None,
+            proposal_effective_delay: None,
+            proposal_expiration_period: None,
+            proposal_required_deposit: None,
+            proposal_required_quorum: None,
+            proposal_required_threshold: None,
+            proposal_required_veto_threshold: None,
+            expedited_proposal_required_deposit: None,
+            expedited_proposal_voting_period: None,
+            expedited_proposal_required_threshold: None,
+            early_finalization_enabled: None,
+            proposal_required_vxastro_power: None,
+            quorum_failure_deposit_slash: None,
+            veto_deposit_slash: None,
+            slashed_deposit_receiver: None,
+            guardian: None,
+            timelock_addr: None,
+            proposal_validation: None,
+            voting_power_sources: None,
+            whitelist_add: None,
+            whitelist_remove: None,
+            proposal_types_add: Some(vec![upgrade_type]),
+            proposal_types_remove: None,
+            ibc_satellites_add: None,
+            ibc_satellites_remove: None,
+        }),
+        &[],
+    )
+    .unwrap();
+
+    // Skip block
+    app.update_block(next_block);
+
+    app.execute_contract(
+        Addr::unchecked("user0"),
+        xastro_addr.clone(),
+        &Cw20ExecuteMsg::Send {
+            contract: assembly_addr.to_string(),
+            amount: Uint128::from(EXPEDITED_PROPOSAL_REQUIRED_DEPOSIT),
+            msg: to_binary(&Cw20HookMsg::SubmitProposal {
+                title: "Test title!".to_string(),
+                description: "Test description!".to_string(),
+                link: None,
+                content_hash: None,
+                messages: None,
+                proposal_type: Some("upgrade".to_string()),
+                expedited: Some(true),
+            })
+            .unwrap(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Over 85% of the votes exceeds the expedited threshold but not the threshold of the proposal type
+    cast_vote(
+        &mut app,
+        assembly_addr.clone(),
+        1,
+        Addr::unchecked("user1"),
+        ProposalVoteOption::For,
+    )
+    .unwrap();
+    cast_vote(
+        &mut app,
+        assembly_addr.clone(),
+        1,
+        Addr::unchecked("user2"),
+        ProposalVoteOption::Against,
+    )
+    .unwrap();
+
+    app.update_block(|bi| {
+        bi.height += (EXPEDITED_PROPOSAL_VOTING_PERIOD + 1) / 5;
+        bi.time = bi.time.plus_seconds(EXPEDITED_PROPOSAL_VOTING_PERIOD + 1);
+    });
+
+    app.execute_contract(
+        Addr::unchecked("user0"),
+        assembly_addr.clone(),
+        &ExecuteMsg::EndProposal { proposal_id: 1 },
+        &[],
+    )
+    .unwrap();
+
+    let proposal: Proposal = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::Proposal { proposal_id: 1 },
+        )
+        .unwrap();
+
+    assert_eq!(proposal.status, ProposalStatus::Active);
+    assert!(proposal.converted_from_expedited);
+
+    app.update_block(|bi| {
+        bi.height += PROPOSAL_VOTING_PERIOD / 5;
+        bi.time = bi.time.plus_seconds(PROPOSAL_VOTING_PERIOD);
+    });
+
+    app.execute_contract(
+        Addr::unchecked("user0"),
+        assembly_addr.clone(),
+        &ExecuteMsg::EndProposal { proposal_id: 1 },
+        &[],
+    )
+    .unwrap();
+
+    let proposal: Proposal = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::Proposal { proposal_id: 1 },
+        )
+        .unwrap();
+
+    assert_eq!(proposal.status, ProposalStatus::Rejected);
+}
+
+#[test]
+fn test_early_finalization() {
+    let mut app = mock_app();
+
+    let owner = Addr::unchecked("owner");
+
+    let (_, staking_instance, xastro_addr, _, _, assembly_addr) =
+        instantiate_contracts(&mut app, owner);
+
+    let xastro_balances: Vec<(&str, u128)> = vec![
+        ("user0", PROPOSAL_REQUIRED_DEPOSIT * 3), // proposal submitter
+        ("user1", 8000),
+        ("user2", 1000),
+    ];
+
+    for (addr, xastro) in xastro_balances {
+        mint_tokens(
+            &mut app,
+            &staking_instance,
+            &xastro_addr,
+            &Addr::unchecked(addr),
+            xastro,
+        );
+    }
+
+    // user1 votes with the voting power of user2 if user2 doesn't vote
+    app.execute_contract(
+        Addr::unchecked("user2"),
+        assembly_addr.clone(),
+        &ExecuteMsg::Delegate {
+            delegatee: "user1".to_string(),
+            proposal_id: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Skip block
+    app.update_block(next_block);
+
+    for _ in 0..3 {
+        create_proposal(
+            &mut app,
+            &xastro_addr,
+            &assembly_addr,
+            Addr::unchecked("user0"),
+            None,
+        );
+    }
+
+    check_total_vp(&mut app, &assembly_addr, 1, 12000);
+
+    let votes = vec![
+        (1, "user1", ProposalVoteOption::For),
+        (2, "user1", ProposalVoteOption::Against),
+        (3, "user2", ProposalVoteOption::For),
+    ];
+
+    for (proposal_id, voter, option) in votes {
+        cast_vote(
+            &mut app,
+            assembly_addr.clone(),
+            proposal_id,
+            Addr::unchecked(voter),
+            option,
+        )
+        .unwrap();
+    }
+
+    let end_proposal = |app: &mut TerraApp, proposal_id: u64| {
+        app.execute_contract(
+            Addr::unchecked("user0"),
+            assembly_addr.clone(),
+            &ExecuteMsg::EndProposal { proposal_id },
+            &[],
+        )
+    };
+
+    // Early finalization is disabled by default
+    let res = end_proposal(&mut app, 1).unwrap_err();
+    assert_eq!(res.to_string(), "Voting period not ended yet!");
+
+    app.execute_contract(
+        assembly_addr.clone(),
+        assembly_addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfig {
+            xastro_token_addr: None,
+            vxastro_token_addr: None,
+            builder_unlock_addr: None,
+            proposal_voting_period: None,
+            proposal_effective_delay: None,
+            proposal_expiration_period: None,
+            proposal_required_deposit: None,
+            proposal_required_quorum: None,
+            proposal_required_threshold: None,
+            proposal_required_veto_threshold: None,
+            expedited_proposal_required_deposit: None,
+            expedited_proposal_voting_period: None,
+            expedited_proposal_required_threshold: None,
+            early_finalization_enabled: Some(true),
+            proposal_required_vxastro_power: None,
+            quorum_failure_deposit_slash: None,
+            veto_deposit_slash: None,
+            slashed_deposit_receiver: None,
+            guardian: None,
+            timelock_addr: None,
+            proposal_validation: None,
+            voting_power_sources: None,
+            whitelist_add: None,
+            whitelist_remove: None,
+            proposal_types_add: None,
+            proposal_types_remove: None,
+            ibc_satellites_add: None,
+            ibc_satellites_remove: None,
+        }),
+        &[],
+    )
+    .unwrap();
+
+    // Votes are final once proposals can be ended early, otherwise a re-vote could flip the result
+    let res = cast_vote(
+        &mut app,
+        assembly_addr.clone(),
+        1,
+        Addr::unchecked("user1"),
+        ProposalVoteOption::Against,
+    )
+    .unwrap_err();
+    assert_eq!(res.to_string(), "User already voted!");
+
+    // A delegator can't override the vote their delegatee cast with their voting power either
+    let res = cast_vote(
+        &mut app,
+        assembly_addr.clone(),
+        1,
+        Addr::unchecked("user2"),
+        ProposalVoteOption::NoWithVeto,
+    )
+    .unwrap_err();
+    assert_eq!(
+        res.to_string(),
+        "Delegated voting power was already used on this proposal!"
+    );
+
+    // Even if the remaining 3000 of voting power votes `NoWithVeto`, the proposal passes
+    end_proposal(&mut app, 1).unwrap();
+
+    let proposal: Proposal = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::Proposal { proposal_id: 1 },
+        )
+        .unwrap();
+
+    let block_time = app.block_info().time.seconds();
+
+    assert_eq!(proposal.status, ProposalStatus::Passed);
+    assert_eq!(proposal.end_time, block_time);
+    assert_eq!(
+        proposal.effective_time,
+        block_time + PROPOSAL_EFFECTIVE_DELAY
+    );
+
+    // Even if the remaining 3000 of voting power votes `For`, the proposal is rejected
+    end_proposal(&mut app, 2).unwrap();
+
+    let proposal: Proposal = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::Proposal { proposal_id: 2 },
+        )
+        .unwrap();
+
+    assert_eq!(proposal.status, ProposalStatus::Rejected);
+
+    // The third proposal can still go either way
+    let res = end_proposal(&mut app, 3).unwrap_err();
+    assert_eq!(res.to_string(), "Voting period not ended yet!");
+}
+
+#[test]
+fn test_proposal_submission_with_voting_power() {
+    let mut app = mock_app();
+
+    let owner = Addr::unchecked("owner");
+
+    let (_, staking_instance, xastro_addr, vxastro_addr, _, assembly_addr) =
+        instantiate_contracts(&mut app, owner);
+
+    mint_vxastro(
+        &mut app,
+        &staking_instance,
+        xastro_addr.clone(),
+        &vxastro_addr,
+        Addr::unchecked("user1"),
+        2000,
+    );
+
+    mint_vxastro(
+        &mut app,
+        &staking_instance,
+        xastro_addr.clone(),
+        &vxastro_addr,
+        Addr::unchecked("user2"),
+        100,
+    );
+
+    // Skip block
+    app.update_block(next_block);
+
+    let submit_proposal_msg = ExecuteMsg::SubmitProposal {
+        title: "Test title!".to_string(),
+        description: "Test description!".to_string(),
+        link: None,
+        content_hash: None,
+        messages: None,
+        proposal_type: None,
+    };
+
+    // Submission with vxASTRO voting power is disabled by default
+    let res = app
+        .execute_contract(
+            Addr::unchecked("user1"),
+            assembly_addr.clone(),
+            &submit_proposal_msg,
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        res.to_string(),
+        "Proposal submission with vxASTRO voting power is disabled!"
+    );
+
+    app.execute_contract(
+        assembly_addr.clone(),
+        assembly_addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfig {
+            xastro_token_addr: None,
+            vxastro_token_addr: None,
+            builder_unlock_addr: None,
+            proposal_voting_period: None,
+            proposal_effective_delay: None,
+            proposal_expiration_period: None,
+            proposal_required_deposit: None,
+            proposal_required_quorum: None,
+            proposal_required_threshold: None,
+            proposal_required_veto_threshold: None,
+            expedited_proposal_required_deposit: None,
+            expedited_proposal_voting_period: None,
+            expedited_proposal_required_threshold: None,
+            early_finalization_enabled: None,
+            proposal_required_vxastro_power: Some(1000),
+            quorum_failure_deposit_slash: None,
+            veto_deposit_slash: None,
+            slashed_deposit_receiver: None,
+            guardian: None,
+            timelock_addr: None,
+            proposal_validation: None,
+            voting_power_sources: None,
+            whitelist_add: None,
+            whitelist_remove: None,
+            proposal_types_add: None,
+            proposal_types_remove: None,
+            ibc_satellites_add: None,
+            ibc_satellites_remove: None,
+        }),
+        &[],
+    )
+    .unwrap();
+
+    // The voting power is read at the end of the previous week, so a lock created in the current
+    // week doesn't count yet
+    let res = app
+        .execute_contract(
+            Addr::unchecked("user1"),
+            assembly_addr.clone(),
+            &submit_proposal_msg,
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(res.to_string(), "Insufficient vxASTRO voting power!");
+
+    app.update_block(|bi| {
+        bi.height += WEEK / 5;
+        bi.time = bi.time.plus_seconds(WEEK);
+    });
+
+    mint_vxastro(
+        &mut app,
+        &staking_instance,
+        xastro_addr.clone(),
+        &vxastro_addr,
+        Addr::unchecked("user3"),
+        2000,
+    );
+
+    for user in ["user2", "user3"].iter() {
+        let res = app
+            .execute_contract(
+                Addr::unchecked(*user),
+                assembly_addr.clone(),
+                &submit_proposal_msg,
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(res.to_string(), "Insufficient vxASTRO voting power!");
+    }
+
+    app.execute_contract(
+        Addr::unchecked("user1"),
+        assembly_addr.clone(),
+        &submit_proposal_msg,
+        &[],
+    )
+    .unwrap();
+
+    let proposal: Proposal = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::Proposal { proposal_id: 1 },
+        )
+        .unwrap();
+
+    assert_eq!(proposal.submitter, Addr::unchecked("user1"));
+    assert_eq!(proposal.status, ProposalStatus::Active);
+    assert_eq!(proposal.deposit_amount, Uint128::zero());
+
+    // A proposal without a deposit can be cancelled as usual
+    app.execute_contract(
+        Addr::unchecked("user1"),
+        assembly_addr.clone(),
+        &ExecuteMsg::CancelProposal { proposal_id: 1 },
+        &[],
+    )
+    .unwrap();
+
+    let proposal: Proposal = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::Proposal { proposal_id: 1 },
+        )
+        .unwrap();
+
+    assert_eq!(proposal.status, ProposalStatus::Cancelled);
+
+    // The number of active proposals submitted by an address is limited
+    for _ in 0..MAX_ACTIVE_PROPOSALS_PER_SUBMITTER {
+        app.execute_contract(
+            Addr::unchecked("user1"),
+            assembly_addr.clone(),
+            &submit_proposal_msg,
+            &[],
+        )
+        .unwrap();
+    }
+
+    let res = app
+        .execute_contract(
+            Addr::unchecked("user1"),
+            assembly_addr.clone(),
+            &submit_proposal_msg,
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        res.to_string(),
+        "The submitter has too many active proposals!"
+    );
+}
+
+#[test]
+fn test_timelock_execution() {
+    let mut app = mock_app();
+
+    let owner = Addr::unchecked("owner");
+
+    let (_, staking_instance, xastro_addr, _, _, assembly_addr) =
+        instantiate_contracts(&mut app, owner);
+
+    let timelock =
+        TimelockHelper::init(&mut app, assembly_addr.clone(), Addr::unchecked("guardian"));
+
+    let xastro_balances: Vec<(&str, u128)> = vec![
+        ("user0", PROPOSAL_REQUIRED_DEPOSIT * 2), // proposal submitter
+        ("user1", 3000),
+    ];
+
+    for (addr, xastro) in xastro_balances {
+        mint_tokens(
+            &mut app,
+            &staking_instance,
+            &xastro_addr,
+            &Addr::unchecked(addr),
+            xastro,
+        );
+    }
+
+    let update_config = UpdateConfig {
+        xastro_token_addr: None,
+        vxastro_token_addr: None,
+        builder_unlock_addr: None,
+        proposal_voting_period: None,
+        proposal_effective_delay: None,
+        proposal_expiration_period: None,
+        proposal_required_deposit: None,
+        proposal_required_quorum: None,
+        proposal_required_threshold: None,
+        proposal_required_veto_threshold: None,
+        expedited_proposal_required_deposit: None,
+        expedited_proposal_voting_period: None,
+        expedited_proposal_required_threshold: None,
+        early_finalization_enabled: None,
+        proposal_required_vxastro_power: None,
+        quorum_failure_deposit_slash: None,
+        veto_deposit_slash: None,
+        slashed_deposit_receiver: None,
+        guardian: None,
+        timelock_addr: None,
+        proposal_validation: None,
+        voting_power_sources: None,
+        whitelist_add: None,
+        whitelist_remove: None,
+        proposal_types_add: None,
+        proposal_types_remove: None,
+        ibc_satellites_add: None,
+        ibc_satellites_remove: None,
+    };
+
+    app.execute_contract(
+        assembly_addr.clone(),
+        assembly_addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfig {
+            timelock_addr: Some(timelock.timelock_instance.to_string()),
+            guardian: Some("guardian".to_string()),
+            ..update_config.clone()
+        }),
+        &[],
+    )
+    .unwrap();
+
+    // Skip block
+    app.update_block(next_block);
+
+    let proposal_message = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: assembly_addr.to_string(),
+        msg: to_binary(&ExecuteMsg::UpdateConfig(UpdateConfig {
+            proposal_voting_period: Some(750),
+            ..update_config
+        }))
+        .unwrap(),
+        funds: vec![],
+    });
+
+    for proposal_id in [1, 2].iter() {
+        create_proposal(
+            &mut app,
+            &xastro_addr,
+            &assembly_addr,
+            Addr::unchecked("user0"),
+            Some(vec![ProposalMessage {
+                order: Uint64::from(1u32),
+                ibc_channel: None,
+                msg: proposal_message.clone(),
+            }]),
+        );
+
+        cast_vote(
+            &mut app,
+            assembly_addr.clone(),
+            *proposal_id,
+            Addr::unchecked("user1"),
+            ProposalVoteOption::For,
+        )
+        .unwrap();
+    }
+
+    // Skip voting period
+    app.update_block(|bi| {
+        bi.height += (PROPOSAL_VOTING_PERIOD + 1) / 5;
+        bi.time = bi.time.plus_seconds(PROPOSAL_VOTING_PERIOD + 1);
+    });
+
+    for proposal_id in [1, 2].iter() {
+        app.execute_contract(
+            Addr::unchecked("user0"),
+            assembly_addr.clone(),
+            &ExecuteMsg::EndProposal {
+                proposal_id: *proposal_id,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    // Skip effective delay
+    app.update_block(|bi| {
+        bi.height += (PROPOSAL_EFFECTIVE_DELAY + 1) / 5;
+        bi.time = bi.time.plus_seconds(PROPOSAL_EFFECTIVE_DELAY + 1);
+    });
+
+    // The proposal messages are queued in the Timelock instead of being executed
+    for proposal_id in [1, 2].iter() {
+        app.execute_contract(
+            Addr::unchecked("user0"),
+            assembly_addr.clone(),
+            &ExecuteMsg::ExecuteProposal {
+                proposal_id: *proposal_id,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    let proposal: Proposal = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::Proposal { proposal_id: 1 },
+        )
+        .unwrap();
+
+    assert_eq!(proposal.status, ProposalStatus::Queued);
+
+    let config: Config = app
+        .wrap()
+        .query_wasm_smart(assembly_addr.clone(), &QueryMsg::Config {})
+        .unwrap();
+
+    assert_eq!(config.proposal_voting_period, PROPOSAL_VOTING_PERIOD);
+
+    let transaction = timelock.query_transaction(&mut app, 1).unwrap();
+    assert_eq!(transaction.proposal_id, 1);
+    assert_eq!(transaction.messages, vec![proposal_message]);
+    assert_eq!(transaction.status, TransactionStatus::Queued);
+
+    let res = timelock
+        .execute_transaction(&mut app, "user0", 1)
+        .unwrap_err();
+    assert_eq!(res.to_string(), "Transaction delay not ended!");
+
+    let res = app
+        .execute_contract(
+            Addr::unchecked("user0"),
+            assembly_addr.clone(),
+            &ExecuteMsg::SyncQueuedProposal { proposal_id: 1 },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(res.to_string(), "Timelock transaction is still queued!");
+
+    // The guardian cancels a queued proposal together with its Timelock transaction
+    app.execute_contract(
+        Addr::unchecked("guardian"),
+        assembly_addr.clone(),
+        &ExecuteMsg::CancelProposal { proposal_id: 2 },
+        &[],
+    )
+    .unwrap();
+
+    let proposal: Proposal = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::Proposal { proposal_id: 2 },
+        )
+        .unwrap();
+    assert_eq!(proposal.status, ProposalStatus::Cancelled);
+
+    let transaction = timelock.query_proposal_transaction(&mut app, 2).unwrap();
+    assert_eq!(transaction.status, TransactionStatus::Cancelled);
+
+    // Skip timelock delay
+    app.update_block(|bi| {
+        bi.height += TIMELOCK_DELAY / 5;
+        bi.time = bi.time.plus_seconds(TIMELOCK_DELAY);
+    });
+
+    timelock.execute_transaction(&mut app, "user0", 1).unwrap();
+
+    let config: Config = app
+        .wrap()
+        .query_wasm_smart(assembly_addr.clone(), &QueryMsg::Config {})
+        .unwrap();
+
+    assert_eq!(config.proposal_voting_period, 750);
+
+    // Anyone can update the status of the proposal once its Timelock transaction was executed
+    app.execute_contract(
+        Addr::unchecked("user1"),
+        assembly_addr.clone(),
+        &ExecuteMsg::SyncQueuedProposal { proposal_id: 1 },
+        &[],
+    )
+    .unwrap();
+
+    let proposal: Proposal = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::Proposal { proposal_id: 1 },
+        )
+        .unwrap();
+    assert_eq!(proposal.status, ProposalStatus::Executed);
+
+    let res = app
+        .execute_contract(
+            Addr::unchecked("user1"),
+            assembly_addr.clone(),
+            &ExecuteMsg::SyncQueuedProposal { proposal_id: 1 },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(res.to_string(), "Proposal not queued!");
+}
+
+#[test]
+fn test_transferred_vxastro_lock_voting() {
+    let mut app = mock_app();
+
+    let owner = Addr::unchecked("owner");
+
+    let (_, staking_instance, xastro_addr, vxastro_addr, _, assembly_addr) =
+        instantiate_contracts(&mut app, owner);
+
+    mint_tokens(
+        &mut app,
+        &staking_instance,
+        &xastro_addr,
+        &Addr::unchecked("user0"),
+        PROPOSAL_REQUIRED_DEPOSIT,
+    );
+
+    mint_vxastro(
+        &mut app,
+        &staking_instance,
+        xastro_addr.clone(),
+        &vxastro_addr,
+        Addr::unchecked("user1"),
+        1000,
+    );
+
+    app.update_block(|bi| {
+        bi.height += 1;
+        bi.time = bi.time.plus_seconds(WEEK);
+    });
+
+    create_proposal(
+        &mut app,
+        &xastro_addr,
+        &assembly_addr,
+        Addr::unchecked("user0"),
+        None,
+    );
+
+    let user1_vp: Uint128 = app
+        .wrap()
+        .query_wasm_smart(
+            assembly_addr.clone(),
+            &QueryMsg::UserVotingPower {
+                user: "user1".to_string(),
+                proposal_id: 1,
+            },
+        )
+        .unwrap();
+
+    assert!(!user1_vp.is_zero());
+
+    cast_vote(
+        &mut app,
+        assembly_addr.clone(),
+        1,
+        Addr::unchecked("user1"),
+        ProposalVoteOption::For,
+    )
+    .unwrap();
+
+    // The lock position is moved to user2 during the voting period
+    app.execute_contract(
+        Addr::unchecked("user1"),
+        vxastro_addr.clone(),
+        &VXAstroExecuteMsg::TransferLock {
+            lock_id: 1,
+            recipient: "user2".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked("user2"),
+        vxastro_addr,
+        &VXAstroExecuteMsg::AcceptLockTransfer {
+            sender: "user1".to_string(),
+            lock_id: 1,
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.update_block(next_block);
+
+    // The voting power snapshot of the proposal doesn't change
+    check_user_vp(
+        &mut app,
+        &assembly_addr,
+        &Addr::unchecked("user1"),
+        1,
+        user1_vp.u128(),
+    );
+    check_user_vp(&mut app, &assembly_addr, &Addr::unchecked("user2"), 1, 0);
+
+    let err = cast_vote(
+        &mut app,
+        assembly_addr.clone(),
+        1,
+        Addr::unchecked("user2"),
+        ProposalVoteOption::For,
+    )
+    .unwrap_err();
+
+    assert_eq!(err.to_string(), "You don't have any voting power!");
+
+    let proposal_votes: ProposalVotesResponse = app
+        .wrap()
+        .query_wasm_smart(assembly_addr, &QueryMsg::ProposalVotes { proposal_id: 1 })
+        .unwrap();
+
+    assert_eq!(proposal_votes.for_power, user1_vp);
+}
+
+#[test]
+fn test_voting_power_sources() {
+    let mut app = mock_app();
+
+    let owner = Addr::unchecked("owner");
+
+    let (_, staking_instance, xastro_addr, vxastro_addr, builder_unlock_addr, assembly_addr) =
+        instantiate_contracts(&mut app, owner.clone());
+
+    // xASTRO, the builder unlock contract and vxASTRO are used by default
+    let config: Config = app
+        .wrap()
+        .query_wasm_smart(assembly_addr.clone(), &QueryMsg::Config {})
+        .unwrap();
+
+    assert_eq!(
+        config.voting_power_sources,
+        vec![
+            VotingPowerSource {
+                contract_addr: xastro_addr.clone(),
+                adapter: VotingPowerAdapter::Cw20Snapshot,
+                weight: Decimal::one(),
+            },
+            VotingPowerSource {
+                contract_addr: builder_unlock_addr,
+                adapter: VotingPowerAdapter::BuilderUnlock,
+                weight: Decimal::one(),
+            },
+            VotingPowerSource {
+                contract_addr: vxastro_addr.clone(),
+                adapter: VotingPowerAdapter::VotingEscrow,
+                weight: Decimal::one(),
+            },
+        ]
+    );
+
+    let lp_staking_contract = Box::new(ContractWrapper::new_with_empty(
+        mock_lp_staking_execute,
+        mock_lp_staking_instantiate,
+        mock_lp_staking_query,
+    ));
+
+    let lp_staking_code = app.store_code(lp_staking_contract);
+
+    let lp_staking_addr = app
+        .instantiate_contract(
+            lp_staking_code,
+            owner,
+            &Empty {},
+            &[],
+            "LP staking".to_string(),
+            None,
+        )
+        .unwrap();
+
+    let xastro_balances: Vec<(&str, u128)> = vec![
+        ("user0", PROPOSAL_REQUIRED_DEPOSIT), // proposal submitter
+        ("user1", 1000),
+    ];
+
+    for (addr, xastro) in xastro_balances {
+        mint_tokens(
+            &mut app,
+            &staking_instance,
+            &xastro_addr,
+            &Addr::unchecked(addr),
+            xastro,
+        );
+    }
+
+    let update_config = UpdateConfig {
+        xastro_token_addr: None,
+        vxastro_token_addr: None,
+        builder_unlock_addr: None,
+        proposal_voting_period: None,
+        proposal_effective_delay: None,
+        proposal_expiration_period: None,
+        proposal_required_deposit: None,
+        proposal_required_quorum: None,
+        proposal_required_threshold: None,
+        proposal_required_veto_threshold: None,
+        expedited_proposal_required_deposit: None,
+        expedited_proposal_voting_period: None,
+        expedited_proposal_required_threshold: None,
+        early_finalization_enabled: None,
+        proposal_required_vxastro_power: None,
+        quorum_failure_deposit_slash: None,
+        veto_deposit_slash: None,
+        slashed_deposit_receiver: None,
+        guardian: None,
+        timelock_addr: None,
+        proposal_validation: None,
+        voting_power_sources: Some(vec![]),
+        whitelist_add: None,
+        whitelist_remove: None,
+        proposal_types_add: None,
+        proposal_types_remove: None,
+        ibc_satellites_add: None,
+        ibc_satellites_remove: None,
+    };
+
+    let err = app
+        .execute_contract(
+            assembly_addr.clone(),
+            assembly_addr.clone(),
+            &ExecuteMsg::UpdateConfig(update_config.clone()),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "Generic error: The number of voting power sources must be between 1 and 10"
+    );
+
+    let xastro_source = VotingPowerSource {
+        contract_addr: xastro_addr.clone(),
+        adapter: VotingPowerAdapter::Cw20Snapshot,
+        weight: Decimal::zero(),
+    };
+
+    let err = app
+        .execute_contract(
+            assembly_addr.clone(),
+            assembly_addr.clone(),
+            &ExecuteMsg::UpdateConfig(UpdateConfig {
+                voting_power_sources: Some(vec![xastro_source.clone()]),
+                ..update_config.clone()
+            }),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        format!(
+            "Generic error: The weight of the voting power source {} cannot be zero",
+            xastro_addr
+        )
+    );
+
+    let xastro_source = VotingPowerSource {
+        weight: Decimal::from_ratio(2u128, 1u128),
+        ..xastro_source
+    };
+
+    let err = app
+        .execute_contract(
+            assembly_addr.clone(),
+            assembly_addr.clone(),
+            &ExecuteMsg::UpdateConfig(UpdateConfig {
+                voting_power_sources: Some(vec![xastro_source.clone(), xastro_source.clone()]),
+                ..update_config.clone()
+            }),
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        format!(
+            "Generic error: Duplicate voting power source: {}",
+            xastro_addr
+        )
+    );
+
+    // xASTRO counts twice, LP staking power counts half and the builder unlock contract is not used
+    app.execute_contract(
+        assembly_addr.clone(),
+        assembly_addr.clone(),
+        &ExecuteMsg::UpdateConfig(UpdateConfig {
+            voting_power_sources: Some(vec![
+                xastro_source,
+                VotingPowerSource {
+                    contract_addr: vxastro_addr,
+                    adapter: VotingPowerAdapter::VotingEscrow,
+                    weight: Decimal::one(),
+                },
+                VotingPowerSource {
+                    contract_addr: lp_staking_addr,
+                    adapter: VotingPowerAdapter::VotingPowerAt,
+                    weight: Decimal::percent(50),
+                },
+            ]),
+            ..update_config
+        }),
+        &[],
+    )
+    .unwrap();
+
+    app.update_block(next_block);
+
+    create_proposal(
+        &mut app,
+        &xastro_addr,
+        &assembly_addr,
+        Addr::unchecked("user0"),
+        None,
+    );
+
+    // 2000 xASTRO * 2 + 10000 LP staking power * 0.5
+    check_total_vp(&mut app, &assembly_addr, 1, 9000);
+    // 1000 xASTRO * 2 + 500 LP staking power * 0.5
+    check_user_vp(&mut app, &assembly_addr, &Addr::unchecked("user1"), 1, 2250);
+    // Only user1 has LP staking power
+    check_user_vp(&mut app, &assembly_addr, &Addr::unchecked("user2"), 1, 0);
+
+    cast_vote(
+        &mut app,
+        assembly_addr.clone(),
+        1,
+        Addr::unchecked("user1"),
+        ProposalVoteOption::For,
+    )
+    .unwrap();
+
+    let proposal_votes: ProposalVotesResponse = app
+        .wrap()
+        .query_wasm_smart(assembly_addr, &QueryMsg::ProposalVotes { proposal_id: 1 })
+        .unwrap();
+
+    assert_eq!(proposal_votes.for_power, Uint128::new(2250));
+}
+
+fn mock_lp_staking_instantiate(
+    _deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: Empty,
+) -> StdResult<Response> {
+    Ok(Response::default())
+}
+
+fn mock_lp_staking_execute(
+    _deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: Empty,
+) -> StdResult<Response> {
+    Ok(Response::default())
+}
+
+fn mock_lp_staking_query(_deps: Deps, _env: Env, msg: VotingPowerQueryMsg) -> StdResult<Binary> {
+    let voting_power = match msg {
+        VotingPowerQueryMsg::VotingPowerAt { user, .. } if user == "user1" => Uint128::new(500),
+        VotingPowerQueryMsg::VotingPowerAt { .. } => Uint128::zero(),
+        VotingPowerQueryMsg::TotalVotingPowerAt { .. } => Uint128::new(10_000),
+    };
+
+    to_binary(&VotingPowerResponse { voting_power })
+}
+
+fn mock_app() -> TerraApp {
+    let mut env = mock_env();
+    env.block.time = Timestamp::from_seconds(EPOCH_START);
+    let api = MockApi::default();
+    let bank = BankKeeper::new();
+    let storage = MockStorage::new();
+    let custom = TerraMock::luna_ust_case();
+
+    AppBuilder::new()
+        .with_api(api)
+        .with_block(env.block)
+        .with_bank(bank)
+        .with_storage(storage)
+        .with_custom(custom)
+        .build()
+}
+
+fn instantiate_contracts(
+    router: &mut TerraApp,
+    owner: Addr,
+) -> (Addr, Addr, Addr, Addr, Addr, Addr) {
+    let token_addr = instantiate_astro_token(router, &owner);
+    let (staking_instance, xastro_token_addr) =
+        instantiate_xastro_token(router, &owner, &token_addr);
+    let vxastro_token_addr = instantiate_vxastro_token(router, &owner, &xastro_token_addr);
+    let builder_unlock_addr = instantiate_builder_unlock_contract(router, &owner, &token_addr);
+    let assembly_addr = instantiate_assembly_contract(
+        router,
+        &owner,
+        &xastro_token_addr,
+        &vxastro_token_addr,
+        &builder_unlock_addr,
+    );
+
+    assert_eq!("contract #0", token_addr);
+    assert_eq!("contract #2", xastro_token_addr);
+    assert_eq!("contract #3", vxastro_token_addr);
+    assert_eq!("contract #4", builder_unlock_addr);
+    assert_eq!("contract #5", assembly_addr);
+
+    (
+        token_addr,
+        staking_instance,
+        xastro_token_addr,
+        vxastro_token_addr,
+        builder_unlock_addr,
+        assembly_addr,
+    )
+}
+
+fn instantiate_astro_token(router: &mut TerraApp, owner: &Addr) -> Addr {
+    let astro_token_contract = Box::new(ContractWrapper::new_with_empty(
+        astroport_token::contract::execute,
+        astroport_token::contract::instantiate,
+        astroport_token::contract::query,
+    ));
+
+    let astro_token_code_id = router.store_code(astro_token_contract);
+
+    let msg = TokenInstantiateMsg {
+        name: String::from("Astro token"),
+        symbol: String::from("ASTRO"),
+        decimals: 6,
+        initial_balances: vec![],
+        mint: Some(MinterResponse {
+            minter: owner.to_string(),
+            cap: None,
+        }),
+    };
+
+    router
+        .instantiate_contract(
+            astro_token_code_id,
+            owner.clone(),
+            &msg,
+            &[],
+            String::from("ASTRO"),
+            None,
+        )
+        .unwrap()
+}
+
+fn instantiate_xastro_token(
+    router: &mut TerraApp,
+    owner: &Addr,
+    astro_token: &Addr,
+) -> (Addr, Addr) {
+    let xastro_contract = Box::new(ContractWrapper::new_with_empty(
+        astroport_xastro_token::contract::execute,
+        astroport_xastro_token::contract::instantiate,
+        astroport_xastro_token::contract::query,
+    ));
+
+    let xastro_code_id = router.store_code(xastro_contract);
+
+    let staking_contract = Box::new(
+        ContractWrapper::new_with_empty(
+            astroport_staking::contract::execute,
+            astroport_staking::contract::instantiate,
+            astroport_staking::contract::query,
+        )
+        .with_reply_empty(astroport_staking::contract::reply),
+    );
+
+    let staking_code_id = router.store_code(staking_contract);
+
+    let msg = astroport::staking::InstantiateMsg {
+        owner: owner.to_string(),
+        token_code_id: xastro_code_id,
+        deposit_token_addr: astro_token.to_string(),
+    };
+    let staking_instance = router
+        .instantiate_contract(
+            staking_code_id,
+            owner.clone(),
+            &msg,
+            &[],
+            String::from("xASTRO"),
+            None,
+        )
+        .unwrap();
+
+    let res = router
+        .wrap()
+        .query::<astroport::staking::ConfigResponse>(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: staking_instance.to_string(),
+            msg: to_binary(&astroport::staking::QueryMsg::Config {}).unwrap(),
+        }))
+        .unwrap();
+
+    (staking_instance, res.share_token_addr)
+}
 
 fn instantiate_vxastro_token(router: &mut TerraApp, owner: &Addr, xastro: &Addr) -> Addr {
     let vxastro_token_contract = Box::new(ContractWrapper::new_with_empty(
@@ -1425,11 +5405,14 @@ fn instantiate_assembly_contract(
     vxastro: &Addr,
     builder: &Addr,
 ) -> Addr {
-    let assembly_contract = Box::new(ContractWrapper::new_with_empty(
-        astro_assembly::contract::execute,
-        astro_assembly::contract::instantiate,
-        astro_assembly::contract::query,
-    ));
+    let assembly_contract = Box::new(
+        ContractWrapper::new_with_empty(
+            astro_assembly::contract::execute,
+            astro_assembly::contract::instantiate,
+            astro_assembly::contract::query,
+        )
+        .with_reply_empty(astro_assembly::contract::reply),
+    );
 
     let assembly_code = router.store_code(assembly_contract);
 
@@ -1443,7 +5426,21 @@ fn instantiate_assembly_contract(
         proposal_required_deposit: Uint128::new(PROPOSAL_REQUIRED_DEPOSIT),
         proposal_required_quorum: String::from(PROPOSAL_REQUIRED_QUORUM),
         proposal_required_threshold: String::from(PROPOSAL_REQUIRED_THRESHOLD),
+        proposal_required_veto_threshold: String::from(PROPOSAL_REQUIRED_VETO_THRESHOLD),
+        expedited_proposal_required_deposit: Uint128::from(EXPEDITED_PROPOSAL_REQUIRED_DEPOSIT),
+        expedited_proposal_voting_period: EXPEDITED_PROPOSAL_VOTING_PERIOD,
+        expedited_proposal_required_threshold: String::from(EXPEDITED_PROPOSAL_REQUIRED_THRESHOLD),
+        early_finalization_enabled: None,
+        proposal_required_vxastro_power: None,
+        quorum_failure_deposit_slash: String::from(QUORUM_FAILURE_DEPOSIT_SLASH),
+        veto_deposit_slash: String::from(VETO_DEPOSIT_SLASH),
+        slashed_deposit_receiver: None,
+        guardian: Some("guardian".to_string()),
+        timelock_addr: None,
+        proposal_validation: None,
+        voting_power_sources: None,
         whitelisted_links: vec!["https://some.link/".to_string()],
+        proposal_types: None,
     };
 
     router
@@ -1536,7 +5533,10 @@ fn create_proposal(
         title: "Test title!".to_string(),
         description: "Test description!".to_string(),
         link: None,
+        content_hash: None,
         messages: msgs,
+        proposal_type: None,
+        expedited: None,
     };
 
     app.execute_contract(
@@ -4,11 +4,12 @@ use astroport::common::{claim_ownership, drop_ownership_proposal, propose_new_ow
 use cosmwasm_std::entry_point;
 
 use cosmwasm_std::{
-    from_binary, to_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError,
-    StdResult, Uint128, WasmMsg,
+    from_binary, to_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Order, Response,
+    StdError, StdResult, Storage, Uint128, WasmMsg,
 };
 use cw2::{get_contract_version, set_contract_version};
 use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+use cw_storage_plus::{Bound, U64Key};
 
 use crate::contract::helpers::compute_unlocked_amount;
 use crate::migration::{MigrateMsg, CONFIGV100, STATEV100, STATUSV100};
@@ -18,7 +19,7 @@ use astroport_governance::builder_unlock::msg::{
 };
 use astroport_governance::builder_unlock::{AllocationParams, AllocationStatus, Config, State};
 
-use crate::state::{CONFIG, OWNERSHIP_PROPOSAL, PARAMS, STATE, STATUS};
+use crate::state::{CONFIG, OWNERSHIP_PROPOSAL, PARAMS, STATE, STATE_HISTORY, STATUS};
 
 // Version and name used for contract migration.
 const CONTRACT_NAME: &str = "builder-unlock";
@@ -87,14 +88,14 @@ pub fn instantiate(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> StdResult<Response> {
     match msg {
-        ExecuteMsg::Receive(cw20_msg) => execute_receive_cw20(deps, info, cw20_msg),
+        ExecuteMsg::Receive(cw20_msg) => execute_receive_cw20(deps, env, info, cw20_msg),
         ExecuteMsg::Withdraw {} => execute_withdraw(deps, env, info),
         ExecuteMsg::ProposeNewReceiver { new_receiver } => {
-            execute_propose_new_receiver(deps, info, new_receiver)
+            execute_propose_new_receiver(deps, env, info, new_receiver)
         }
-        ExecuteMsg::DropNewReceiver {} => execute_drop_new_receiver(deps, info),
+        ExecuteMsg::DropNewReceiver {} => execute_drop_new_receiver(deps, env, info),
         ExecuteMsg::ClaimReceiver { prev_receiver } => {
-            execute_claim_receiver(deps, info, prev_receiver)
+            execute_claim_receiver(deps, env, info, prev_receiver)
         }
         ExecuteMsg::IncreaseAllocation { receiver, amount } => {
             let config = CONFIG.load(deps.storage)?;
@@ -103,13 +104,13 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> S
                     "Only the contract owner can increase allocations",
                 ));
             }
-            execute_increase_allocation(deps, &config, receiver, amount, None)
+            execute_increase_allocation(deps, env, &config, receiver, amount, None)
         }
         ExecuteMsg::DecreaseAllocation { receiver, amount } => {
             execute_decrease_allocation(deps, env, info, receiver, amount)
         }
         ExecuteMsg::TransferUnallocated { amount, recipient } => {
-            execute_transfer_unallocated(deps, info, amount, recipient)
+            execute_transfer_unallocated(deps, env, info, amount, recipient)
         }
         ExecuteMsg::ProposeNewOwner {
             new_owner,
@@ -155,17 +156,21 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> S
 /// ## Params
 /// * **deps** is an object of type [`DepsMut`].
 ///
+/// * **env** is an object of type [`Env`].
+///
 /// * **info** is an object of type [`MessageInfo`].
 ///
 /// * **cw20_msg** is an object of type [`Cw20ReceiveMsg`]. This is the CW20 message to process.
 fn execute_receive_cw20(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     cw20_msg: Cw20ReceiveMsg,
 ) -> StdResult<Response> {
     match from_binary(&cw20_msg.msg)? {
         ReceiveMsg::CreateAllocations { allocations } => execute_create_allocations(
             deps,
+            env,
             cw20_msg.sender,
             info.sender,
             cw20_msg.amount,
@@ -183,7 +188,7 @@ fn execute_receive_cw20(
                 ));
             }
 
-            execute_increase_allocation(deps, &config, user, amount, Some(cw20_msg.amount))
+            execute_increase_allocation(deps, env, &config, user, amount, Some(cw20_msg.amount))
         }
     }
 }
@@ -202,8 +207,12 @@ fn execute_receive_cw20(
 ///
 /// * **QueryMsg::State {}** Return the contract state (number of RCT that still need to be withdrawn).
 ///
+/// * **QueryMsg::StateAt { height }** Return the contract state at the specified block height.
+///
 /// * **QueryMsg::Allocation {}** Return the allocation details for a specific account.
 ///
+/// * **QueryMsg::AllocationAt { account, height }** Return the allocation details for a specific account at the specified block height.
+///
 /// * **QueryMsg::UnlockedTokens {}** Return the amoint of unlocked RCT for a specific account.
 ///
 /// * **QueryMsg::SimulateWithdraw {}** Return the result of a withdrawal simulation.
@@ -212,7 +221,11 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => to_binary(&query_config(deps)?),
         QueryMsg::State {} => to_binary(&query_state(deps)?),
+        QueryMsg::StateAt { height } => to_binary(&query_state_at(deps, height)?),
         QueryMsg::Allocation { account } => to_binary(&query_allocation(deps, account)?),
+        QueryMsg::AllocationAt { account, height } => {
+            to_binary(&query_allocation_at(deps, account, height)?)
+        }
         QueryMsg::UnlockedTokens { account } => {
             to_binary(&query_tokens_unlocked(deps, env, account)?)
         }
@@ -227,6 +240,8 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
 /// ## Params
 /// * **deps** is an object of type [`DepsMut`].
 ///
+/// * **env** is an object of type [`Env`].
+///
 /// * **creator** is an object of type [`String`]. This is the allocations creator (the contract admin).
 ///
 /// * **deposit_token** is an object of type [`Addr`]. This is the token being deposited (should be RCT).
@@ -236,6 +251,7 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
 /// * **deposit_amount** is a vector of tuples of type [(`String`, `AllocationParams`)]. New allocations being created.
 fn execute_create_allocations(
     deps: DepsMut,
+    env: Env,
     creator: String,
     deposit_token: Addr,
     deposit_amount: Uint128,
@@ -281,7 +297,7 @@ fn execute_create_allocations(
                 )));
             }
             Err(..) => {
-                PARAMS.save(deps.storage, &user, &params)?;
+                PARAMS.save(deps.storage, &user, &params, env.block.height)?;
             }
         }
 
@@ -293,12 +309,17 @@ fn execute_create_allocations(
                 )));
             }
             Err(..) => {
-                STATUS.save(deps.storage, &user, &AllocationStatus::new())?;
+                STATUS.save(
+                    deps.storage,
+                    &user,
+                    &AllocationStatus::new(),
+                    env.block.height,
+                )?;
             }
         }
     }
 
-    STATE.save(deps.storage, &state)?;
+    save_state(deps.storage, &state, env.block.height)?;
     Ok(Response::default())
 }
 
@@ -323,10 +344,10 @@ fn execute_withdraw(deps: DepsMut, env: Env, info: MessageInfo) -> StdResult<Res
     state.remaining_rct_tokens -= reactor_to_withdraw;
 
     // SAVE :: state & allocation
-    STATE.save(deps.storage, &state)?;
+    save_state(deps.storage, &state, env.block.height)?;
 
     // Update status
-    STATUS.save(deps.storage, &info.sender, &status)?;
+    STATUS.save(deps.storage, &info.sender, &status, env.block.height)?;
 
     let mut msgs: Vec<WasmMsg> = vec![];
 
@@ -353,11 +374,14 @@ fn execute_withdraw(deps: DepsMut, env: Env, info: MessageInfo) -> StdResult<Res
 /// ## Params
 /// * **deps** is an object of type [`DepsMut`].
 ///
+/// * **env** is an object of type [`Env`].
+///
 /// * **info** is an object of type [`MessageInfo`].
 ///
 /// * **new_receiver** is an object of type [`String`]. Newly proposed receiver for the allocation.
 fn execute_propose_new_receiver(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     new_receiver: String,
 ) -> StdResult<Response> {
@@ -382,7 +406,7 @@ fn execute_propose_new_receiver(
             }
 
             alloc_params.proposed_receiver = Some(deps.api.addr_validate(&new_receiver)?);
-            PARAMS.save(deps.storage, &info.sender, &alloc_params)?;
+            PARAMS.save(deps.storage, &info.sender, &alloc_params, env.block.height)?;
         }
     }
 
@@ -396,8 +420,10 @@ fn execute_propose_new_receiver(
 /// ## Params
 /// * **deps** is an object of type [`DepsMut`].
 ///
+/// * **env** is an object of type [`Env`].
+///
 /// * **info** is an object of type [`MessageInfo`].
-fn execute_drop_new_receiver(deps: DepsMut, info: MessageInfo) -> StdResult<Response> {
+fn execute_drop_new_receiver(deps: DepsMut, env: Env, info: MessageInfo) -> StdResult<Response> {
     let mut alloc_params = PARAMS.load(deps.storage, &info.sender)?;
     let prev_proposed_receiver: Addr;
 
@@ -405,7 +431,7 @@ fn execute_drop_new_receiver(deps: DepsMut, info: MessageInfo) -> StdResult<Resp
         Some(proposed_receiver) => {
             prev_proposed_receiver = proposed_receiver;
             alloc_params.proposed_receiver = None;
-            PARAMS.save(deps.storage, &info.sender, &alloc_params)?;
+            PARAMS.save(deps.storage, &info.sender, &alloc_params, env.block.height)?;
         }
         None => {
             return Err(StdError::generic_err("Proposed receiver not set"));
@@ -469,9 +495,9 @@ fn execute_decrease_allocation(
     state.unallocated_tokens = state.unallocated_tokens.checked_add(amount)?;
     state.remaining_rct_tokens = state.remaining_rct_tokens.checked_sub(amount)?;
 
-    STATUS.save(deps.storage, &receiver, &status)?;
-    PARAMS.save(deps.storage, &receiver, &params)?;
-    STATE.save(deps.storage, &state)?;
+    STATUS.save(deps.storage, &receiver, &status, env.block.height)?;
+    PARAMS.save(deps.storage, &receiver, &params, env.block.height)?;
+    save_state(deps.storage, &state, env.block.height)?;
 
     Ok(Response::new()
         .add_attribute("action", "execute_decrease_allocation")
@@ -484,6 +510,8 @@ fn execute_decrease_allocation(
 /// ## Params
 /// * **deps** is an object of type [`DepsMut`].
 ///
+/// * **env** is an object of type [`Env`].
+///
 /// * **config** is an object of type [`Config`].
 ///
 /// * **receiver** is an object of type [`String`]. Increasing receiver.
@@ -493,6 +521,7 @@ fn execute_decrease_allocation(
 /// * **deposit_amount** is an [`Option`] of type [`Uint128`]. Amount of RCT to increase using CW20 Receive.
 fn execute_increase_allocation(
     deps: DepsMut,
+    env: Env,
     config: &Config,
     receiver: String,
     amount: Uint128,
@@ -528,8 +557,8 @@ fn execute_increase_allocation(
             state.unallocated_tokens = state.unallocated_tokens.checked_sub(amount)?;
             state.remaining_rct_tokens = state.remaining_rct_tokens.checked_add(amount)?;
 
-            PARAMS.save(deps.storage, &receiver, &params)?;
-            STATE.save(deps.storage, &state)?;
+            PARAMS.save(deps.storage, &receiver, &params, env.block.height)?;
+            save_state(deps.storage, &state, env.block.height)?;
         }
         None => {
             return Err(StdError::generic_err("Proposed receiver not set"));
@@ -547,6 +576,8 @@ fn execute_increase_allocation(
 /// ## Params
 /// * **deps** is an object of type [`DepsMut`].
 ///
+/// * **env** is an object of type [`Env`].
+///
 /// * **info** is an object of type [`MessageInfo`].
 ///
 /// * **amount** is an object of type [`Uint128`]. Amount RCT to transfer.
@@ -554,6 +585,7 @@ fn execute_increase_allocation(
 /// * **recipient** is an [`Option`] of type [`u64`]. Transfer recipient.
 fn execute_transfer_unallocated(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     amount: Uint128,
     recipient: Option<String>,
@@ -590,7 +622,7 @@ fn execute_transfer_unallocated(
         funds: vec![],
     };
 
-    STATE.save(deps.storage, &state)?;
+    save_state(deps.storage, &state, env.block.height)?;
 
     Ok(Response::new()
         .add_attribute("action", "execute_transfer_unallocated")
@@ -603,11 +635,14 @@ fn execute_transfer_unallocated(
 /// ## Params
 /// * **deps** is an object of type [`DepsMut`].
 ///
+/// * **env** is an object of type [`Env`].
+///
 /// * **info** is an object of type [`MessageInfo`].
 ///
 /// * **prev_receiver** is an object of type [`String`]. This is the previous receiver for hte allocation.
 fn execute_claim_receiver(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     prev_receiver: String,
 ) -> StdResult<Response> {
@@ -628,9 +663,13 @@ fn execute_claim_receiver(
                 // 1. Save the allocation for the new receiver
                 alloc_params.proposed_receiver = None;
 
-                PARAMS.save(deps.storage, &info.sender, &alloc_params)?;
+                PARAMS.save(deps.storage, &info.sender, &alloc_params, env.block.height)?;
                 // 2. Remove the allocation info from the previous owner
-                PARAMS.remove(deps.storage, &deps.api.addr_validate(&prev_receiver)?);
+                PARAMS.remove(
+                    deps.storage,
+                    &deps.api.addr_validate(&prev_receiver)?,
+                    env.block.height,
+                )?;
                 // Transfers Allocation Status ::
                 let mut status =
                     STATUS.load(deps.storage, &deps.api.addr_validate(&prev_receiver)?)?;
@@ -641,8 +680,12 @@ fn execute_claim_receiver(
                         .checked_add(sender_status.rct_withdrawn)?;
                 }
 
-                STATUS.save(deps.storage, &info.sender, &status)?;
-                STATUS.remove(deps.storage, &deps.api.addr_validate(&prev_receiver)?)
+                STATUS.save(deps.storage, &info.sender, &status, env.block.height)?;
+                STATUS.remove(
+                    deps.storage,
+                    &deps.api.addr_validate(&prev_receiver)?,
+                    env.block.height,
+                )?;
             } else {
                 return Err(StdError::generic_err(format!(
                     "Proposed receiver mismatch, actual proposed receiver : {}",
@@ -711,6 +754,32 @@ pub fn query_state(deps: Deps) -> StdResult<StateResponse> {
     })
 }
 
+/// ## Description
+/// Return the global distribution state at the beginning of the specified block.
+/// ## Params
+/// * **deps** is an object of type [`Deps`].
+///
+/// * **height** is a parameter of type `u64`. This is the block height at which we read the state.
+pub fn query_state_at(deps: Deps, height: u64) -> StdResult<StateResponse> {
+    let state = STATE_HISTORY
+        .range(
+            deps.storage,
+            None,
+            Some(Bound::exclusive(U64Key::new(height))),
+            Order::Descending,
+        )
+        .next()
+        .transpose()?
+        .map(|(_, state)| state)
+        .unwrap_or_default();
+
+    Ok(StateResponse {
+        total_rct_deposited: state.total_rct_deposited,
+        remaining_rct_tokens: state.remaining_rct_tokens,
+        unallocated_rct_tokens: state.unallocated_tokens,
+    })
+}
+
 /// ## Description
 /// Return information about a specific allocation.
 /// ## Params
@@ -730,6 +799,27 @@ fn query_allocation(deps: Deps, account: String) -> StdResult<AllocationResponse
     })
 }
 
+/// ## Description
+/// Return information about a specific allocation at the beginning of the specified block.
+/// ## Params
+/// * **deps** is an object of type [`Deps`].
+///
+/// * **account** is an object of type [`String`]. This is the account whose allocation we query.
+///
+/// * **height** is a parameter of type `u64`. This is the block height at which we read the allocation.
+fn query_allocation_at(deps: Deps, account: String, height: u64) -> StdResult<AllocationResponse> {
+    let account_checked = deps.api.addr_validate(&account)?;
+
+    Ok(AllocationResponse {
+        params: PARAMS
+            .may_load_at_height(deps.storage, &account_checked, height)?
+            .unwrap_or_default(),
+        status: STATUS
+            .may_load_at_height(deps.storage, &account_checked, height)?
+            .unwrap_or_default(),
+    })
+}
+
 /// ## Description
 /// Return the total amount of unlocked tokens for a specific account.
 /// ## Params
@@ -785,16 +875,43 @@ fn query_simulate_withdraw(
     ))
 }
 
+/// ## Description
+/// Saves the global distribution state along with its snapshot at the specified block height.
+/// ## Params
+/// * **storage** is an object of type [`Storage`].
+///
+/// * **state** is an object of type [`State`]. This is the new state.
+///
+/// * **height** is a parameter of type `u64`. This is the current block height.
+fn save_state(storage: &mut dyn Storage, state: &State, height: u64) -> StdResult<()> {
+    STATE.save(storage, state)?;
+    STATE_HISTORY.save(storage, U64Key::new(height), state)
+}
+
+/// ## Description
+/// Seeds the state history of a contract upgraded from a version without it, so heights before the
+/// upgrade read the state at the moment of the upgrade. An existing history is left untouched.
+/// ## Params
+/// * **storage** is an object of type [`Storage`].
+fn seed_state_history(storage: &mut dyn Storage) -> StdResult<()> {
+    if STATE_HISTORY.may_load(storage, U64Key::new(0))?.is_none() {
+        let state = STATE.may_load(storage)?.unwrap_or_default();
+        STATE_HISTORY.save(storage, U64Key::new(0), &state)?;
+    }
+
+    Ok(())
+}
+
 /// ## Description
 /// Used for contract migration. Returns a default object of type [`Response`].
 /// ## Params
 /// * **deps** is an object of type [`DepsMut`].
 ///
-/// * **_env** is an object of type [`Env`].
+/// * **env** is an object of type [`Env`].
 ///
 /// * **msg** is an object of type [`Empty`].
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> StdResult<Response> {
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> StdResult<Response> {
     let contract_version = get_contract_version(deps.storage)?;
 
     match contract_version.contract.as_ref() {
@@ -821,7 +938,12 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> StdResult<Response>
                         rct_withdrawn: status_v100.rct_withdrawn,
                         unlocked_amount_checkpoint: Uint128::zero(),
                     };
-                    STATUS.save(deps.storage, &Addr::unchecked(key), &status)?;
+                    STATUS.save(
+                        deps.storage,
+                        &Addr::unchecked(key),
+                        &status,
+                        env.block.height,
+                    )?;
                 }
 
                 let config_v100 = CONFIGV100.load(deps.storage)?;
@@ -834,7 +956,10 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> StdResult<Response>
                         max_allocations_amount: msg.max_allocations_amount,
                     },
                 )?;
+
+                seed_state_history(deps.storage)?;
             }
+            "1.1.0" => seed_state_history(deps.storage)?,
             _ => return Err(StdError::generic_err("Contract can't be migrated!")),
         },
         _ => return Err(StdError::generic_err("Contract can't be migrated!")),
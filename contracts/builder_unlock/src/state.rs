@@ -1,6 +1,6 @@
 use astroport::common::OwnershipProposal;
 use cosmwasm_std::Addr;
-use cw_storage_plus::{Item, Map};
+use cw_storage_plus::{Item, Map, SnapshotMap, Strategy, U64Key};
 
 use astroport_governance::builder_unlock::{AllocationParams, AllocationStatus, Config, State};
 
@@ -8,9 +8,21 @@ use astroport_governance::builder_unlock::{AllocationParams, AllocationStatus, C
 pub const CONFIG: Item<Config> = Item::new("config");
 /// Stores global unlcok state such as the total amount of RCT tokens still to be distributed
 pub const STATE: Item<State> = Item::new("state");
+/// Snapshots of the global unlock state keyed by the block height at which they were taken
+pub const STATE_HISTORY: Map<U64Key, State> = Map::new("state_history");
 /// Allocation parameters for each unlock recipient
-pub const PARAMS: Map<&Addr, AllocationParams> = Map::new("params");
+pub const PARAMS: SnapshotMap<&Addr, AllocationParams> = SnapshotMap::new(
+    "params",
+    "params__checkpoints",
+    "params__changelog",
+    Strategy::EveryBlock,
+);
 /// The status of each unlock schedule
-pub const STATUS: Map<&Addr, AllocationStatus> = Map::new("status");
+pub const STATUS: SnapshotMap<&Addr, AllocationStatus> = SnapshotMap::new(
+    "status",
+    "status__checkpoints",
+    "status__changelog",
+    Strategy::EveryBlock,
+);
 /// Contains a proposal to change contract ownership
 pub const OWNERSHIP_PROPOSAL: Item<OwnershipProposal> = Item::new("ownership_proposal");
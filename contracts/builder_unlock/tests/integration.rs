@@ -1569,3 +1569,114 @@ fn test_increase_and_decrease_allocation() {
         }
     );
 }
+
+#[test]
+fn test_state_and_allocation_snapshots() {
+    let mut app = mock_app();
+    let (unlock_instance, astro_instance, _) = init_contracts(&mut app);
+
+    mint_some_astro(
+        &mut app,
+        Addr::unchecked(OWNER.clone()),
+        astro_instance.clone(),
+        Uint128::new(1_000_000_000_000000),
+        OWNER.to_string(),
+    );
+
+    let creation_height = app.block_info().height;
+
+    app.execute_contract(
+        Addr::unchecked(OWNER.clone()),
+        astro_instance.clone(),
+        &cw20::Cw20ExecuteMsg::Send {
+            contract: unlock_instance.clone().to_string(),
+            amount: Uint128::from(5_000_000_000000u64),
+            msg: to_binary(&ReceiveMsg::CreateAllocations {
+                allocations: vec![(
+                    "investor_1".to_string(),
+                    AllocationParams {
+                        amount: Uint128::from(5_000_000_000000u64),
+                        unlock_schedule: Schedule {
+                            start_time: 1642402274u64,
+                            cliff: 0u64,
+                            duration: 31536000u64,
+                        },
+                        proposed_receiver: None,
+                    },
+                )],
+            })
+            .unwrap(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.update_block(|b| {
+        b.height += 1;
+        b.time = Timestamp::from_seconds(1642402275)
+    });
+
+    let withdrawal_height = app.block_info().height;
+
+    app.execute_contract(
+        Addr::unchecked("investor_1"),
+        unlock_instance.clone(),
+        &ExecuteMsg::Withdraw {},
+        &[],
+    )
+    .unwrap();
+
+    let state_at = |app: &mut App, height: u64| -> StateResponse {
+        app.wrap()
+            .query_wasm_smart(&unlock_instance, &QueryMsg::StateAt { height })
+            .unwrap()
+    };
+    let allocation_at = |app: &mut App, height: u64| -> AllocationResponse {
+        app.wrap()
+            .query_wasm_smart(
+                &unlock_instance,
+                &QueryMsg::AllocationAt {
+                    account: "investor_1".to_string(),
+                    height,
+                },
+            )
+            .unwrap()
+    };
+
+    // Snapshots are taken at the beginning of a block, so changes show up from the next block
+    let state = state_at(&mut app, creation_height);
+    assert_eq!(state.total_rct_deposited, Uint128::zero());
+    assert_eq!(state.remaining_rct_tokens, Uint128::zero());
+    assert_eq!(
+        allocation_at(&mut app, creation_height).params.amount,
+        Uint128::zero()
+    );
+
+    let state = state_at(&mut app, withdrawal_height);
+    assert_eq!(
+        state.total_rct_deposited,
+        Uint128::from(5_000_000_000000u64)
+    );
+    assert_eq!(
+        state.remaining_rct_tokens,
+        Uint128::from(5_000_000_000000u64)
+    );
+    let allocation = allocation_at(&mut app, withdrawal_height);
+    assert_eq!(allocation.params.amount, Uint128::from(5_000_000_000000u64));
+    assert_eq!(allocation.status.rct_withdrawn, Uint128::zero());
+
+    let state = state_at(&mut app, withdrawal_height + 1);
+    assert_eq!(
+        state.remaining_rct_tokens,
+        Uint128::from(4_999_999_841452u64)
+    );
+    let allocation = allocation_at(&mut app, withdrawal_height + 1);
+    assert_eq!(allocation.status.rct_withdrawn, Uint128::from(158548u64));
+
+    // The live queries return the latest values
+    let state: StateResponse = app
+        .wrap()
+        .query_wasm_smart(&unlock_instance, &QueryMsg::State {})
+        .unwrap();
+    assert_eq!(state, state_at(&mut app, withdrawal_height + 1));
+}
@@ -299,7 +299,7 @@ fn check_if_user_exists_after_withdraw() {
         resp.end
     );
 
-    base_pack.withdraw(router_ref, user1.as_str()).unwrap();
+    base_pack.withdraw(router_ref, user1.as_str(), 1).unwrap();
 
     let resp: LockInfoResponse = router_ref
         .wrap()
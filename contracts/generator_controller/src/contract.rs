@@ -7,7 +7,7 @@ use astroport::common::{claim_ownership, drop_ownership_proposal, propose_new_ow
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
     to_binary, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Order, Response, StdError,
-    StdResult, WasmMsg,
+    StdResult, Uint128, WasmMsg,
 };
 use cw2::set_contract_version;
 use itertools::Itertools;
@@ -16,7 +16,7 @@ use astroport_governance::generator_controller::{
     ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, UserInfoResponse,
 };
 use astroport_governance::utils::{calc_voting_power, get_period, WEEK};
-use astroport_governance::voting_escrow::{get_lock_info, get_voting_power};
+use astroport_governance::voting_escrow::get_user_locks;
 
 use crate::bps::BasicPoints;
 use crate::error::ContractError;
@@ -145,6 +145,8 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> E
 /// * sum of all BPS values <= 10000.
 ///
 /// The function cancels changes applied by previous votes and apply new votes for the next period.
+/// Votes are applied with every lock position of the user separately, so the voting power of each
+/// lock stops counting for the voted pools at the lock's own end.
 /// New vote parameters are saved in [`USER_INFO`].  
 ///
 /// The function returns [`Response`] in case of success or [`ContractError`] in case of errors.
@@ -168,7 +170,19 @@ fn handle_vote(
     let user = info.sender;
     let block_period = get_period(env.block.time.seconds())?;
     let escrow_addr = CONFIG.load(deps.storage)?.escrow_addr;
-    let user_vp = get_voting_power(deps.querier, &escrow_addr, &user)?;
+    // Every lock position decays with its own slope until its own end
+    let user_locks = get_user_locks(deps.querier, &escrow_addr, &user)?
+        .into_iter()
+        .filter(|user_lock| user_lock.lock.end > block_period && !user_lock.lock.slope.is_zero())
+        .map(|user_lock| {
+            let lock = user_lock.lock;
+            let lock_vp = lock.slope * Uint128::from(lock.end - block_period);
+            (lock_vp, lock.slope, lock.end)
+        })
+        .collect::<Vec<_>>();
+    let user_vp = user_locks
+        .iter()
+        .fold(Uint128::zero(), |acc, (lock_vp, ..)| acc + lock_vp);
 
     if user_vp.is_zero() {
         return Err(ContractError::ZeroVotingPower {});
@@ -210,51 +224,64 @@ fn handle_vote(
             acc.checked_add(*bps)
         })?;
 
-    if user_info.lock_end > block_period {
-        let user_last_vote_period = get_period(user_info.vote_ts).unwrap_or(block_period);
-        // Calculate voting power before changes
-        let old_vp_at_period = calc_voting_power(
-            user_info.slope,
-            user_info.voting_power,
-            user_last_vote_period,
-            block_period,
-        );
-
-        // Cancel changes applied by previous votes
-        user_info.votes.iter().try_for_each(|(pool_addr, bps)| {
-            cancel_user_changes(
-                deps.storage,
-                block_period + 1,
-                pool_addr,
-                *bps,
-                old_vp_at_period,
-                user_info.slope,
-                user_info.lock_end,
-            )
+    let user_last_vote_period = get_period(user_info.vote_ts).unwrap_or(block_period);
+    let old_locks = if user_info.locks.is_empty() {
+        vec![(user_info.voting_power, user_info.slope, user_info.lock_end)]
+    } else {
+        user_info.locks.clone()
+    };
+    // Cancel changes applied by previous votes for the locks that didn't expire yet
+    old_locks
+        .iter()
+        .filter(|(_, _, lock_end)| *lock_end > block_period)
+        .try_for_each(|(lock_vp, lock_slope, lock_end)| {
+            // Calculate voting power before changes
+            let old_vp_at_period =
+                calc_voting_power(*lock_slope, *lock_vp, user_last_vote_period, block_period);
+
+            user_info.votes.iter().try_for_each(|(pool_addr, bps)| {
+                cancel_user_changes(
+                    deps.storage,
+                    block_period + 1,
+                    pool_addr,
+                    *bps,
+                    old_vp_at_period,
+                    *lock_slope,
+                    *lock_end,
+                )
+            })
         })?;
-    }
-
-    let ve_lock_info = get_lock_info(deps.querier, &escrow_addr, &user)?;
 
     // Votes are applied to the next period
-    votes.iter().try_for_each(|(pool_addr, bps)| {
-        vote_for_pool(
-            deps.storage,
-            block_period + 1,
-            pool_addr,
-            *bps,
-            user_vp,
-            ve_lock_info.slope,
-            ve_lock_info.end,
-        )
-    })?;
+    user_locks
+        .iter()
+        .try_for_each(|(lock_vp, lock_slope, lock_end)| {
+            votes.iter().try_for_each(|(pool_addr, bps)| {
+                vote_for_pool(
+                    deps.storage,
+                    block_period + 1,
+                    pool_addr,
+                    *bps,
+                    *lock_vp,
+                    *lock_slope,
+                    *lock_end,
+                )
+            })
+        })?;
 
     let user_info = UserInfo {
         vote_ts: env.block.time.seconds(),
         voting_power: user_vp,
-        slope: ve_lock_info.slope,
-        lock_end: ve_lock_info.end,
+        slope: user_locks
+            .iter()
+            .fold(Uint128::zero(), |acc, (_, lock_slope, _)| acc + lock_slope),
+        lock_end: user_locks
+            .iter()
+            .map(|(_, _, lock_end)| *lock_end)
+            .max()
+            .unwrap_or_default(),
         votes,
+        locks: user_locks,
     };
 
     USER_INFO.save(deps.storage, &user, &user_info)?;
@@ -24,6 +24,10 @@ pub struct UserInfo {
     pub slope: Uint128,
     pub lock_end: u64,
     pub votes: Vec<(Addr, BasicPoints)>,
+    /// Voting power, slope and end of every lock position that the votes were applied with.
+    /// It is empty for votes that were applied with a single aggregated lock
+    #[serde(default)]
+    pub locks: Vec<(Uint128, Uint128, u64)>,
 }
 
 impl UserInfo {
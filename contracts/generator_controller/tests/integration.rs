@@ -133,6 +133,85 @@ fn check_vote_works() {
         .unwrap();
 }
 
+#[test]
+fn check_vote_lock_transfer() {
+    let mut router = mock_app();
+    let owner = Addr::unchecked("owner");
+    let helper = ControllerHelper::init(&mut router, &owner);
+    let pool = helper
+        .create_pool_with_tokens(&mut router, "FOO", "BAR")
+        .unwrap();
+
+    helper.escrow_helper.mint_xastro(&mut router, "user1", 100);
+    helper
+        .escrow_helper
+        .create_lock(&mut router, "user1", 10 * WEEK, 100f32)
+        .unwrap();
+    helper
+        .vote(&mut router, "user1", vec![(pool.as_str(), 10000)])
+        .unwrap();
+
+    // The lock can't be transferred while its voting power is used in the generator controller
+    let err = helper
+        .escrow_helper
+        .transfer_lock(&mut router, "user1", 1, "user2")
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "A lock position can't be transferred while its owner has active votes in the generator controller"
+    );
+
+    // The transfer can't be accepted if the previous owner voted after proposing it
+    helper.escrow_helper.mint_xastro(&mut router, "user3", 100);
+    helper
+        .escrow_helper
+        .create_lock(&mut router, "user3", 10 * WEEK, 100f32)
+        .unwrap();
+    helper
+        .escrow_helper
+        .transfer_lock(&mut router, "user3", 1, "user2")
+        .unwrap();
+    helper
+        .vote(&mut router, "user3", vec![(pool.as_str(), 10000)])
+        .unwrap();
+    let err = helper
+        .escrow_helper
+        .accept_lock_transfer(&mut router, "user2", "user3", 1)
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "A lock position can't be transferred while its owner has active votes in the generator controller"
+    );
+
+    // The lock can be transferred after its owner removed their votes
+    router.next_block(86400 * 10);
+    helper
+        .vote(&mut router, "user1", Vec::<(String, u16)>::new())
+        .unwrap();
+    helper
+        .vote(&mut router, "user3", Vec::<(String, u16)>::new())
+        .unwrap();
+    helper
+        .escrow_helper
+        .transfer_lock(&mut router, "user1", 1, "user2")
+        .unwrap();
+    helper
+        .escrow_helper
+        .accept_lock_transfer(&mut router, "user2", "user1", 1)
+        .unwrap();
+    helper
+        .vote(&mut router, "user2", vec![(pool.as_str(), 10000)])
+        .unwrap();
+
+    // The lock's voting power is counted only once
+    let user_info = helper.query_user_info(&mut router, "user2").unwrap();
+    let pool_info = helper
+        .query_voted_pool_info_at_period(&mut router, pool.as_str(), router.block_period() + 1)
+        .unwrap();
+    assert_eq!(pool_info.vxastro_amount, user_info.voting_power);
+    assert_eq!(pool_info.slope, user_info.slope);
+}
+
 #[test]
 fn check_tuning() {
     let mut router = mock_app();
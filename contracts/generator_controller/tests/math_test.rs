@@ -34,7 +34,7 @@ enum VeEvent {
 
 struct Simulator {
     user_votes: HashMap<String, HashMap<String, u16>>,
-    locks: HashMap<String, (Uint128, u64, f32)>,
+    locks: HashMap<String, Vec<(Uint128, u64, Uint128)>>,
     helper: ControllerHelper,
     router: TerraApp,
     owner: Addr,
@@ -78,17 +78,23 @@ impl Simulator {
             IncreaseTime(interval) => {
                 self.helper
                     .escrow_helper
-                    .extend_lock_time(&mut self.router, user, interval)
+                    .extend_lock_time(&mut self.router, user, 1, interval)
             }
             ExtendLock(amount) => {
                 self.helper
                     .escrow_helper
                     .mint_xastro(&mut self.router, user, amount as u64);
-                self.helper
-                    .escrow_helper
-                    .extend_lock_amount(&mut self.router, user, amount as f32)
+                self.helper.escrow_helper.extend_lock_amount(
+                    &mut self.router,
+                    user,
+                    1,
+                    amount as f32,
+                )
             }
-            Withdraw => self.helper.escrow_helper.withdraw(&mut self.router, user),
+            Withdraw => self
+                .helper
+                .escrow_helper
+                .withdraw(&mut self.router, user, 1),
         };
     }
 
@@ -107,20 +113,22 @@ impl Simulator {
         self.helper
             .vote(&mut self.router, user, votes.clone())
             .map(|response| {
-                let lock_info = self
+                let block_period = self.router.block_period();
+                // Every lock position decays with its own slope until its own end
+                let locks = self
                     .helper
                     .escrow_helper
-                    .query_lock_info(&mut self.router, user)
-                    .unwrap();
-                let vp = self
-                    .helper
-                    .escrow_helper
-                    .query_user_vp(&mut self.router, user)
-                    .unwrap();
-                self.locks.insert(
-                    user.to_string(),
-                    (lock_info.slope, self.router.block_period(), vp),
-                );
+                    .query_user_locks(&mut self.router, user)
+                    .unwrap()
+                    .into_iter()
+                    .filter(|user_lock| user_lock.lock.end > block_period)
+                    .map(|user_lock| {
+                        let vp =
+                            user_lock.lock.slope * Uint128::from(user_lock.lock.end - block_period);
+                        (user_lock.lock.slope, block_period, vp)
+                    })
+                    .collect();
+                self.locks.insert(user.to_string(), locks);
                 self.user_votes.insert(user.to_string(), HashMap::new());
                 for (pool, bps) in votes {
                     self.user_votes
@@ -238,13 +246,13 @@ impl Simulator {
             // Checking calculations
             for user in self.user_votes.keys() {
                 let votes = self.user_votes.get(user).unwrap();
-                if let Some((slope, start, vp)) = self.locks.get(user) {
-                    let user_vp = calc_voting_power(
-                        *slope,
-                        Uint128::from((*vp * MULTIPLIER as f32) as u128),
-                        *start,
-                        period as u64,
-                    );
+                if let Some(locks) = self.locks.get(user) {
+                    let user_vp = locks
+                        .iter()
+                        .map(|(slope, start, vp)| {
+                            calc_voting_power(*slope, *vp, *start, period as u64)
+                        })
+                        .fold(Uint128::zero(), |acc, vp| acc + vp);
                     let user_vp = user_vp.u128() as f32 / MULTIPLIER as f32;
                     votes.iter().for_each(|(pool, &bps)| {
                         let vp = voted_pools.entry(pool.clone()).or_default();
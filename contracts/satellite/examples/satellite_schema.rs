@@ -0,0 +1,22 @@
+use std::env::current_dir;
+use std::fs::create_dir_all;
+
+use cosmwasm_schema::{export_schema_with_title, remove_schemas, schema_for};
+
+use astroport_governance::satellite::{
+    Config, ExecuteMsg, InstantiateMsg, QueryMsg, SatelliteAck, SatellitePacket,
+};
+
+fn main() {
+    let mut out_dir = current_dir().unwrap();
+    out_dir.push("schema");
+    create_dir_all(&out_dir).unwrap();
+    remove_schemas(&out_dir).unwrap();
+
+    export_schema_with_title(&schema_for!(InstantiateMsg), &out_dir, "InstantiateMsg");
+    export_schema_with_title(&schema_for!(ExecuteMsg), &out_dir, "ExecuteMsg");
+    export_schema_with_title(&schema_for!(QueryMsg), &out_dir, "QueryMsg");
+    export_schema_with_title(&schema_for!(Config), &out_dir, "Config");
+    export_schema_with_title(&schema_for!(SatellitePacket), &out_dir, "SatellitePacket");
+    export_schema_with_title(&schema_for!(SatelliteAck), &out_dir, "SatelliteAck");
+}
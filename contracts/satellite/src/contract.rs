@@ -0,0 +1,209 @@
+use cosmwasm_std::{
+    entry_point, to_binary, Binary, ContractResult, Deps, DepsMut, Env, MessageInfo, Reply,
+    Response, StdResult,
+};
+use cw2::{get_contract_version, set_contract_version};
+
+use astroport::asset::addr_validate_to_lower;
+use astroport_governance::satellite::{
+    Config, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, SatelliteAck,
+};
+
+use crate::error::ContractError;
+use crate::state::CONFIG;
+
+// Contract name and version used for migration.
+const CONTRACT_NAME: &str = "astro-satellite";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Reply id of the proposal messages received from the Assembly
+pub const RECEIVED_MESSAGE_REPLY_ID: u64 = 1;
+
+/// ## Description
+/// Creates a new contract with the specified parameters in the `msg` variable.
+/// Returns a [`Response`] with the specified attributes if the operation was successful,
+/// or a [`ContractError`] if the contract was not created.
+/// ## Params
+/// * **deps** is an object of type [`DepsMut`].
+///
+/// * **_env** is an object of type [`Env`]
+///
+/// * **_info** is an object of type [`MessageInfo`]
+///
+/// * **msg**  is a message of type [`InstantiateMsg`] which contains the parameters used for creating a contract.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let config = Config {
+        owner: addr_validate_to_lower(deps.api, &msg.owner)?,
+        controller_port: msg.controller_port,
+        connection_id: msg.connection_id,
+        controller_channel: msg.controller_channel,
+        governance_channel: None,
+        channel_closed: false,
+    };
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::default())
+}
+
+/// ## Description
+/// Exposes all the execute functions available in the contract.
+/// ## Params
+/// * **deps** is an object of type [`Deps`].
+///
+/// * **_env** is an object of type [`Env`].
+///
+/// * **info** is an object of type [`MessageInfo`].
+///
+/// * **msg** is an object of type [`ExecuteMsg`].
+///
+/// ## Queries
+/// * **ExecuteMsg::UpdateConfig { owner, controller_port, connection_id, controller_channel }** Updates the contract configuration.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::UpdateConfig {
+            owner,
+            controller_port,
+            connection_id,
+            controller_channel,
+        } => update_config(
+            deps,
+            info,
+            owner,
+            controller_port,
+            connection_id,
+            controller_channel,
+        ),
+    }
+}
+
+/// ## Description
+/// Updates Satellite contract parameters. Only the owner can execute this. A new governance channel
+/// is accepted again after the config is updated.
+/// Returns [`ContractError`] on failure, otherwise returns a [`Response`] with the specified
+/// attributes if the operation was successful.
+/// ## Params
+/// * **deps** is an object of type [`DepsMut`].
+///
+/// * **info** is an object of type [`MessageInfo`].
+///
+/// * **owner** is an [`Option`] of type [`String`]. This is the new owner address.
+///
+/// * **controller_port** is an [`Option`] of type [`String`]. This is the new IBC port of the Assembly.
+///
+/// * **connection_id** is an [`Option`] of type [`String`]. This is the new IBC connection to the main chain.
+///
+/// * **controller_channel** is an [`Option`] of type [`String`]. This is the new channel of the Assembly.
+pub fn update_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    owner: Option<String>,
+    controller_port: Option<String>,
+    connection_id: Option<String>,
+    controller_channel: Option<String>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if let Some(owner) = owner {
+        config.owner = addr_validate_to_lower(deps.api, &owner)?;
+    }
+
+    if let Some(controller_port) = controller_port {
+        config.controller_port = controller_port;
+    }
+
+    if let Some(connection_id) = connection_id {
+        config.connection_id = connection_id;
+    }
+
+    if let Some(controller_channel) = controller_channel {
+        config.controller_channel = Some(controller_channel);
+    }
+
+    config.channel_closed = false;
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attribute("action", "update_config"))
+}
+
+/// ## Description
+/// The entry point to the contract for processing replies from submessages. A failed proposal
+/// message overrides the acknowledgement sent back to the Assembly with its error.
+/// ## Params
+/// * **_deps** is an object of type [`DepsMut`].
+///
+/// * **_env** is an object of type [`Env`].
+///
+/// * **msg** is an object of type [`Reply`].
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(_deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.result {
+        ContractResult::Ok(_) => Ok(Response::default()),
+        ContractResult::Err(error) => Ok(Response::new()
+            .set_data(to_binary(&SatelliteAck::Error(error.clone()))?)
+            .add_attribute("action", "execute_proposal_message")
+            .add_attribute("error", error)),
+    }
+}
+
+/// ## Description
+/// Expose available contract queries.
+/// ## Params
+/// * **deps** is an object of type [`Deps`].
+///
+/// * **_env** is an object of type [`Env`].
+///
+/// * **msg** is an object of type [`QueryMsg`].
+///
+/// ## Queries
+/// * **QueryMsg::Config {}** Returns core contract settings stored in the [`Config`] structure.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_binary(&CONFIG.load(deps.storage)?),
+    }
+}
+
+/// ## Description
+/// Used for the contract migration. Returns a default object of type [`Response`].
+/// ## Params
+/// * **deps** is an object of type [`DepsMut`].
+///
+/// * **_env** is an object of type [`Env`].
+///
+/// * **_msg** is an object of type [`MigrateMsg`].
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let contract_version = get_contract_version(deps.storage)?;
+
+    if contract_version.contract != CONTRACT_NAME {
+        return Err(ContractError::MigrationError {});
+    }
+
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    Ok(Response::new()
+        .add_attribute("previous_contract_name", &contract_version.contract)
+        .add_attribute("previous_contract_version", &contract_version.version)
+        .add_attribute("new_contract_name", CONTRACT_NAME)
+        .add_attribute("new_contract_version", CONTRACT_VERSION))
+}
@@ -0,0 +1,40 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+/// ## Description
+/// This enum describes Satellite contract errors!
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Only unordered IBC channels are supported!")]
+    InvalidIbcOrder {},
+
+    #[error("Invalid IBC channel version!")]
+    InvalidIbcVersion {},
+
+    #[error("Only the Assembly port can open IBC channels: {0}")]
+    InvalidCounterpartyPort(String),
+
+    #[error("Only channels on the main chain connection can be opened: {0}")]
+    InvalidConnection(String),
+
+    #[error("Only the Assembly channel can be connected: {0}")]
+    InvalidCounterpartyChannel(String),
+
+    #[error("The governance channel was closed. The owner must update the config to accept a new channel!")]
+    GovernanceChannelClosed {},
+
+    #[error("The governance channel is already open: {0}")]
+    GovernanceChannelAlreadyOpen(String),
+
+    #[error("Packet received from an unknown channel: {0}")]
+    UnknownChannel(String),
+
+    #[error("Contract can't be migrated!")]
+    MigrationError {},
+}
@@ -0,0 +1,237 @@
+use cosmwasm_std::{
+    entry_point, from_binary, to_binary, Binary, DepsMut, Env, IbcBasicResponse, IbcChannel,
+    IbcChannelCloseMsg, IbcChannelConnectMsg, IbcChannelOpenMsg, IbcOrder, IbcPacketAckMsg,
+    IbcPacketReceiveMsg, IbcPacketTimeoutMsg, IbcReceiveResponse, SubMsg,
+};
+
+use astroport_governance::satellite::{Config, SatelliteAck, SatellitePacket, IBC_APP_VERSION};
+
+use crate::contract::RECEIVED_MESSAGE_REPLY_ID;
+use crate::error::ContractError;
+use crate::state::CONFIG;
+
+/// ## Description
+/// Validates a channel opened with the Assembly. Only one unordered channel with the
+/// [`IBC_APP_VERSION`] version can be opened from the Assembly port.
+/// ## Params
+/// * **deps** is an object of type [`DepsMut`].
+///
+/// * **_env** is an object of type [`Env`].
+///
+/// * **msg** is an object of type [`IbcChannelOpenMsg`].
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_channel_open(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelOpenMsg,
+) -> Result<(), ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    validate_channel(&config, msg.channel(), msg.counterparty_version())
+}
+
+/// ## Description
+/// Saves the governance channel once the handshake with the Assembly is completed.
+/// ## Params
+/// * **deps** is an object of type [`DepsMut`].
+///
+/// * **_env** is an object of type [`Env`].
+///
+/// * **msg** is an object of type [`IbcChannelConnectMsg`].
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_channel_connect(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelConnectMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    let channel = msg.channel();
+
+    validate_channel(&config, channel, msg.counterparty_version())?;
+
+    config.governance_channel = Some(channel.endpoint.channel_id.clone());
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(IbcBasicResponse::new()
+        .add_attribute("action", "ibc_channel_connect")
+        .add_attribute("channel_id", &channel.endpoint.channel_id))
+}
+
+/// ## Description
+/// Clears the governance channel when it is closed. A new channel can be opened only after the
+/// owner updates the config.
+/// ## Params
+/// * **deps** is an object of type [`DepsMut`].
+///
+/// * **_env** is an object of type [`Env`].
+///
+/// * **msg** is an object of type [`IbcChannelCloseMsg`].
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_channel_close(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelCloseMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    let channel = msg.channel();
+
+    if config.governance_channel.as_ref() == Some(&channel.endpoint.channel_id) {
+        config.governance_channel = None;
+        config.channel_closed = true;
+        CONFIG.save(deps.storage, &config)?;
+    }
+
+    Ok(IbcBasicResponse::new()
+        .add_attribute("action", "ibc_channel_close")
+        .add_attribute("channel_id", &channel.endpoint.channel_id))
+}
+
+/// ## Description
+/// Executes a proposal message received from the Assembly. The packet is acknowledged with
+/// [`SatelliteAck::Result`] if the message succeeds and with [`SatelliteAck::Error`] otherwise.
+/// ## Params
+/// * **deps** is an object of type [`DepsMut`].
+///
+/// * **_env** is an object of type [`Env`].
+///
+/// * **msg** is an object of type [`IbcPacketReceiveMsg`].
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_packet_receive(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcPacketReceiveMsg,
+) -> Result<IbcReceiveResponse, ContractError> {
+    match receive_proposal_message(deps, msg) {
+        Ok(response) => Ok(response),
+        Err(err) => Ok(IbcReceiveResponse::new()
+            .set_ack(to_binary(&SatelliteAck::Error(err.to_string()))?)
+            .add_attribute("action", "ibc_packet_receive")
+            .add_attribute("error", err.to_string())),
+    }
+}
+
+/// ## Description
+/// The Satellite never sends packets, so acknowledgements are ignored.
+/// ## Params
+/// * **_deps** is an object of type [`DepsMut`].
+///
+/// * **_env** is an object of type [`Env`].
+///
+/// * **_msg** is an object of type [`IbcPacketAckMsg`].
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_packet_ack(
+    _deps: DepsMut,
+    _env: Env,
+    _msg: IbcPacketAckMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    Ok(IbcBasicResponse::new().add_attribute("action", "ibc_packet_ack"))
+}
+
+/// ## Description
+/// The Satellite never sends packets, so timeouts are ignored.
+/// ## Params
+/// * **_deps** is an object of type [`DepsMut`].
+///
+/// * **_env** is an object of type [`Env`].
+///
+/// * **_msg** is an object of type [`IbcPacketTimeoutMsg`].
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_packet_timeout(
+    _deps: DepsMut,
+    _env: Env,
+    _msg: IbcPacketTimeoutMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    Ok(IbcBasicResponse::new().add_attribute("action", "ibc_packet_timeout"))
+}
+
+/// ## Description
+/// Checks that a packet was sent through the governance channel and dispatches its message.
+/// ## Params
+/// * **deps** is an object of type [`DepsMut`].
+///
+/// * **msg** is an object of type [`IbcPacketReceiveMsg`].
+fn receive_proposal_message(
+    deps: DepsMut,
+    msg: IbcPacketReceiveMsg,
+) -> Result<IbcReceiveResponse, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if config.governance_channel.as_ref() != Some(&msg.packet.dest.channel_id) {
+        return Err(ContractError::UnknownChannel(msg.packet.dest.channel_id));
+    }
+
+    let SatellitePacket::ExecuteProposalMessage {
+        proposal_id,
+        order,
+        msg,
+    } = from_binary(&msg.packet.data)?;
+
+    Ok(IbcReceiveResponse::new()
+        .set_ack(to_binary(&SatelliteAck::Result(Binary::default()))?)
+        .add_submessage(SubMsg::reply_on_error(msg, RECEIVED_MESSAGE_REPLY_ID))
+        .add_attribute("action", "ibc_packet_receive")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("order", order.to_string()))
+}
+
+/// ## Description
+/// Checks that a channel is unordered, uses the [`IBC_APP_VERSION`] version and is opened from
+/// the Assembly port and channel on the main chain connection while no other governance channel
+/// is open.
+/// ## Params
+/// * **config** is an object of type [`Config`].
+///
+/// * **channel** is an object of type [`IbcChannel`].
+///
+/// * **counterparty_version** is an [`Option`] of type [`str`]. This is the version proposed by the Assembly.
+fn validate_channel(
+    config: &Config,
+    channel: &IbcChannel,
+    counterparty_version: Option<&str>,
+) -> Result<(), ContractError> {
+    if channel.order != IbcOrder::Unordered {
+        return Err(ContractError::InvalidIbcOrder {});
+    }
+
+    if channel.version != IBC_APP_VERSION
+        || matches!(counterparty_version, Some(version) if version != IBC_APP_VERSION)
+    {
+        return Err(ContractError::InvalidIbcVersion {});
+    }
+
+    if channel.connection_id != config.connection_id {
+        return Err(ContractError::InvalidConnection(
+            channel.connection_id.clone(),
+        ));
+    }
+
+    if channel.counterparty_endpoint.port_id != config.controller_port {
+        return Err(ContractError::InvalidCounterpartyPort(
+            channel.counterparty_endpoint.port_id.clone(),
+        ));
+    }
+
+    // The counterparty channel is not known yet when the handshake is initiated by the Satellite
+    if let Some(controller_channel) = &config.controller_channel {
+        let counterparty_channel = &channel.counterparty_endpoint.channel_id;
+        if !counterparty_channel.is_empty() && counterparty_channel != controller_channel {
+            return Err(ContractError::InvalidCounterpartyChannel(
+                counterparty_channel.clone(),
+            ));
+        }
+    }
+
+    if config.channel_closed {
+        return Err(ContractError::GovernanceChannelClosed {});
+    }
+
+    if let Some(governance_channel) = &config.governance_channel {
+        if *governance_channel != channel.endpoint.channel_id {
+            return Err(ContractError::GovernanceChannelAlreadyOpen(
+                governance_channel.clone(),
+            ));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,7 @@
+pub mod contract;
+pub mod error;
+pub mod ibc;
+pub mod state;
+
+#[cfg(test)]
+mod testing;
@@ -0,0 +1,6 @@
+use astroport_governance::satellite::Config;
+use cw_storage_plus::Item;
+
+/// ## Description
+/// Stores the config for the Satellite contract
+pub const CONFIG: Item<Config> = Item::new("config");
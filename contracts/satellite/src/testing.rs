@@ -0,0 +1,289 @@
+use crate::contract::{execute, instantiate, query, reply, RECEIVED_MESSAGE_REPLY_ID};
+use crate::ibc::{ibc_channel_close, ibc_channel_connect, ibc_channel_open, ibc_packet_receive};
+use astroport_governance::satellite::{
+    Config, ExecuteMsg, InstantiateMsg, QueryMsg, SatelliteAck, SatellitePacket, IBC_APP_VERSION,
+};
+
+use cosmwasm_std::testing::{
+    mock_dependencies, mock_env, mock_ibc_channel_close_init, mock_ibc_channel_connect_ack,
+    mock_ibc_channel_open_try, mock_ibc_packet_recv, mock_info, MockApi, MockQuerier, MockStorage,
+};
+use cosmwasm_std::{
+    from_binary, Addr, BankMsg, Binary, ContractResult, CosmosMsg, IbcOrder, OwnedDeps, Reply,
+    SubMsg, Uint64,
+};
+
+fn setup() -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+    let mut deps = mock_dependencies(&[]);
+
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        InstantiateMsg {
+            owner: "owner".to_string(),
+            controller_port: "their_port".to_string(),
+            connection_id: "connection-2".to_string(),
+            controller_channel: None,
+        },
+    )
+    .unwrap();
+
+    deps
+}
+
+fn query_config(deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>) -> Config {
+    from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap()).unwrap()
+}
+
+#[test]
+fn governance_channel_handshake() {
+    let mut deps = setup();
+
+    let err = ibc_channel_open(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_open_try("channel-1", IbcOrder::Ordered, IBC_APP_VERSION),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Only unordered IBC channels are supported!"
+    );
+
+    let err = ibc_channel_open(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_open_try("channel-1", IbcOrder::Unordered, "ics20-1"),
+    )
+    .unwrap_err();
+    assert_eq!(err.to_string(), "Invalid IBC channel version!");
+
+    ibc_channel_open(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_open_try("channel-1", IbcOrder::Unordered, IBC_APP_VERSION),
+    )
+    .unwrap();
+
+    ibc_channel_connect(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_connect_ack("channel-1", IbcOrder::Unordered, IBC_APP_VERSION),
+    )
+    .unwrap();
+    assert_eq!(
+        query_config(&deps).governance_channel,
+        Some("channel-1".to_string())
+    );
+
+    // Only one governance channel can be open at a time
+    let err = ibc_channel_open(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_open_try("channel-2", IbcOrder::Unordered, IBC_APP_VERSION),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "The governance channel is already open: channel-1"
+    );
+
+    ibc_channel_close(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_close_init("channel-1", IbcOrder::Unordered, IBC_APP_VERSION),
+    )
+    .unwrap();
+    let config = query_config(&deps);
+    assert_eq!(config.governance_channel, None);
+    assert!(config.channel_closed);
+
+    // A new channel is accepted only after the owner updates the config
+    let err = ibc_channel_open(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_open_try("channel-2", IbcOrder::Unordered, IBC_APP_VERSION),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "The governance channel was closed. The owner must update the config to accept a new channel!"
+    );
+
+    let update_config = ExecuteMsg::UpdateConfig {
+        owner: None,
+        controller_port: None,
+        connection_id: None,
+        controller_channel: Some("channel-8".to_string()),
+    };
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        update_config.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err.to_string(), "Unauthorized");
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("owner", &[]),
+        update_config,
+    )
+    .unwrap();
+    let config = query_config(&deps);
+    assert_eq!(config.controller_channel, Some("channel-8".to_string()));
+    assert!(!config.channel_closed);
+
+    // Only the pinned Assembly channel can be connected
+    let err = ibc_channel_open(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_open_try("channel-2", IbcOrder::Unordered, IBC_APP_VERSION),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Only the Assembly channel can be connected: channel-7"
+    );
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("owner", &[]),
+        ExecuteMsg::UpdateConfig {
+            owner: None,
+            controller_port: None,
+            connection_id: None,
+            controller_channel: Some("channel-7".to_string()),
+        },
+    )
+    .unwrap();
+
+    ibc_channel_open(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_open_try("channel-2", IbcOrder::Unordered, IBC_APP_VERSION),
+    )
+    .unwrap();
+
+    // Channels can only be opened from the Assembly port on the main chain connection
+    let mut deps = mock_dependencies(&[]);
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        InstantiateMsg {
+            owner: "owner".to_string(),
+            controller_port: "wasm.assembly".to_string(),
+            connection_id: "connection-0".to_string(),
+            controller_channel: None,
+        },
+    )
+    .unwrap();
+
+    let err = ibc_channel_open(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_open_try("channel-1", IbcOrder::Unordered, IBC_APP_VERSION),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Only channels on the main chain connection can be opened: connection-2"
+    );
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("owner", &[]),
+        ExecuteMsg::UpdateConfig {
+            owner: None,
+            controller_port: None,
+            connection_id: Some("connection-2".to_string()),
+            controller_channel: None,
+        },
+    )
+    .unwrap();
+
+    let err = ibc_channel_open(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_open_try("channel-1", IbcOrder::Unordered, IBC_APP_VERSION),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Only the Assembly port can open IBC channels: their_port"
+    );
+}
+
+#[test]
+fn execute_proposal_message() {
+    let mut deps = setup();
+
+    let msg = CosmosMsg::Bank(BankMsg::Send {
+        to_address: "recipient".to_string(),
+        amount: vec![],
+    });
+    let packet = SatellitePacket::ExecuteProposalMessage {
+        proposal_id: 1,
+        order: Uint64::from(1u32),
+        msg: msg.clone(),
+    };
+
+    // Packets are rejected until the governance channel is open
+    let res = ibc_packet_receive(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_packet_recv("channel-1", &packet).unwrap(),
+    )
+    .unwrap();
+    assert!(res.messages.is_empty());
+    assert_eq!(
+        from_binary::<SatelliteAck>(&res.acknowledgement).unwrap(),
+        SatelliteAck::Error("Packet received from an unknown channel: channel-1".to_string())
+    );
+
+    ibc_channel_connect(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_connect_ack("channel-1", IbcOrder::Unordered, IBC_APP_VERSION),
+    )
+    .unwrap();
+
+    let res = ibc_packet_receive(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_packet_recv("channel-1", &packet).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::reply_on_error(msg, RECEIVED_MESSAGE_REPLY_ID)]
+    );
+    assert_eq!(
+        from_binary::<SatelliteAck>(&res.acknowledgement).unwrap(),
+        SatelliteAck::Result(Binary::default())
+    );
+
+    // A failed message overrides the acknowledgement with its error
+    let res = reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: RECEIVED_MESSAGE_REPLY_ID,
+            result: ContractResult::Err("Insufficient funds".to_string()),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        from_binary::<SatelliteAck>(&res.data.unwrap()).unwrap(),
+        SatelliteAck::Error("Insufficient funds".to_string())
+    );
+
+    assert_eq!(query_config(&deps).owner, Addr::unchecked("owner"));
+}
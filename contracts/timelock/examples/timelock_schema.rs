@@ -0,0 +1,26 @@
+use std::env::current_dir;
+use std::fs::create_dir_all;
+
+use cosmwasm_schema::{export_schema_with_title, remove_schemas, schema_for};
+
+use astroport_governance::timelock::{
+    Config, ExecuteMsg, InstantiateMsg, QueryMsg, Transaction, TransactionListResponse,
+};
+
+fn main() {
+    let mut out_dir = current_dir().unwrap();
+    out_dir.push("schema");
+    create_dir_all(&out_dir).unwrap();
+    remove_schemas(&out_dir).unwrap();
+
+    export_schema_with_title(&schema_for!(InstantiateMsg), &out_dir, "InstantiateMsg");
+    export_schema_with_title(&schema_for!(ExecuteMsg), &out_dir, "ExecuteMsg");
+    export_schema_with_title(&schema_for!(QueryMsg), &out_dir, "QueryMsg");
+    export_schema_with_title(&schema_for!(Config), &out_dir, "Config");
+    export_schema_with_title(&schema_for!(Transaction), &out_dir, "Transaction");
+    export_schema_with_title(
+        &schema_for!(TransactionListResponse),
+        &out_dir,
+        "TransactionListResponse",
+    );
+}
@@ -0,0 +1,397 @@
+use cosmwasm_std::{
+    entry_point, to_binary, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Order, Response,
+    StdResult,
+};
+use cw2::{get_contract_version, set_contract_version};
+use cw_storage_plus::{Bound, U64Key};
+
+use astroport::asset::addr_validate_to_lower;
+use astroport_governance::timelock::{
+    Config, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, Transaction, TransactionListResponse,
+    TransactionStatus,
+};
+
+use crate::error::ContractError;
+use crate::state::{CONFIG, PROPOSAL_TRANSACTIONS, TRANSACTIONS, TRANSACTION_COUNT};
+
+// Contract name and version used for migration.
+const CONTRACT_NAME: &str = "astro-timelock";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+// Default pagination constants
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+
+/// ## Description
+/// Creates a new contract with the specified parameters in the `msg` variable.
+/// Returns a [`Response`] with the specified attributes if the operation was successful,
+/// or a [`ContractError`] if the contract was not created.
+/// ## Params
+/// * **deps** is an object of type [`DepsMut`].
+///
+/// * **_env** is an object of type [`Env`]
+///
+/// * **_info** is an object of type [`MessageInfo`]
+///
+/// * **msg**  is a message of type [`InstantiateMsg`] which contains the parameters used for creating a contract.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let mut config = Config {
+        admin: addr_validate_to_lower(deps.api, &msg.admin)?,
+        guardian: None,
+        delay: msg.delay,
+        grace_period: msg.grace_period,
+    };
+
+    if let Some(guardian) = msg.guardian {
+        config.guardian = Some(addr_validate_to_lower(deps.api, &guardian)?);
+    }
+
+    config.validate()?;
+
+    CONFIG.save(deps.storage, &config)?;
+
+    TRANSACTION_COUNT.save(deps.storage, &0)?;
+
+    Ok(Response::default())
+}
+
+/// ## Description
+/// Exposes all the execute functions available in the contract.
+/// ## Params
+/// * **deps** is an object of type [`Deps`].
+///
+/// * **env** is an object of type [`Env`].
+///
+/// * **info** is an object of type [`MessageInfo`].
+///
+/// * **msg** is an object of type [`ExecuteMsg`].
+///
+/// ## Queries
+/// * **ExecuteMsg::QueueTransaction { proposal_id, messages }** Queues the messages of a passed proposal.
+///
+/// * **ExecuteMsg::ExecuteTransaction { transaction_id }** Executes a queued transaction once its delay has passed and before its grace period ends.
+///
+/// * **ExecuteMsg::CancelTransaction { transaction_id }** Cancels a queued transaction.
+///
+/// * **ExecuteMsg::UpdateConfig { admin, guardian, delay, grace_period }** Updates the contract configuration.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::QueueTransaction {
+            proposal_id,
+            messages,
+        } => queue_transaction(deps, env, info, proposal_id, messages),
+        ExecuteMsg::ExecuteTransaction { transaction_id } => {
+            execute_transaction(deps, env, info, transaction_id)
+        }
+        ExecuteMsg::CancelTransaction { transaction_id } => {
+            cancel_transaction(deps, env, info, transaction_id)
+        }
+        ExecuteMsg::UpdateConfig {
+            admin,
+            guardian,
+            delay,
+            grace_period,
+        } => update_config(deps, env, info, admin, guardian, delay, grace_period),
+    }
+}
+
+/// ## Description
+/// Queues the messages of a passed proposal. The transaction can be executed once the configured
+/// delay has passed. Returns [`ContractError`] on failure, otherwise returns a [`Response`] with
+/// the specified attributes if the operation was successful.
+/// ## Params
+/// * **deps** is an object of type [`DepsMut`].
+///
+/// * **env** is an object of type [`Env`].
+///
+/// * **info** is an object of type [`MessageInfo`].
+///
+/// * **proposal_id** is a parameter of type `u64`. This is the proposal identifier.
+///
+/// * **messages** is a [`Vec`] of type [`CosmosMsg`]. These are the messages to execute.
+pub fn queue_transaction(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    messages: Vec<CosmosMsg>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if messages.is_empty() {
+        return Err(ContractError::EmptyTransaction {});
+    }
+
+    let transaction_id = TRANSACTION_COUNT.load(deps.storage)? + 1;
+    TRANSACTION_COUNT.save(deps.storage, &transaction_id)?;
+
+    let transaction = Transaction {
+        transaction_id,
+        proposal_id,
+        messages,
+        eta: env.block.time.seconds() + config.delay,
+        status: TransactionStatus::Queued,
+    };
+
+    TRANSACTIONS.save(deps.storage, U64Key::new(transaction_id), &transaction)?;
+    PROPOSAL_TRANSACTIONS.save(deps.storage, U64Key::new(proposal_id), &transaction_id)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "queue_transaction")
+        .add_attribute("transaction_id", transaction_id.to_string())
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("eta", transaction.eta.to_string()))
+}
+
+/// ## Description
+/// Executes a queued transaction once its delay has passed. Anyone can execute a transaction.
+/// A transaction can't be executed once the grace period after its ETA has ended.
+/// If any of the messages fails, the transaction stays queued and can be executed again.
+/// Returns [`ContractError`] on failure, otherwise returns a [`Response`] with the specified
+/// attributes if the operation was successful.
+/// ## Params
+/// * **deps** is an object of type [`DepsMut`].
+///
+/// * **env** is an object of type [`Env`].
+///
+/// * **_info** is an object of type [`MessageInfo`].
+///
+/// * **transaction_id** is a parameter of type `u64`. This is the transaction identifier.
+pub fn execute_transaction(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    transaction_id: u64,
+) -> Result<Response, ContractError> {
+    let mut transaction = TRANSACTIONS.load(deps.storage, U64Key::new(transaction_id))?;
+
+    if transaction.status != TransactionStatus::Queued {
+        return Err(ContractError::TransactionNotQueued {});
+    }
+
+    if env.block.time.seconds() < transaction.eta {
+        return Err(ContractError::TransactionDelayNotEnded {});
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+
+    if env.block.time.seconds() > transaction.eta + config.grace_period {
+        return Err(ContractError::TransactionExpired {});
+    }
+
+    transaction.status = TransactionStatus::Executed;
+
+    TRANSACTIONS.save(deps.storage, U64Key::new(transaction_id), &transaction)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "execute_transaction")
+        .add_attribute("transaction_id", transaction_id.to_string())
+        .add_messages(transaction.messages))
+}
+
+/// ## Description
+/// Cancels a queued transaction. Only the guardian or the admin can cancel transactions.
+/// Returns [`ContractError`] on failure, otherwise returns a [`Response`] with the specified
+/// attributes if the operation was successful.
+/// ## Params
+/// * **deps** is an object of type [`DepsMut`].
+///
+/// * **_env** is an object of type [`Env`].
+///
+/// * **info** is an object of type [`MessageInfo`].
+///
+/// * **transaction_id** is a parameter of type `u64`. This is the transaction identifier.
+pub fn cancel_transaction(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    transaction_id: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if config.guardian.as_ref() != Some(&info.sender) && info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut transaction = TRANSACTIONS.load(deps.storage, U64Key::new(transaction_id))?;
+
+    if transaction.status != TransactionStatus::Queued {
+        return Err(ContractError::TransactionNotQueued {});
+    }
+
+    transaction.status = TransactionStatus::Cancelled;
+
+    TRANSACTIONS.save(deps.storage, U64Key::new(transaction_id), &transaction)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "cancel_transaction")
+        .add_attribute("transaction_id", transaction_id.to_string()))
+}
+
+/// ## Description
+/// Updates Timelock contract parameters. Only the Timelock itself can update its parameters,
+/// which means the update has to go through a queued transaction.
+/// Returns [`ContractError`] on failure, otherwise returns a [`Response`] with the specified
+/// attributes if the operation was successful.
+/// ## Params
+/// * **deps** is an object of type [`DepsMut`].
+///
+/// * **env** is an object of type [`Env`].
+///
+/// * **info** is an object of type [`MessageInfo`].
+///
+/// * **admin** is an [`Option`] of type [`String`]. This is the new admin address.
+///
+/// * **guardian** is an [`Option`] of type [`String`]. This is the new guardian address.
+///
+/// * **delay** is an [`Option`] of type `u64`. This is the new delay in seconds.
+///
+/// * **grace_period** is an [`Option`] of type `u64`. This is the new grace period in seconds.
+pub fn update_config(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    admin: Option<String>,
+    guardian: Option<String>,
+    delay: Option<u64>,
+    grace_period: Option<u64>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    if info.sender != env.contract.address {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if let Some(admin) = admin {
+        config.admin = addr_validate_to_lower(deps.api, &admin)?;
+    }
+
+    if let Some(guardian) = guardian {
+        config.guardian = Some(addr_validate_to_lower(deps.api, &guardian)?);
+    }
+
+    if let Some(delay) = delay {
+        config.delay = delay;
+    }
+
+    if let Some(grace_period) = grace_period {
+        config.grace_period = grace_period;
+    }
+
+    config.validate()?;
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attribute("action", "update_config"))
+}
+
+/// ## Description
+/// Expose available contract queries.
+/// ## Params
+/// * **deps** is an object of type [`Deps`].
+///
+/// * **_env** is an object of type [`Env`].
+///
+/// * **msg** is an object of type [`QueryMsg`].
+///
+/// ## Queries
+/// * **QueryMsg::Config {}** Returns core contract settings stored in the [`Config`] structure.
+///
+/// * **QueryMsg::Transaction { transaction_id }** Returns a [`Transaction`] according to the specified `transaction_id`.
+///
+/// * **QueryMsg::ProposalTransaction { proposal_id }** Returns the latest [`Transaction`] queued for the specified `proposal_id`.
+///
+/// * **QueryMsg::Transactions { start_after, limit }** Returns a [`TransactionListResponse`] according to the specified input parameters.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::Transaction { transaction_id } => {
+            to_binary(&TRANSACTIONS.load(deps.storage, U64Key::new(transaction_id))?)
+        }
+        QueryMsg::ProposalTransaction { proposal_id } => {
+            let transaction_id =
+                PROPOSAL_TRANSACTIONS.load(deps.storage, U64Key::new(proposal_id))?;
+            to_binary(&TRANSACTIONS.load(deps.storage, U64Key::new(transaction_id))?)
+        }
+        QueryMsg::Transactions { start_after, limit } => {
+            to_binary(&query_transactions(deps, start_after, limit)?)
+        }
+    }
+}
+
+/// ## Description
+/// Returns the list of transactions stored in the contract.
+/// ## Params
+/// * **deps** is an object of type [`Deps`].
+///
+/// * **start_after** is an [`Option`] type. Specifies the transaction id after which to start reading.
+///
+/// * **limit** is a [`Option`] type. Specifies the number of items to read.
+pub fn query_transactions(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<TransactionListResponse> {
+    let transaction_count = TRANSACTION_COUNT.load(deps.storage)?;
+
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(|start| Bound::exclusive(U64Key::new(start)));
+
+    let transactions: StdResult<Vec<_>> = TRANSACTIONS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (_k, v) = item?;
+            Ok(v)
+        })
+        .collect();
+
+    Ok(TransactionListResponse {
+        transaction_count,
+        transactions: transactions?,
+    })
+}
+
+/// ## Description
+/// Used for the contract migration. Returns a default object of type [`Response`].
+/// ## Params
+/// * **deps** is an object of type [`DepsMut`].
+///
+/// * **_env** is an object of type [`Env`].
+///
+/// * **_msg** is an object of type [`MigrateMsg`].
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let contract_version = get_contract_version(deps.storage)?;
+
+    if contract_version.contract != CONTRACT_NAME {
+        return Err(ContractError::MigrationError {});
+    }
+
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    Ok(Response::new()
+        .add_attribute("previous_contract_name", &contract_version.contract)
+        .add_attribute("previous_contract_version", &contract_version.version)
+        .add_attribute("new_contract_name", CONTRACT_NAME)
+        .add_attribute("new_contract_version", CONTRACT_VERSION))
+}
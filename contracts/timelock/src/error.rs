@@ -0,0 +1,28 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+/// ## Description
+/// This enum describes Timelock contract errors!
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Transaction must contain at least one message!")]
+    EmptyTransaction {},
+
+    #[error("Transaction not queued!")]
+    TransactionNotQueued {},
+
+    #[error("Transaction delay not ended!")]
+    TransactionDelayNotEnded {},
+
+    #[error("Transaction expired!")]
+    TransactionExpired {},
+
+    #[error("Contract can't be migrated!")]
+    MigrationError {},
+}
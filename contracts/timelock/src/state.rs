@@ -0,0 +1,18 @@
+use astroport_governance::timelock::{Config, Transaction};
+use cw_storage_plus::{Item, Map, U64Key};
+
+/// ## Description
+/// Stores the config for the Timelock contract
+pub const CONFIG: Item<Config> = Item::new("config");
+
+/// ## Description
+/// Stores the amount of transactions ever queued in the Timelock contract
+pub const TRANSACTION_COUNT: Item<u64> = Item::new("transaction_count");
+
+/// ## Description
+/// This is a map that contains information about all queued transactions
+pub const TRANSACTIONS: Map<U64Key, Transaction> = Map::new("transactions");
+
+/// ## Description
+/// This is a map that contains the identifier of the latest transaction queued for each proposal
+pub const PROPOSAL_TRANSACTIONS: Map<U64Key, u64> = Map::new("proposal_transactions");
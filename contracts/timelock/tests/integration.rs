@@ -0,0 +1,271 @@
+use astroport_governance::timelock::{ExecuteMsg, InstantiateMsg, TransactionStatus};
+use astroport_tests::mock_app;
+use astroport_tests::timelock_helper::{TimelockHelper, TIMELOCK_DELAY, TIMELOCK_GRACE_PERIOD};
+use cosmwasm_std::{to_binary, Addr, CosmosMsg, WasmMsg};
+use terra_multi_test::{ContractWrapper, Executor, TerraApp};
+
+fn update_delay_msg(helper: &TimelockHelper, delay: u64) -> CosmosMsg {
+    CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: helper.timelock_instance.to_string(),
+        msg: to_binary(&ExecuteMsg::UpdateConfig {
+            admin: None,
+            guardian: None,
+            delay: Some(delay),
+            grace_period: None,
+        })
+        .unwrap(),
+        funds: vec![],
+    })
+}
+
+fn skip_time(router: &mut TerraApp, seconds: u64) {
+    router.update_block(|bi| {
+        bi.height += seconds / 5;
+        bi.time = bi.time.plus_seconds(seconds);
+    });
+}
+
+#[test]
+fn test_contract_instantiation() {
+    let mut router = mock_app();
+    let owner = Addr::unchecked("owner");
+
+    let timelock_contract = Box::new(ContractWrapper::new_with_empty(
+        astro_timelock::contract::execute,
+        astro_timelock::contract::instantiate,
+        astro_timelock::contract::query,
+    ));
+
+    let timelock_code_id = router.store_code(timelock_contract);
+
+    let err = router
+        .instantiate_contract(
+            timelock_code_id,
+            owner.clone(),
+            &InstantiateMsg {
+                admin: "assembly".to_string(),
+                guardian: None,
+                delay: 3600,
+                grace_period: TIMELOCK_GRACE_PERIOD,
+            },
+            &[],
+            String::from("Timelock"),
+            None,
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Generic error: The timelock delay cannot be less than 86400 seconds."
+    );
+
+    let err = router
+        .instantiate_contract(
+            timelock_code_id,
+            owner.clone(),
+            &InstantiateMsg {
+                admin: "assembly".to_string(),
+                guardian: None,
+                delay: TIMELOCK_DELAY,
+                grace_period: 3600,
+            },
+            &[],
+            String::from("Timelock"),
+            None,
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Generic error: The timelock grace period cannot be less than 86400 seconds."
+    );
+
+    let helper = TimelockHelper::init(
+        &mut router,
+        Addr::unchecked("assembly"),
+        Addr::unchecked("guardian"),
+    );
+
+    let config = helper.query_config(&mut router).unwrap();
+    assert_eq!(config.admin, Addr::unchecked("assembly"));
+    assert_eq!(config.guardian, Some(Addr::unchecked("guardian")));
+    assert_eq!(config.delay, TIMELOCK_DELAY);
+    assert_eq!(config.grace_period, TIMELOCK_GRACE_PERIOD);
+}
+
+#[test]
+fn test_queue_and_execute_transaction() {
+    let mut router = mock_app();
+    let helper = TimelockHelper::init(
+        &mut router,
+        Addr::unchecked("assembly"),
+        Addr::unchecked("guardian"),
+    );
+
+    let messages = vec![update_delay_msg(&helper, 3 * 86400)];
+
+    // Only the admin can queue transactions
+    let err = helper
+        .queue_transaction(&mut router, "user1", 1, messages.clone())
+        .unwrap_err();
+    assert_eq!(err.to_string(), "Unauthorized");
+
+    let err = helper
+        .queue_transaction(&mut router, "assembly", 1, vec![])
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Transaction must contain at least one message!"
+    );
+
+    helper
+        .queue_transaction(&mut router, "assembly", 1, messages.clone())
+        .unwrap();
+
+    let transaction = helper.query_transaction(&mut router, 1).unwrap();
+    assert_eq!(transaction.transaction_id, 1);
+    assert_eq!(transaction.proposal_id, 1);
+    assert_eq!(transaction.messages, messages);
+    assert_eq!(
+        transaction.eta,
+        router.block_info().time.seconds() + TIMELOCK_DELAY
+    );
+    assert_eq!(transaction.status, TransactionStatus::Queued);
+
+    // The transaction can't be executed before its ETA
+    let err = helper
+        .execute_transaction(&mut router, "user1", 1)
+        .unwrap_err();
+    assert_eq!(err.to_string(), "Transaction delay not ended!");
+
+    skip_time(&mut router, TIMELOCK_DELAY);
+
+    // Anyone can execute the transaction
+    helper.execute_transaction(&mut router, "user1", 1).unwrap();
+
+    let transaction = helper.query_transaction(&mut router, 1).unwrap();
+    assert_eq!(transaction.status, TransactionStatus::Executed);
+
+    let config = helper.query_config(&mut router).unwrap();
+    assert_eq!(config.delay, 3 * 86400);
+
+    let err = helper
+        .execute_transaction(&mut router, "user1", 1)
+        .unwrap_err();
+    assert_eq!(err.to_string(), "Transaction not queued!");
+
+    // The Timelock parameters can only be updated through a queued transaction
+    let err = router
+        .execute_contract(
+            Addr::unchecked("assembly"),
+            helper.timelock_instance.clone(),
+            &ExecuteMsg::UpdateConfig {
+                admin: None,
+                guardian: None,
+                delay: Some(86400),
+                grace_period: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(err.to_string(), "Unauthorized");
+}
+
+#[test]
+fn test_cancel_transaction() {
+    let mut router = mock_app();
+    let helper = TimelockHelper::init(
+        &mut router,
+        Addr::unchecked("assembly"),
+        Addr::unchecked("guardian"),
+    );
+
+    for proposal_id in 1..=3 {
+        helper
+            .queue_transaction(
+                &mut router,
+                "assembly",
+                proposal_id,
+                vec![update_delay_msg(&helper, 3 * 86400)],
+            )
+            .unwrap();
+    }
+
+    // Only the guardian and the admin can cancel transactions
+    let err = helper
+        .cancel_transaction(&mut router, "user1", 2)
+        .unwrap_err();
+    assert_eq!(err.to_string(), "Unauthorized");
+
+    helper
+        .cancel_transaction(&mut router, "guardian", 2)
+        .unwrap();
+    helper
+        .cancel_transaction(&mut router, "assembly", 1)
+        .unwrap();
+
+    let err = helper
+        .cancel_transaction(&mut router, "guardian", 2)
+        .unwrap_err();
+    assert_eq!(err.to_string(), "Transaction not queued!");
+
+    skip_time(&mut router, TIMELOCK_DELAY);
+
+    // A cancelled transaction can't be executed
+    let err = helper
+        .execute_transaction(&mut router, "user1", 2)
+        .unwrap_err();
+    assert_eq!(err.to_string(), "Transaction not queued!");
+
+    let transactions = helper
+        .query_transactions(&mut router, Some(1), None)
+        .unwrap();
+    assert_eq!(transactions.transaction_count, 3);
+    assert_eq!(
+        transactions
+            .transactions
+            .iter()
+            .map(|transaction| (transaction.transaction_id, transaction.status.clone()))
+            .collect::<Vec<_>>(),
+        vec![
+            (2, TransactionStatus::Cancelled),
+            (3, TransactionStatus::Queued)
+        ]
+    );
+
+    // The transactions can be looked up by the proposal they belong to
+    let transaction = helper.query_proposal_transaction(&mut router, 1).unwrap();
+    assert_eq!(transaction.transaction_id, 1);
+    assert_eq!(transaction.status, TransactionStatus::Cancelled);
+}
+
+#[test]
+fn test_expired_transaction() {
+    let mut router = mock_app();
+    let helper = TimelockHelper::init(
+        &mut router,
+        Addr::unchecked("assembly"),
+        Addr::unchecked("guardian"),
+    );
+
+    helper
+        .queue_transaction(
+            &mut router,
+            "assembly",
+            1,
+            vec![update_delay_msg(&helper, 3 * 86400)],
+        )
+        .unwrap();
+
+    skip_time(&mut router, TIMELOCK_DELAY + TIMELOCK_GRACE_PERIOD + 1);
+
+    // The transaction can't be executed once its grace period has ended
+    let err = helper
+        .execute_transaction(&mut router, "user1", 1)
+        .unwrap_err();
+    assert_eq!(err.to_string(), "Transaction expired!");
+
+    let transaction = helper.query_transaction(&mut router, 1).unwrap();
+    assert_eq!(transaction.status, TransactionStatus::Queued);
+
+    let config = helper.query_config(&mut router).unwrap();
+    assert_eq!(config.delay, TIMELOCK_DELAY);
+}
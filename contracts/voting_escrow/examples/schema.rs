@@ -2,7 +2,8 @@ use std::env::current_dir;
 use std::fs::create_dir_all;
 
 use astroport_governance::voting_escrow::{
-    ConfigResponse, ExecuteMsg, InstantiateMsg, LockInfoResponse, QueryMsg, VotingPowerResponse,
+    ConfigResponse, ExecuteMsg, InstantiateMsg, LockInfoResponse, QueryMsg, UserLockResponse,
+    VotingPowerResponse,
 };
 use cosmwasm_schema::{export_schema, remove_schemas, schema_for};
 
@@ -17,5 +18,6 @@ fn main() {
     export_schema(&schema_for!(QueryMsg), &out_dir);
     export_schema(&schema_for!(VotingPowerResponse), &out_dir);
     export_schema(&schema_for!(LockInfoResponse), &out_dir);
+    export_schema(&schema_for!(UserLockResponse), &out_dir);
     export_schema(&schema_for!(ConfigResponse), &out_dir);
 }
@@ -22,19 +22,23 @@ use astroport_governance::querier::query_token_balance;
 use astroport_governance::utils::{get_period, get_periods_count, EPOCH_START, WEEK};
 use astroport_governance::voting_escrow::{
     ConfigResponse, Cw20HookMsg, ExecuteMsg, InstantiateMsg, LockInfoResponse, MigrateMsg,
-    QueryMsg, VotingPowerResponse,
+    QueryMsg, UserLockResponse, VotingPowerResponse,
 };
 
 use crate::error::ContractError;
 use crate::migration::v110::MigrationV110;
+use crate::migration::v120::{migrate_user_lock, LOCKED_V110};
 use crate::migration::Migration;
 use crate::state::{
-    Config, Lock, Point, BLACKLIST, CONFIG, HISTORY, LAST_SLOPE_CHANGE, LOCKED, OWNERSHIP_PROPOSAL,
+    Config, Lock, Point, BLACKLIST, CONFIG, HISTORY, LAST_SLOPE_CHANGE, LOCKED, LOCK_TRANSFERS,
+    OWNERSHIP_PROPOSAL,
 };
 use crate::utils::{
     adjust_vp_and_slope, blacklist_check, calc_coefficient, calc_early_withdraw_amount,
-    calc_voting_power, cancel_scheduled_slope, fetch_last_checkpoint, fetch_slope_changes,
-    schedule_slope_change, time_limits_check, validate_addresses, xastro_token_check,
+    calc_lock_voting_power, calc_voting_power, cancel_scheduled_slope, cancel_user_scheduled_slope,
+    controller_votes_check, fetch_last_checkpoint, fetch_slope_changes, fetch_user_locks,
+    fetch_user_point, find_free_lock_id, schedule_slope_change, schedule_user_slope_change,
+    time_limits_check, validate_addresses, xastro_token_check,
 };
 
 /// Contract name that is used for migration.
@@ -90,6 +94,7 @@ pub fn instantiate(
         astro_addr: staking_config.deposit_token_addr,
         xastro_staking_addr: addr_validate_to_lower(deps.api, &xastro_minter_resp.minter)?,
         slashed_fund_receiver,
+        generator_controller_addr: None,
     };
     CONFIG.save(deps.storage, &config)?;
 
@@ -152,11 +157,23 @@ pub fn instantiate(
 /// Exposes all the execute functions available in the contract.
 ///
 /// ## Execute messages
-/// * **ExecuteMsg::ExtendLockTime { time }** Increase a staker's lock time.
+/// * **ExecuteMsg::ExtendLockTime { lock_id, time }** Increase the lock time of a staker's lock position.
 ///
 /// * **ExecuteMsg::Receive(msg)** Parse incoming messages coming from the xASTRO token contract.
 ///
-/// * **ExecuteMsg::Withdraw {}** Withdraw all xASTRO from a lock position if the lock has expired.
+/// * **ExecuteMsg::Withdraw { lock_id }** Withdraw all xASTRO from a lock position if the lock has expired.
+///
+/// * **ExecuteMsg::WithdrawEarly { lock_id }** Withdraw all xASTRO from a lock position before it expires with a penalty.
+///
+/// * **ExecuteMsg::TransferLock { lock_id, recipient }** Propose to transfer a lock position to another address.
+///
+/// * **ExecuteMsg::AcceptLockTransfer { sender, lock_id }** Accept a lock position transfer.
+///
+/// * **ExecuteMsg::CancelLockTransfer { lock_id }** Cancel a proposed lock position transfer.
+///
+/// * **ExecuteMsg::MergeLock { first_lock_id, second_lock_id }** Merge two lock positions into one.
+///
+/// * **ExecuteMsg::UpdateConfig { generator_controller }** Set the generator controller address.
 ///
 /// * **ExecuteMsg::ProposeNewOwner { owner, expires_in }** Creates a new request to change contract ownership.
 ///
@@ -165,16 +182,32 @@ pub fn instantiate(
 /// * **ExecuteMsg::ClaimOwnership {}** Claims contract ownership.
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
+    // The sender's lock position is migrated to multiple lock positions on their first interaction
+    migrate_user_lock(deps.branch(), &env, &info.sender)?;
+
     match msg {
-        ExecuteMsg::ExtendLockTime { time } => extend_lock_time(deps, env, info, time),
+        ExecuteMsg::ExtendLockTime { lock_id, time } => {
+            extend_lock_time(deps, env, info, lock_id, time)
+        }
         ExecuteMsg::Receive(msg) => receive_cw20(deps, env, info, msg),
-        ExecuteMsg::Withdraw {} => withdraw(deps, env, info),
-        ExecuteMsg::WithdrawEarly {} => withdraw_early(deps, env, info),
+        ExecuteMsg::Withdraw { lock_id } => withdraw(deps, env, info, lock_id),
+        ExecuteMsg::WithdrawEarly { lock_id } => withdraw_early(deps, env, info, lock_id),
+        ExecuteMsg::TransferLock { lock_id, recipient } => {
+            transfer_lock(deps, env, info, lock_id, recipient)
+        }
+        ExecuteMsg::AcceptLockTransfer { sender, lock_id } => {
+            accept_lock_transfer(deps, env, info, sender, lock_id)
+        }
+        ExecuteMsg::CancelLockTransfer { lock_id } => cancel_lock_transfer(deps, info, lock_id),
+        ExecuteMsg::MergeLock {
+            first_lock_id,
+            second_lock_id,
+        } => merge_lock(deps, env, info, first_lock_id, second_lock_id),
         ExecuteMsg::EarlyWithdrawCallback {
             precallback_astro,
             slashed_funds_receiver,
@@ -189,6 +222,9 @@ pub fn execute(
             max_penalty,
             slashed_fund_receiver,
         } => configure_early_withdrawal(deps, info, max_penalty, slashed_fund_receiver),
+        ExecuteMsg::UpdateConfig {
+            generator_controller,
+        } => update_config(deps, info, generator_controller),
         ExecuteMsg::ProposeNewOwner {
             new_owner,
             expires_in,
@@ -319,13 +355,14 @@ fn checkpoint_total(
 }
 
 /// ## Description
-/// Checkpoint a user's voting power (vxASTRO supply).
-/// This function fetches the user's last available checkpoint, calculates the user's current voting power,
+/// Checkpoint a user's lock position and voting power (vxASTRO supply).
+/// This function calculates the lock's current voting power,
 /// applies slope changes based on `add_amount` and `new_end` parameters,
-/// schedules slope changes for total voting power
+/// reschedules slope changes for the user's and the total voting power,
+/// adds the change in the lock's voting power to the user's last available checkpoint
 /// and saves the new checkpoint for the current period in [`HISTORY`] (using the user's address).
-/// If a user already checkpointed themselves for the current period, then
-/// this function uses the current checkpoint as the latest available one.
+/// The user's checkpoint aggregates the voting power and the slopes of all their lock positions.
+/// The lock's `amount` must already be updated by the caller.
 /// The function returns Ok(()) in case of success or [`StdError`]
 /// in case of a serialization/deserialization error.
 ///
@@ -337,90 +374,91 @@ fn checkpoint_total(
 ///
 /// * **addr** is an object of type [`Addr`]. This is the staker for which we checkpoint the voting power.
 ///
-/// * **add_amount** is an object of type [`Option<Uint128>`]. This is an amount of vxASTRO to add to the user's balance.
+/// * **lock_id** is a parameter of type [`u64`]. This is the identifier of the staker's lock position.
+///
+/// * **add_amount** is an object of type [`Option<Uint128>`]. This is an amount of xASTRO added to the lock position.
 ///
 /// * **new_end** is an object of type [`Option<u64>`]. This is a new lock time for the user's vxASTRO position.
 fn checkpoint(
     mut deps: DepsMut,
     env: Env,
     addr: Addr,
+    lock_id: u64,
     add_amount: Option<Uint128>,
     new_end: Option<u64>,
 ) -> StdResult<()> {
     let cur_period = get_period(env.block.time.seconds())?;
-    let cur_period_key = U64Key::new(cur_period);
     let add_amount = add_amount.unwrap_or_default();
-    let mut old_slope = Default::default();
-    let mut add_voting_power = Uint128::zero();
-
-    // Get last user checkpoint
-    let last_checkpoint = fetch_last_checkpoint(deps.as_ref(), &addr, &cur_period_key)?;
-    let new_point = if let Some((_, point)) = last_checkpoint {
-        let end = new_end.unwrap_or(point.end);
-        let dt = end.saturating_sub(cur_period);
-        let current_power = calc_voting_power(&point, cur_period);
-        let new_slope = if dt != 0 {
-            if end > point.end && add_amount.is_zero() {
-                // This is extend_lock_time. Recalculating user's voting power
-                let mut lock = LOCKED.load(deps.storage, addr.clone())?;
-                let mut new_voting_power = calc_coefficient(dt).checked_mul(lock.amount)?;
-                let slope = adjust_vp_and_slope(&mut new_voting_power, dt)?;
-                // new_voting_power should always be >= current_power. saturating_sub is used for extra safety
-                add_voting_power = new_voting_power.saturating_sub(current_power);
-                lock.last_extend_lock_period = cur_period;
-                LOCKED.save(deps.storage, addr.clone(), &lock, env.block.height)?;
-                slope
-            } else {
-                // This is an increase in the user's lock amount
-                let raw_add_voting_power = calc_coefficient(dt).checked_mul(add_amount)?;
-                let mut new_voting_power = current_power.checked_add(raw_add_voting_power)?;
-                let slope = adjust_vp_and_slope(&mut new_voting_power, dt)?;
-                // new_voting_power should always be >= current_power. saturating_sub is used for extra safety
-                add_voting_power = new_voting_power.saturating_sub(current_power);
-                slope
-            }
+    let lock_key = (addr.clone(), U64Key::new(lock_id));
+    let mut lock = LOCKED.load(deps.storage, lock_key.clone())?;
+
+    // The lock's contribution to the user's voting power. Expired locks don't contribute anymore
+    let current_power = calc_lock_voting_power(&lock, cur_period);
+    let old_slope = if lock.end > cur_period {
+        lock.slope
+    } else {
+        Uint128::zero()
+    };
+    let old_end = lock.end;
+
+    let end = new_end.unwrap_or(lock.end);
+    let dt = end.saturating_sub(cur_period);
+    let (new_power, new_slope) = if dt != 0 {
+        let mut new_voting_power = if add_amount.is_zero() {
+            // This is extend_lock_time or a lock restored after the user was removed from the blacklist.
+            // Recalculating the lock's voting power
+            calc_coefficient(dt).checked_mul(lock.amount)?
         } else {
-            Uint128::zero()
+            // This is an increase in the lock amount
+            let raw_add_voting_power = calc_coefficient(dt).checked_mul(add_amount)?;
+            current_power.checked_add(raw_add_voting_power)?
         };
+        // new_voting_power should always be >= current_power
+        let slope = adjust_vp_and_slope(&mut new_voting_power, dt)?;
+        (new_voting_power, slope)
+    } else {
+        (Uint128::zero(), Uint128::zero())
+    };
 
-        // Cancel the previously scheduled slope change
-        cancel_scheduled_slope(deps.branch(), point.slope, point.end)?;
+    if end > lock.end {
+        lock.last_extend_lock_period = cur_period;
+    }
+    lock.end = end;
+    lock.slope = new_slope;
+    LOCKED.save(deps.storage, lock_key, &lock, env.block.height)?;
 
-        // We need to subtract the slope point from the total voting power slope
-        old_slope = point.slope;
+    // Cancel the previously scheduled slope changes
+    cancel_scheduled_slope(deps.branch(), old_slope, old_end)?;
+    cancel_user_scheduled_slope(deps.branch(), &addr, old_slope, old_end)?;
 
+    // Schedule slope changes
+    schedule_slope_change(deps.branch(), new_slope, end)?;
+    schedule_user_slope_change(deps.branch(), &addr, new_slope, end)?;
+
+    let new_point = if let Some(point) = fetch_user_point(deps.as_ref(), &addr, cur_period)? {
         Point {
-            power: current_power + add_voting_power,
-            slope: new_slope,
+            power: point.power.saturating_sub(current_power) + new_power,
+            slope: point.slope - old_slope + new_slope,
             start: cur_period,
-            end,
+            end: point.end.max(end),
         }
     } else {
-        // This error can't happen since this if-branch is intended for checkpoint creation
-        let end =
-            new_end.ok_or_else(|| StdError::generic_err("Checkpoint initialization error"))?;
-        let dt = end - cur_period;
-        add_voting_power = calc_coefficient(dt).checked_mul(add_amount)?;
-        let slope = adjust_vp_and_slope(&mut add_voting_power, dt)?;
         Point {
-            power: add_voting_power,
-            slope,
+            power: new_power,
+            slope: new_slope,
             start: cur_period,
             end,
         }
     };
+    HISTORY.save(deps.storage, (addr, U64Key::new(cur_period)), &new_point)?;
 
-    // Schedule a slope change
-    schedule_slope_change(deps.branch(), new_point.slope, new_point.end)?;
-
-    HISTORY.save(deps.storage, (addr, cur_period_key), &new_point)?;
     checkpoint_total(
         deps,
         env,
-        Some(add_voting_power),
-        None,
+        Some(new_power.saturating_sub(current_power)),
+        Some(current_power.saturating_sub(new_power)),
         old_slope,
-        new_point.slope,
+        new_slope,
     )
 }
 
@@ -437,7 +475,7 @@ fn checkpoint(
 ///
 /// * **cw20_msg** is an object of type [`Cw20ReceiveMsg`]. This is the CW20 message to process.
 fn receive_cw20(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     cw20_msg: Cw20ReceiveMsg,
@@ -445,24 +483,29 @@ fn receive_cw20(
     xastro_token_check(deps.as_ref(), info.sender)?;
     let sender = addr_validate_to_lower(deps.api, &cw20_msg.sender)?;
     blacklist_check(deps.as_ref(), &sender)?;
+    migrate_user_lock(deps.branch(), &env, &sender)?;
 
     match from_binary(&cw20_msg.msg)? {
         Cw20HookMsg::CreateLock { time } => create_lock(deps, env, sender, cw20_msg.amount, time),
-        Cw20HookMsg::ExtendLockAmount {} => deposit_for(deps, env, cw20_msg.amount, sender),
-        Cw20HookMsg::DepositFor { user } => {
+        Cw20HookMsg::ExtendLockAmount { lock_id } => {
+            deposit_for(deps, env, cw20_msg.amount, sender, lock_id)
+        }
+        Cw20HookMsg::DepositFor { user, lock_id } => {
             let addr = addr_validate_to_lower(deps.api, &user)?;
             blacklist_check(deps.as_ref(), &addr)?;
-            deposit_for(deps, env, cw20_msg.amount, addr)
+            migrate_user_lock(deps.branch(), &env, &addr)?;
+            deposit_for(deps, env, cw20_msg.amount, addr, lock_id)
         }
     }
 }
 
 /// ## Description
-/// Creates a lock for the user that lasts for the specified time duration (in seconds).
+/// Creates a new lock for the user that lasts for the specified time duration (in seconds).
 /// Checks that the user is locking xASTRO tokens.
 /// Checks that the lock time is within [`WEEK`]..[`MAX_LOCK_TIME`].
-/// Creates a lock if it doesn't exist and triggers a [`checkpoint`] for the staker.
-/// If a lock already exists, then a [`ContractError`] is returned,
+/// The identifier of a withdrawn lock is reused for the new lock, otherwise the next identifier is used.
+/// Creates the lock and triggers a [`checkpoint`] for the staker.
+/// If the user already has [`MAX_USER_LOCKS`] lock positions, then a [`ContractError`] is returned,
 /// otherwise it returns a [`Response`] with the specified attributes if the operation was successful.
 ///
 /// ## Params
@@ -487,28 +530,35 @@ fn create_lock(
     let block_period = get_period(env.block.time.seconds())?;
     let end = block_period + get_periods_count(time);
 
-    LOCKED.update(deps.storage, user.clone(), env.block.height, |lock_opt| {
-        if lock_opt.is_some() && !lock_opt.unwrap().amount.is_zero() {
-            return Err(ContractError::LockAlreadyExists {});
-        }
-        Ok(Lock {
+    let lock_id = find_free_lock_id(deps.as_ref(), &user)?;
+    // A transfer proposed for a withdrawn lock position doesn't apply to the new lock
+    LOCK_TRANSFERS.remove(deps.storage, (user.clone(), U64Key::new(lock_id)));
+
+    LOCKED.save(
+        deps.storage,
+        (user.clone(), U64Key::new(lock_id)),
+        &Lock {
             amount,
             start: block_period,
             end,
             last_extend_lock_period: block_period,
-        })
-    })?;
+            slope: Uint128::zero(),
+        },
+        env.block.height,
+    )?;
 
-    checkpoint(deps, env, user, Some(amount), Some(end))?;
+    checkpoint(deps, env, user, lock_id, Some(amount), None)?;
 
-    Ok(Response::default().add_attribute("action", "create_lock"))
+    Ok(Response::default()
+        .add_attribute("action", "create_lock")
+        .add_attribute("lock_id", lock_id.to_string()))
 }
 
 /// ## Description
-/// Deposits an 'amount' of xASTRO tokens into 'user''s lock.
+/// Deposits an 'amount' of xASTRO tokens into one of 'user''s locks.
 /// Checks that the user is transferring and locking xASTRO.
 /// Triggers a [`checkpoint`] for the user.
-/// If the user does not have a lock, then a [`ContractError`] is returned,
+/// If the user does not have the specified lock, then a [`ContractError`] is returned,
 /// otherwise it returns a [`Response`] with the specified attributes if the operation was successful.
 ///
 /// ## Params
@@ -519,15 +569,18 @@ fn create_lock(
 /// * **amount** is an object of type [`Uint128`]. This is the amount of xASTRO to deposit.
 ///
 /// * **user** is an object of type [`Addr`]. This is the user who's lock amount will increase.
+///
+/// * **lock_id** is a parameter of type [`u64`]. This is the identifier of the lock to deposit in.
 fn deposit_for(
     deps: DepsMut,
     env: Env,
     amount: Uint128,
     user: Addr,
+    lock_id: u64,
 ) -> Result<Response, ContractError> {
     LOCKED.update(
         deps.storage,
-        user.clone(),
+        (user.clone(), U64Key::new(lock_id)),
         env.block.height,
         |lock_opt| match lock_opt {
             Some(mut lock) if !lock.amount.is_zero() => {
@@ -541,7 +594,7 @@ fn deposit_for(
             _ => Err(ContractError::LockDoesntExist {}),
         },
     )?;
-    checkpoint(deps, env, user, Some(amount), None)?;
+    checkpoint(deps, env, user, lock_id, Some(amount), None)?;
 
     Ok(Response::default().add_attribute("action", "deposit_for"))
 }
@@ -557,11 +610,19 @@ fn deposit_for(
 /// * **env** is an object of type [`Env`].
 ///
 /// * **info** is an object of type [`MessageInfo`]. This is the withdrawal message coming from a user.
-fn withdraw(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+///
+/// * **lock_id** is a parameter of type [`u64`]. This is the identifier of the lock to withdraw from.
+fn withdraw(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    lock_id: u64,
+) -> Result<Response, ContractError> {
     let sender = info.sender;
+    let lock_key = (sender.clone(), U64Key::new(lock_id));
     // 'LockDoesntExist' is either a lock does not exist in LOCKED or a lock exits but lock.amount == 0
     let mut lock = LOCKED
-        .may_load(deps.storage, sender.clone())?
+        .may_load(deps.storage, lock_key.clone())?
         .filter(|lock| !lock.amount.is_zero())
         .ok_or(ContractError::LockDoesntExist {})?;
 
@@ -578,20 +639,10 @@ fn withdraw(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, Cont
             })?,
             funds: vec![],
         });
+        // The slope of the expired lock was already eliminated from the user's voting power
+        // by the scheduled slope change
         lock.amount = Uint128::zero();
-        LOCKED.save(deps.storage, sender.clone(), &lock, env.block.height)?;
-
-        // We need to checkpoint and eliminate the slope influence on a future lock
-        HISTORY.save(
-            deps.storage,
-            (sender, U64Key::new(cur_period)),
-            &Point {
-                power: Uint128::zero(),
-                start: cur_period,
-                end: cur_period,
-                slope: Default::default(),
-            },
-        )?;
+        LOCKED.save(deps.storage, lock_key, &lock, env.block.height)?;
 
         Ok(Response::default()
             .add_message(transfer_msg)
@@ -631,6 +682,29 @@ fn configure_early_withdrawal(
     Ok(Response::default().add_attribute("action", "configure_early_withdrawal"))
 }
 
+/// ## Description
+/// Sets the generator controller address. Can be called by the owner only.
+fn update_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    generator_controller: Option<String>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    // Permission check
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if let Some(generator_controller) = generator_controller {
+        config.generator_controller_addr =
+            Some(addr_validate_to_lower(deps.api, &generator_controller)?);
+    }
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::default().add_attribute("action", "update_config"))
+}
+
 /// ## Description
 /// Withdraws stacked funds with penalty before the lock expires.
 /// The penalty is calculated as min(max_exit_penalty, time_left_until_unlock / MAX_LOCK_TIME).
@@ -641,15 +715,19 @@ fn configure_early_withdrawal(
 /// * **env** is an object of type [`Env`].
 ///
 /// * **info** is an object of type [`MessageInfo`]. This is the withdrawal message coming from a user.
+///
+/// * **lock_id** is a parameter of type [`u64`]. This is the identifier of the lock to withdraw from.
 fn withdraw_early(
-    mut deps: DepsMut,
+    deps: DepsMut,
     env: Env,
     info: MessageInfo,
+    lock_id: u64,
 ) -> Result<Response, ContractError> {
     let sender = info.sender;
+    let lock_key = (sender.clone(), U64Key::new(lock_id));
     // 'LockDoesntExist' is either a lock does not exist in LOCKED or a lock exits but lock.amount == 0
     let mut lock = LOCKED
-        .may_load(deps.storage, sender.clone())?
+        .may_load(deps.storage, lock_key.clone())?
         .filter(|lock| !lock.amount.is_zero())
         .ok_or(ContractError::LockDoesntExist {})?;
 
@@ -709,43 +787,10 @@ fn withdraw_early(
     }
 
     lock.amount = Uint128::zero();
-    LOCKED.save(deps.storage, sender.clone(), &lock, env.block.height)?;
+    LOCKED.save(deps.storage, lock_key, &lock, env.block.height)?;
 
-    let cur_period_key = U64Key::new(cur_period);
-    let last_checkpoint = fetch_last_checkpoint(deps.as_ref(), &sender, &cur_period_key)?;
-
-    // If a user has voting power they must have checkpoint.
-    let (_, point) = last_checkpoint.ok_or_else(|| {
-        StdError::generic_err(format!(
-            "There is no previous checkpoint for user: {}",
-            sender.as_str()
-        ))
-    })?;
-    // We need to checkpoint with zero power and zero slope
-    HISTORY.save(
-        deps.storage,
-        (sender, cur_period_key),
-        &Point {
-            power: Uint128::zero(),
-            slope: Default::default(),
-            start: cur_period,
-            end: cur_period,
-        },
-    )?;
-
-    let cur_power = calc_voting_power(&point, cur_period);
-    if !cur_power.is_zero() {
-        cancel_scheduled_slope(deps.branch(), point.slope, point.end)?;
-        // We need to checkpoint total VP and eliminate the slope influence on a future lock
-        checkpoint_total(
-            deps,
-            env,
-            None,
-            Some(cur_power),
-            point.slope,
-            Default::default(),
-        )?
-    }
+    // We need to checkpoint the lock with zero power and zero slope
+    checkpoint(deps, env, sender, lock_id, None, Some(cur_period))?;
 
     Ok(Response::default()
         .add_submessages(transfer_msgs)
@@ -802,7 +847,7 @@ fn withdraw_early_callback(
 }
 
 /// ## Description
-/// Increase the current lock time for a staker by a specified time period.
+/// Increase the current lock time of a staker's lock position by a specified time period.
 /// Evaluates that the `time` is within [`WEEK`]..[`MAX_LOCK_TIME`]
 /// and then it triggers a [`checkpoint`].
 /// If the user lock doesn't exist or if it expired, then a [`ContractError`] is returned,
@@ -819,17 +864,20 @@ fn withdraw_early_callback(
 ///
 /// * **info** is an object of type [`MessageInfo`].
 ///
+/// * **lock_id** is a parameter of type [`u64`]. This is the identifier of the lock to extend.
+///
 /// * **time** is an object of type [`u64`]. This is the increase in lock time applied to the staker's position.
 fn extend_lock_time(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
+    lock_id: u64,
     time: u64,
 ) -> Result<Response, ContractError> {
     let user = info.sender;
     blacklist_check(deps.as_ref(), &user)?;
-    let mut lock = LOCKED
-        .may_load(deps.storage, user.clone())?
+    let lock = LOCKED
+        .may_load(deps.storage, (user.clone(), U64Key::new(lock_id)))?
         .filter(|lock| !lock.amount.is_zero())
         .ok_or(ContractError::LockDoesntExist {})?;
 
@@ -842,14 +890,293 @@ fn extend_lock_time(
 
     // Should not exceed MAX_LOCK_TIME
     time_limits_check(EPOCH_START + lock.end * WEEK + time - env.block.time.seconds())?;
-    lock.end += get_periods_count(time);
-    LOCKED.save(deps.storage, user.clone(), &lock, env.block.height)?;
+    let new_end = lock.end + get_periods_count(time);
 
-    checkpoint(deps, env, user, None, Some(lock.end))?;
+    checkpoint(deps, env, user, lock_id, None, Some(new_end))?;
 
     Ok(Response::default().add_attribute("action", "extend_lock_time"))
 }
 
+/// ## Description
+/// Proposes to transfer a lock position together with its voting power to another address.
+/// The transfer is saved in [`LOCK_TRANSFERS`] and the lock is moved only when the recipient accepts it,
+/// so a lock position can't be pushed to an address that doesn't want it. A new proposal for the same lock replaces the previous one.
+/// If the lock doesn't exist or if it expired, or if the sender or the recipient is blacklisted,
+/// then a [`ContractError`] is returned, otherwise it returns a [`Response`] with the specified attributes if the operation was successful.
+///
+/// ## Params
+/// * **deps** is an object of type [`DepsMut`].
+///
+/// * **env** is an object of type [`Env`].
+///
+/// * **info** is an object of type [`MessageInfo`].
+///
+/// * **lock_id** is a parameter of type [`u64`]. This is the identifier of the lock to transfer.
+///
+/// * **recipient** is an object of type [`String`]. This is the address that receives the lock.
+fn transfer_lock(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    lock_id: u64,
+    recipient: String,
+) -> Result<Response, ContractError> {
+    let sender = info.sender;
+    let recipient = addr_validate_to_lower(deps.api, &recipient)?;
+    if sender == recipient {
+        return Err(ContractError::TransferToOwner {});
+    }
+    blacklist_check(deps.as_ref(), &sender)?;
+    blacklist_check(deps.as_ref(), &recipient)?;
+
+    let lock = LOCKED
+        .may_load(deps.storage, (sender.clone(), U64Key::new(lock_id)))?
+        .filter(|lock| !lock.amount.is_zero())
+        .ok_or(ContractError::LockDoesntExist {})?;
+
+    let cur_period = get_period(env.block.time.seconds())?;
+    if lock.end <= cur_period {
+        return Err(ContractError::LockExpired {});
+    }
+    controller_votes_check(deps.as_ref(), &sender, cur_period)?;
+
+    LOCK_TRANSFERS.save(deps.storage, (sender, U64Key::new(lock_id)), &recipient)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "transfer_lock")
+        .add_attribute("lock_id", lock_id.to_string())
+        .add_attribute("recipient", recipient))
+}
+
+/// ## Description
+/// Accepts a lock position transfer proposed to the sender of this message and moves the lock together with its voting power.
+/// The lock keeps its amount, end and slope, so the total voting power (total vxASTRO supply) doesn't change.
+/// The lock is moved to a free lock position of the recipient and the previous owner's lock position becomes free.
+/// New checkpoints are saved in [`HISTORY`] for both the previous owner and the recipient
+/// and the scheduled slope change is moved from the previous owner to the recipient.
+/// If the transfer or the lock doesn't exist or if the lock expired, if the previous owner or the recipient is blacklisted
+/// or if the recipient has no free lock positions, then a [`ContractError`] is returned,
+/// otherwise it returns a [`Response`] with the specified attributes if the operation was successful.
+///
+/// ## Params
+/// * **deps** is an object of type [`DepsMut`].
+///
+/// * **env** is an object of type [`Env`].
+///
+/// * **info** is an object of type [`MessageInfo`].
+///
+/// * **sender** is an object of type [`String`]. This is the address that proposed the transfer.
+///
+/// * **lock_id** is a parameter of type [`u64`]. This is the identifier of the transferred lock in the sender's lock positions.
+fn accept_lock_transfer(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    sender: String,
+    lock_id: u64,
+) -> Result<Response, ContractError> {
+    let recipient = info.sender;
+    let sender = addr_validate_to_lower(deps.api, &sender)?;
+
+    let transfer_key = (sender.clone(), U64Key::new(lock_id));
+    if LOCK_TRANSFERS.may_load(deps.storage, transfer_key.clone())? != Some(recipient.clone()) {
+        return Err(ContractError::LockTransferDoesntExist {});
+    }
+    LOCK_TRANSFERS.remove(deps.storage, transfer_key);
+
+    blacklist_check(deps.as_ref(), &sender)?;
+    blacklist_check(deps.as_ref(), &recipient)?;
+
+    let sender_lock_key = (sender.clone(), U64Key::new(lock_id));
+    let mut lock = LOCKED
+        .may_load(deps.storage, sender_lock_key.clone())?
+        .filter(|lock| !lock.amount.is_zero())
+        .ok_or(ContractError::LockDoesntExist {})?;
+
+    let cur_period = get_period(env.block.time.seconds())?;
+    if lock.end <= cur_period {
+        return Err(ContractError::LockExpired {});
+    }
+    controller_votes_check(deps.as_ref(), &sender, cur_period)?;
+
+    let recipient_lock_id = find_free_lock_id(deps.as_ref(), &recipient)?;
+    LOCK_TRANSFERS.remove(
+        deps.storage,
+        (recipient.clone(), U64Key::new(recipient_lock_id)),
+    );
+    LOCKED.save(
+        deps.storage,
+        (recipient.clone(), U64Key::new(recipient_lock_id)),
+        &lock,
+        env.block.height,
+    )?;
+
+    let lock_power = calc_lock_voting_power(&lock, cur_period);
+    let lock_slope = lock.slope;
+    let lock_end = lock.end;
+
+    lock.amount = Uint128::zero();
+    lock.slope = Uint128::zero();
+    LOCKED.save(deps.storage, sender_lock_key, &lock, env.block.height)?;
+
+    // Move the lock's contribution from the sender's voting power to the recipient's voting power
+    cancel_user_scheduled_slope(deps.branch(), &sender, lock_slope, lock_end)?;
+    schedule_user_slope_change(deps.branch(), &recipient, lock_slope, lock_end)?;
+
+    if let Some(point) = fetch_user_point(deps.as_ref(), &sender, cur_period)? {
+        HISTORY.save(
+            deps.storage,
+            (sender.clone(), U64Key::new(cur_period)),
+            &Point {
+                power: point.power.saturating_sub(lock_power),
+                slope: point.slope - lock_slope,
+                ..point
+            },
+        )?;
+    }
+
+    let recipient_point =
+        if let Some(point) = fetch_user_point(deps.as_ref(), &recipient, cur_period)? {
+            Point {
+                power: point.power + lock_power,
+                slope: point.slope + lock_slope,
+                end: point.end.max(lock_end),
+                ..point
+            }
+        } else {
+            Point {
+                power: lock_power,
+                slope: lock_slope,
+                start: cur_period,
+                end: lock_end,
+            }
+        };
+    HISTORY.save(
+        deps.storage,
+        (recipient.clone(), U64Key::new(cur_period)),
+        &recipient_point,
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("action", "accept_lock_transfer")
+        .add_attribute("sender", sender)
+        .add_attribute("lock_id", lock_id.to_string())
+        .add_attribute("recipient_lock_id", recipient_lock_id.to_string()))
+}
+
+/// ## Description
+/// Cancels a proposed transfer of one of the sender's lock positions.
+/// If the transfer doesn't exist, then a [`ContractError`] is returned,
+/// otherwise it returns a [`Response`] with the specified attributes if the operation was successful.
+///
+/// ## Params
+/// * **deps** is an object of type [`DepsMut`].
+///
+/// * **info** is an object of type [`MessageInfo`].
+///
+/// * **lock_id** is a parameter of type [`u64`]. This is the identifier of the lock whose transfer is cancelled.
+fn cancel_lock_transfer(
+    deps: DepsMut,
+    info: MessageInfo,
+    lock_id: u64,
+) -> Result<Response, ContractError> {
+    let transfer_key = (info.sender, U64Key::new(lock_id));
+    if !LOCK_TRANSFERS.has(deps.storage, transfer_key.clone()) {
+        return Err(ContractError::LockTransferDoesntExist {});
+    }
+    LOCK_TRANSFERS.remove(deps.storage, transfer_key);
+
+    Ok(Response::default()
+        .add_attribute("action", "cancel_lock_transfer")
+        .add_attribute("lock_id", lock_id.to_string()))
+}
+
+/// ## Description
+/// Merges two lock positions of a staker into the one with the later end (the first one if both end at the same period).
+/// The other lock position is emptied and its voting power is cancelled by a [`checkpoint`].
+/// Its xASTRO is then added to the remaining lock and a [`checkpoint`] recalculates the remaining lock's voting power and slope
+/// as if the xASTRO was deposited into it, which reschedules the slope changes and updates the total voting power.
+/// If one of the locks doesn't exist or if it expired, or if the staker is blacklisted, then a [`ContractError`] is returned,
+/// otherwise it returns a [`Response`] with the specified attributes if the operation was successful.
+///
+/// ## Params
+/// * **deps** is an object of type [`DepsMut`].
+///
+/// * **env** is an object of type [`Env`].
+///
+/// * **info** is an object of type [`MessageInfo`].
+///
+/// * **first_lock_id** is a parameter of type [`u64`]. This is the identifier of the first lock to merge.
+///
+/// * **second_lock_id** is a parameter of type [`u64`]. This is the identifier of the second lock to merge.
+fn merge_lock(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    first_lock_id: u64,
+    second_lock_id: u64,
+) -> Result<Response, ContractError> {
+    let user = info.sender;
+    if first_lock_id == second_lock_id {
+        return Err(ContractError::MergeWithItself {});
+    }
+    blacklist_check(deps.as_ref(), &user)?;
+
+    let cur_period = get_period(env.block.time.seconds())?;
+    let load_lock = |lock_id: u64| -> Result<Lock, ContractError> {
+        let lock = LOCKED
+            .may_load(deps.storage, (user.clone(), U64Key::new(lock_id)))?
+            .filter(|lock| !lock.amount.is_zero())
+            .ok_or(ContractError::LockDoesntExist {})?;
+        if lock.end <= cur_period {
+            return Err(ContractError::LockExpired {});
+        }
+        Ok(lock)
+    };
+    let first_lock = load_lock(first_lock_id)?;
+    let second_lock = load_lock(second_lock_id)?;
+
+    let ((lock_id, mut lock), (merged_lock_id, mut merged_lock)) =
+        if second_lock.end > first_lock.end {
+            ((second_lock_id, second_lock), (first_lock_id, first_lock))
+        } else {
+            ((first_lock_id, first_lock), (second_lock_id, second_lock))
+        };
+    let merged_amount = merged_lock.amount;
+
+    // Cancel the voting power of the merged lock
+    merged_lock.amount = Uint128::zero();
+    LOCKED.save(
+        deps.storage,
+        (user.clone(), U64Key::new(merged_lock_id)),
+        &merged_lock,
+        env.block.height,
+    )?;
+    checkpoint(
+        deps.branch(),
+        env.clone(),
+        user.clone(),
+        merged_lock_id,
+        None,
+        Some(cur_period),
+    )?;
+
+    // Deposit the merged xASTRO into the remaining lock
+    lock.amount += merged_amount;
+    LOCKED.save(
+        deps.storage,
+        (user.clone(), U64Key::new(lock_id)),
+        &lock,
+        env.block.height,
+    )?;
+    checkpoint(deps, env, user, lock_id, Some(merged_amount), None)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "merge_lock")
+        .add_attribute("lock_id", lock_id.to_string())
+        .add_attribute("merged_lock_id", merged_lock_id.to_string()))
+}
+
 /// ## Description
 /// Update the staker blacklist. Whitelists addresses specified in 'remove_addrs'
 /// and blacklists new addresses specified in 'append_addrs'. Nullifies staker voting power and
@@ -894,13 +1221,16 @@ fn update_blacklist(
         return Err(StdError::generic_err("Append and remove arrays are empty").into());
     }
 
+    for addr in append.iter().chain(remove.iter()) {
+        migrate_user_lock(deps.branch(), &env, addr)?;
+    }
+
     let cur_period = get_period(env.block.time.seconds())?;
     let cur_period_key = U64Key::new(cur_period);
     let mut reduce_total_vp = Uint128::zero(); // accumulator for decreasing total voting power
     let mut old_slopes = Uint128::zero(); // accumulator for old slopes
     for addr in append.iter() {
-        let last_checkpoint = fetch_last_checkpoint(deps.as_ref(), addr, &cur_period_key)?;
-        if let Some((_, point)) = last_checkpoint {
+        if let Some(point) = fetch_user_point(deps.as_ref(), addr, cur_period)? {
             // We need to checkpoint with zero power and zero slope
             HISTORY.save(
                 deps.storage,
@@ -913,16 +1243,24 @@ fn update_blacklist(
                 },
             )?;
 
-            let cur_power = calc_voting_power(&point, cur_period);
-            // User's contribution is already zero. Skipping them
-            if cur_power.is_zero() {
-                continue;
+            // Every active lock stops contributing to the voting power until the user is whitelisted again
+            for (lock_id, mut lock) in fetch_user_locks(deps.as_ref(), addr)? {
+                if lock.end > cur_period && !lock.slope.is_zero() {
+                    cancel_scheduled_slope(deps.branch(), lock.slope, lock.end)?;
+                    cancel_user_scheduled_slope(deps.branch(), addr, lock.slope, lock.end)?;
+                    lock.slope = Uint128::zero();
+                    LOCKED.save(
+                        deps.storage,
+                        (addr.clone(), U64Key::new(lock_id)),
+                        &lock,
+                        env.block.height,
+                    )?;
+                }
             }
 
             // User's contribution in the total voting power calculation
-            reduce_total_vp += cur_power;
+            reduce_total_vp += point.power;
             old_slopes += point.slope;
-            cancel_scheduled_slope(deps.branch(), point.slope, point.end)?;
         }
     }
 
@@ -939,15 +1277,18 @@ fn update_blacklist(
     }
 
     for addr in remove.iter() {
-        let lock_opt = LOCKED.may_load(deps.storage, addr.clone())?;
-        if let Some(Lock { amount, end, .. }) = lock_opt {
-            checkpoint(
-                deps.branch(),
-                env.clone(),
-                addr.clone(),
-                Some(amount),
-                Some(end),
-            )?;
+        for (lock_id, lock) in fetch_user_locks(deps.as_ref(), addr)? {
+            if !lock.amount.is_zero() {
+                // Recalculating the lock's voting power
+                checkpoint(
+                    deps.branch(),
+                    env.clone(),
+                    addr.clone(),
+                    lock_id,
+                    None,
+                    None,
+                )?;
+            }
         }
     }
 
@@ -991,7 +1332,11 @@ fn update_blacklist(
 ///
 /// * **QueryMsg::UserVotingPowerAt { time }** Fetch the user's voting power (vxASTRO balance) at a specified timestamp.
 ///
-/// * **QueryMsg::LockInfo { user }** Fetch a user's lock information.
+/// * **QueryMsg::LockInfo { user }** Fetch a user's lock information aggregated across their lock positions.
+///
+/// * **QueryMsg::UserLocks { user }** Fetch the information of every lock position of a user.
+///
+/// * **QueryMsg::EarlyWithdrawAmount { user, lock_id }** Fetch the amount of xASTRO returned by an early withdrawal from a lock.
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
@@ -1012,8 +1357,9 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             to_binary(&get_user_voting_power_at_period(deps, user, period)?)
         }
         QueryMsg::LockInfo { user } => to_binary(&get_user_lock_info(deps, env, user)?),
-        QueryMsg::EarlyWithdrawAmount { user } => {
-            to_binary(&get_early_withdraw_amount(deps, env, user)?)
+        QueryMsg::UserLocks { user } => to_binary(&get_user_locks(deps, env, user)?),
+        QueryMsg::EarlyWithdrawAmount { user, lock_id } => {
+            to_binary(&get_early_withdraw_amount(deps, env, user, lock_id)?)
         }
         QueryMsg::UserDepositAtHeight { user, height } => {
             to_binary(&get_user_deposit_at_height(deps, user, height)?)
@@ -1028,6 +1374,9 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
                 slashed_fund_receiver: config.slashed_fund_receiver.map(|addr| addr.to_string()),
                 astro_addr: config.astro_addr.to_string(),
                 xastro_staking_addr: config.xastro_staking_addr.to_string(),
+                generator_controller_addr: config
+                    .generator_controller_addr
+                    .map(|addr| addr.to_string()),
             })
         }
         QueryMsg::Balance { address } => to_binary(&get_user_balance(deps, env, address)?),
@@ -1038,42 +1387,109 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
 }
 
 /// ## Description
-/// Return a user's lock information using a [`LockInfoResponse`] struct.
+/// Return a user's lock information aggregated across all their lock positions using a [`LockInfoResponse`] struct.
+/// The amounts and the slopes of the locks are summed, `start` is the earliest start and `end` is the latest end.
+/// The coefficient is the average of the lock coefficients weighted by their amounts.
 /// ## Params
 /// * **deps** is an object of type [`Deps`].
 ///
+/// * **env** is an object of type [`Env`].
+///
 /// * **user** is an object of type String. This is the address of the user for which we return lock information.
 fn get_user_lock_info(deps: Deps, env: Env, user: String) -> StdResult<LockInfoResponse> {
     let addr = addr_validate_to_lower(deps.api, &user)?;
-    if let Some(lock) = LOCKED.may_load(deps.storage, addr.clone())? {
-        let cur_period = get_period(env.block.time.seconds())?;
-        let slope = fetch_last_checkpoint(deps, &addr, &U64Key::new(cur_period))?
-            .map(|(_, point)| point.slope)
-            .unwrap_or_default();
-        let resp = LockInfoResponse {
-            amount: lock.amount,
-            coefficient: calc_coefficient(lock.end - lock.last_extend_lock_period),
-            start: lock.start,
-            end: lock.end,
-            slope,
-        };
-        Ok(resp)
-    } else {
-        Err(StdError::generic_err("User is not found"))
+    let locks = fetch_user_locks(deps, &addr)?;
+    if locks.is_empty() {
+        return Err(StdError::generic_err("User is not found"));
+    }
+
+    let cur_period = get_period(env.block.time.seconds())?;
+    let mut resp = LockInfoResponse {
+        amount: Uint128::zero(),
+        coefficient: Decimal::one(),
+        start: u64::MAX,
+        end: 0,
+        slope: Uint128::zero(),
+    };
+    let mut weighted_coefficients = Uint128::zero();
+    for (_, lock) in locks {
+        let lock_info = get_lock_info_response(&lock, cur_period);
+        weighted_coefficients += lock_info.coefficient.checked_mul(lock_info.amount)?;
+        resp.amount += lock_info.amount;
+        resp.start = resp.start.min(lock_info.start);
+        resp.end = resp.end.max(lock_info.end);
+        resp.slope += lock_info.slope;
+    }
+    if !resp.amount.is_zero() {
+        resp.coefficient = Decimal::from_ratio(weighted_coefficients, resp.amount);
+    }
+
+    Ok(resp)
+}
+
+/// ## Description
+/// Return the information of every lock position of a user. Withdrawn locks are skipped.
+/// ## Params
+/// * **deps** is an object of type [`Deps`].
+///
+/// * **env** is an object of type [`Env`].
+///
+/// * **user** is an object of type String. This is the address of the user for which we return lock information.
+fn get_user_locks(deps: Deps, env: Env, user: String) -> StdResult<Vec<UserLockResponse>> {
+    let addr = addr_validate_to_lower(deps.api, &user)?;
+    let cur_period = get_period(env.block.time.seconds())?;
+
+    Ok(fetch_user_locks(deps, &addr)?
+        .into_iter()
+        .filter(|(_, lock)| !lock.amount.is_zero())
+        .map(|(lock_id, lock)| UserLockResponse {
+            lock_id,
+            lock: get_lock_info_response(&lock, cur_period),
+        })
+        .collect())
+}
+
+/// ## Description
+/// Converts a lock position into a [`LockInfoResponse`] struct. Expired locks have a zero slope.
+/// ## Params
+/// * **lock** is an object of type [`Lock`].
+///
+/// * **cur_period** is a parameter of type [`u64`]. This is the current period.
+fn get_lock_info_response(lock: &Lock, cur_period: u64) -> LockInfoResponse {
+    LockInfoResponse {
+        amount: lock.amount,
+        coefficient: calc_coefficient(lock.end - lock.last_extend_lock_period),
+        start: lock.start,
+        end: lock.end,
+        slope: if lock.end > cur_period {
+            lock.slope
+        } else {
+            Uint128::zero()
+        },
     }
 }
 
 /// ## Description
-/// Return early withdraw amount for a given user.
+/// Return early withdraw amount for a given user's lock position.
 /// ## Params
 /// * **deps** is an object of type [`Deps`].
 ///
 /// * **env** is an object of type [`Env`].
 ///
 /// * **user** is an object of type [`String`].
-fn get_early_withdraw_amount(deps: Deps, env: Env, user: String) -> StdResult<Uint128> {
+///
+/// * **lock_id** is a parameter of type [`u64`]. This is the identifier of the user's lock position.
+fn get_early_withdraw_amount(
+    deps: Deps,
+    env: Env,
+    user: String,
+    lock_id: u64,
+) -> StdResult<Uint128> {
     let user = addr_validate_to_lower(deps.api, &user)?;
-    let lock = LOCKED.may_load(deps.storage, user)?;
+    let lock = fetch_user_locks(deps, &user)?
+        .into_iter()
+        .find(|(id, _)| *id == lock_id)
+        .map(|(_, lock)| lock);
 
     let cur_period = get_period(env.block.time.seconds())?;
     match lock {
@@ -1094,7 +1510,8 @@ fn get_early_withdraw_amount(deps: Deps, env: Env, user: String) -> StdResult<Ui
 }
 
 /// ## Description
-/// Return a user's staked xASTRO amount at a given block height.
+/// Return a user's staked xASTRO amount across all their lock positions at a given block height.
+/// Heights preceding the migration to multiple lock positions are read from the previous lock storage.
 /// ## Params
 /// * **deps** is an object of type [`Deps`].
 ///
@@ -1103,11 +1520,22 @@ fn get_early_withdraw_amount(deps: Deps, env: Env, user: String) -> StdResult<Ui
 /// * **block_height** is an object of type u64. This is the block height at which we return the staked xASTRO amount.
 fn get_user_deposit_at_height(deps: Deps, user: String, block_height: u64) -> StdResult<Uint128> {
     let addr = addr_validate_to_lower(deps.api, &user)?;
-    let locked_opt = LOCKED.may_load_at_height(deps.storage, addr, block_height)?;
-    if let Some(lock) = locked_opt {
-        Ok(lock.amount)
-    } else {
-        Ok(Uint128::zero())
+
+    let mut deposit = None;
+    for (lock_id, _) in fetch_user_locks(deps, &addr)? {
+        let lock_key = (addr.clone(), U64Key::new(lock_id));
+        if let Some(lock) = LOCKED.may_load_at_height(deps.storage, lock_key, block_height)? {
+            deposit = Some(deposit.unwrap_or_else(Uint128::zero) + lock.amount);
+        }
+    }
+
+    match deposit {
+        Some(deposit) => Ok(deposit),
+        // The user had no lock positions at this height. Check the locks created before the migration
+        None => Ok(LOCKED_V110
+            .may_load_at_height(deps.storage, addr, block_height)?
+            .map(|lock| lock.amount)
+            .unwrap_or_default()),
     }
 }
 
@@ -1146,19 +1574,12 @@ fn get_user_voting_power_at_period(
     period: u64,
 ) -> StdResult<VotingPowerResponse> {
     let user = addr_validate_to_lower(deps.api, &user)?;
-    let period_key = U64Key::new(period);
-
-    let last_checkpoint = fetch_last_checkpoint(deps, &user, &period_key)?;
 
-    if let Some(point) = last_checkpoint.map(|(_, point)| point) {
-        // The voting power point at the specified `time` was found
-        let voting_power = if point.start == period {
-            point.power
-        } else {
-            // The point before the intended period was found, thus we can calculate the user's voting power for the period we want
-            calc_voting_power(&point, period)
-        };
-        Ok(VotingPowerResponse { voting_power })
+    // The user's scheduled slope changes are applied to the last checkpoint before the intended period
+    if let Some(point) = fetch_user_point(deps, &user, period)? {
+        Ok(VotingPowerResponse {
+            voting_power: point.power,
+        })
     } else {
         // User not found
         Ok(VotingPowerResponse {
@@ -1281,6 +1702,13 @@ pub fn migrate(mut deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response,
             "1.0.0" => {
                 // 1.0.0 -> 1.1.0
                 MigrationV110::migrate(deps.branch(), env, msg)?;
+                // 1.1.0 -> 1.2.0: lock positions are migrated on the first interaction of every staker
+            }
+            _ => return Err(ContractError::MigrationError {}),
+        },
+        "astro-voting-escrow" => match contract_version.version.as_ref() {
+            "1.1.0" => {
+                // 1.1.0 -> 1.2.0: lock positions are migrated on the first interaction of every staker
             }
             _ => return Err(ContractError::MigrationError {}),
         },
@@ -15,8 +15,8 @@ pub enum ContractError {
     #[error("Unauthorized")]
     Unauthorized {},
 
-    #[error("Lock already exists")]
-    LockAlreadyExists {},
+    #[error("A staker can't have more than {0} lock positions")]
+    TooManyLocks(usize),
 
     #[error("Lock does not exist")]
     LockDoesntExist {},
@@ -30,6 +30,18 @@ pub enum ContractError {
     #[error("The lock expired. Withdraw and create new lock")]
     LockExpired {},
 
+    #[error("A lock position can't be transferred to its owner")]
+    TransferToOwner {},
+
+    #[error("Lock transfer does not exist")]
+    LockTransferDoesntExist {},
+
+    #[error("A lock position can't be transferred while its owner has active votes in the generator controller")]
+    ActiveControllerVotes {},
+
+    #[error("A lock position can't be merged with itself")]
+    MergeWithItself {},
+
     #[error("The {0} address is blacklisted")]
     AddressBlacklisted(String),
 
@@ -55,6 +55,7 @@ impl Migration<ParamsV110> for MigrationV110 {
                 slashed_fund_receiver,
                 astro_addr: staking_config.deposit_token_addr,
                 xastro_staking_addr: addr_validate_to_lower(deps.api, &xastro_minter_resp.minter)?,
+                generator_controller_addr: None,
             },
         )?;
 
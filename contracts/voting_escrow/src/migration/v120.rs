@@ -0,0 +1,106 @@
+use cosmwasm_std::{Addr, Deps, DepsMut, Env, StdResult, Uint128};
+use cw_storage_plus::{SnapshotMap, Strategy, U64Key};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use astroport_governance::utils::get_period;
+
+use crate::state::{Lock, Point, HISTORY, LOCKED, USER_SLOPE_CHANGES};
+use crate::utils::{calc_voting_power, fetch_last_checkpoint};
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
+pub struct LockV110 {
+    pub amount: Uint128,
+    pub start: u64,
+    pub end: u64,
+    pub last_extend_lock_period: u64,
+}
+
+/// Stores the single lock position of every staker before the migration to multiple lock positions.
+/// A position is removed once it is migrated and the history is kept to answer deposit queries at
+/// heights preceding the migration.
+pub const LOCKED_V110: SnapshotMap<Addr, LockV110> = SnapshotMap::new(
+    "locked",
+    "locked__checkpoints",
+    "locked__changelog",
+    Strategy::EveryBlock,
+);
+
+/// Returns the lock position a staker had before the migration to multiple lock positions as the lock
+/// with identifier 1. Withdrawn and blacklisted locks have a zero slope.
+/// Returns [`None`] if the staker had no lock or their lock was already migrated.
+pub(crate) fn load_lock_v110(deps: Deps, addr: &Addr) -> StdResult<Option<Lock>> {
+    let lock = match LOCKED_V110.may_load(deps.storage, addr.clone())? {
+        Some(lock) => lock,
+        None => return Ok(None),
+    };
+
+    let slope = match fetch_last_checkpoint(deps, addr, &U64Key::new(u64::MAX))? {
+        Some((_, point)) if !lock.amount.is_zero() => point.slope,
+        _ => Uint128::zero(),
+    };
+
+    Ok(Some(Lock {
+        amount: lock.amount,
+        start: lock.start,
+        end: lock.end,
+        last_extend_lock_period: lock.last_extend_lock_period,
+        slope,
+    }))
+}
+
+/// Moves the lock position of a staker to the lock with identifier 1. Lock positions are migrated
+/// one staker at a time, the first time the staker interacts with the contract after the upgrade.
+/// The staker's scheduled slope change is saved and their last checkpoint is recalculated
+/// for the current period, so that it can be aggregated with new lock positions.
+pub(crate) fn migrate_user_lock(deps: DepsMut, env: &Env, addr: &Addr) -> StdResult<()> {
+    let mut lock = match load_lock_v110(deps.as_ref(), addr)? {
+        Some(lock) => lock,
+        None => return Ok(()),
+    };
+
+    let cur_period = get_period(env.block.time.seconds())?;
+    let cur_period_key = U64Key::new(cur_period);
+
+    // Expired locks don't contribute to the voting power
+    if lock.end <= cur_period {
+        lock.slope = Uint128::zero();
+    }
+
+    // The previous lock storage keeps its history for deposit queries at earlier heights
+    LOCKED_V110.remove(deps.storage, addr.clone(), env.block.height)?;
+    LOCKED.save(
+        deps.storage,
+        (addr.clone(), U64Key::new(1)),
+        &lock,
+        env.block.height,
+    )?;
+
+    if !lock.slope.is_zero() {
+        USER_SLOPE_CHANGES.save(
+            deps.storage,
+            (addr.clone(), U64Key::new(lock.end)),
+            &lock.slope,
+        )?;
+    }
+
+    if let Some((_, point)) = fetch_last_checkpoint(deps.as_ref(), addr, &cur_period_key)? {
+        let power = if lock.slope.is_zero() {
+            Uint128::zero()
+        } else {
+            calc_voting_power(&point, cur_period)
+        };
+        HISTORY.save(
+            deps.storage,
+            (addr.clone(), cur_period_key),
+            &Point {
+                power,
+                start: cur_period,
+                end: point.end,
+                slope: lock.slope,
+            },
+        )?;
+    }
+
+    Ok(())
+}
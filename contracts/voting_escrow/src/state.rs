@@ -22,10 +22,13 @@ pub struct Config {
     pub astro_addr: Addr,
     /// The address of $xASTRO staking contract
     pub xastro_staking_addr: Addr,
+    /// The generator controller address. Lock positions can't be transferred while their owner has active votes in it
+    pub generator_controller_addr: Option<Addr>,
 }
 
 /// ## Description
 /// This structure stores points along the checkpoint history for every vxASTRO staker.
+/// A staker's point aggregates the voting power and the slopes of all their lock positions.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct Point {
     /// The staker's vxASTRO voting power
@@ -50,6 +53,8 @@ pub struct Lock {
     pub end: u64,
     /// the last period when the lock's time was increased
     pub last_extend_lock_period: u64,
+    /// Weekly voting power decay of the lock. It is zero for the locks of blacklisted stakers
+    pub slope: Uint128,
 }
 
 /// ## Description
@@ -57,14 +62,19 @@ pub struct Lock {
 pub const CONFIG: Item<Config> = Item::new("config");
 
 /// ## Description
-/// Stores all user locks history
-pub const LOCKED: SnapshotMap<Addr, Lock> = SnapshotMap::new(
-    "locked",
-    "locked__checkpoints",
-    "locked__changelog",
+/// Stores the history of every staker's lock positions (addr => lock_id).
+/// Withdrawn locks are kept with a zero amount and their identifiers are reused by new locks
+pub const LOCKED: SnapshotMap<(Addr, U64Key), Lock> = SnapshotMap::new(
+    "locks",
+    "locks__checkpoints",
+    "locks__changelog",
     Strategy::EveryBlock,
 );
 
+/// ## Description
+/// Stores the pending lock position transfers (sender, lock_id => recipient)
+pub const LOCK_TRANSFERS: Map<(Addr, U64Key), Addr> = Map::new("lock_transfers");
+
 /// ## Description
 /// Stores the checkpoint history for every staker (addr => period)
 /// Total voting power checkpoints are stored using a (contract_addr => period) key
@@ -74,6 +84,10 @@ pub const HISTORY: Map<(Addr, U64Key), Point> = Map::new("history");
 /// Scheduled slope changes per period (week)
 pub const SLOPE_CHANGES: Map<U64Key, Uint128> = Map::new("slope_changes");
 
+/// ## Description
+/// Scheduled slope changes of every staker's voting power per period (addr => period)
+pub const USER_SLOPE_CHANGES: Map<(Addr, U64Key), Uint128> = Map::new("user_slope_changes");
+
 /// ## Description
 /// Last period when a scheduled slope change was applied
 pub const LAST_SLOPE_CHANGE: Item<u64> = Item::new("last_slope_change");
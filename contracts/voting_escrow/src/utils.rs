@@ -1,12 +1,20 @@
 use crate::error::ContractError;
 use astroport::asset::addr_validate_to_lower;
+use astroport_governance::generator_controller::{
+    QueryMsg as ControllerQueryMsg, UserInfoResponse,
+};
 use astroport_governance::utils::{get_periods_count, MAX_LOCK_TIME, WEEK};
+use astroport_governance::voting_escrow::MAX_USER_LOCKS;
 use cosmwasm_std::{Addr, Decimal, Deps, DepsMut, Order, Pair, StdError, StdResult, Uint128};
 use cw_storage_plus::{Bound, U64Key};
 use std::cmp::min;
 use std::convert::TryInto;
 
-use crate::state::{Point, BLACKLIST, CONFIG, HISTORY, LAST_SLOPE_CHANGE, SLOPE_CHANGES};
+use crate::migration::v120::load_lock_v110;
+use crate::state::{
+    Lock, Point, BLACKLIST, CONFIG, HISTORY, LAST_SLOPE_CHANGE, LOCKED, SLOPE_CHANGES,
+    USER_SLOPE_CHANGES,
+};
 
 /// Checks that a timestamp is within limits.
 pub(crate) fn time_limits_check(time: u64) -> Result<(), ContractError> {
@@ -37,6 +45,35 @@ pub(crate) fn blacklist_check(deps: Deps, addr: &Addr) -> Result<(), ContractErr
     }
 }
 
+/// Checks that a staker has no active votes in the generator controller. Otherwise the voting power
+/// of a transferred lock would be counted in the controller both for the previous owner and for the recipient.
+pub(crate) fn controller_votes_check(
+    deps: Deps,
+    addr: &Addr,
+    cur_period: u64,
+) -> Result<(), ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if let Some(generator_controller_addr) = config.generator_controller_addr {
+        // The generator controller returns an error for stakers that never voted
+        let user_info: Option<UserInfoResponse> = deps
+            .querier
+            .query_wasm_smart(
+                generator_controller_addr,
+                &ControllerQueryMsg::UserInfo {
+                    user: addr.to_string(),
+                },
+            )
+            .ok();
+        if let Some(user_info) = user_info {
+            if !user_info.votes.is_empty() && user_info.lock_end > cur_period {
+                return Err(ContractError::ActiveControllerVotes {});
+            }
+        }
+    }
+
+    Ok(())
+}
+
 /// Adjusting voting power according to the slope. The maximum loss is 103/104 * 104 which is
 /// 0.000103 vxASTRO.
 pub(crate) fn adjust_vp_and_slope(vp: &mut Uint128, dt: u64) -> StdResult<Uint128> {
@@ -57,6 +94,11 @@ pub(crate) fn calc_voting_power(point: &Point, period: u64) -> Uint128 {
         .unwrap_or_else(|_| Uint128::zero())
 }
 
+/// Calculates a lock's voting power at a specific period as: slope * (end - period).
+pub(crate) fn calc_lock_voting_power(lock: &Lock, period: u64) -> Uint128 {
+    lock.slope * Uint128::from(lock.end.saturating_sub(period))
+}
+
 /// Coefficient calculation where 0 [`WEEK`] is equal to 1 and [`MAX_LOCK_TIME`] is 2.5.
 pub(crate) fn calc_coefficient(interval: u64) -> Decimal {
     // coefficient = 1 + 1.5 * (end - start) / MAX_LOCK_TIME
@@ -81,6 +123,61 @@ pub(crate) fn fetch_last_checkpoint(
         .transpose()
 }
 
+/// Fetches the last checkpoint in [`HISTORY`] for the given staker and applies the staker's
+/// scheduled slope changes up to the given period.
+pub(crate) fn fetch_user_point(deps: Deps, addr: &Addr, period: u64) -> StdResult<Option<Point>> {
+    let last_checkpoint = fetch_last_checkpoint(deps, addr, &U64Key::new(period))?;
+
+    if let Some((_, mut point)) = last_checkpoint {
+        let scheduled_slope_changes = fetch_user_slope_changes(deps, addr, point.start, period)?;
+        for (recalc_period, scheduled_change) in scheduled_slope_changes {
+            point = Point {
+                power: calc_voting_power(&point, recalc_period),
+                start: recalc_period,
+                slope: point.slope - scheduled_change,
+                ..point
+            }
+        }
+
+        Ok(Some(Point {
+            power: calc_voting_power(&point, period),
+            start: period,
+            ..point
+        }))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Fetches all lock positions of the given staker. The lock position of a staker that wasn't
+/// migrated to multiple lock positions yet is returned as the lock with identifier 1.
+pub(crate) fn fetch_user_locks(deps: Deps, addr: &Addr) -> StdResult<Vec<(u64, Lock)>> {
+    let locks = LOCKED
+        .prefix(addr.clone())
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(deserialize_pair)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    if locks.is_empty() {
+        if let Some(lock) = load_lock_v110(deps, addr)? {
+            return Ok(vec![(1, lock)]);
+        }
+    }
+
+    Ok(locks)
+}
+
+/// Returns the identifier for a new lock position of the given staker. The identifier of a withdrawn
+/// lock is reused, otherwise the next identifier is used if the staker has less than [`MAX_USER_LOCKS`] locks.
+pub(crate) fn find_free_lock_id(deps: Deps, addr: &Addr) -> Result<u64, ContractError> {
+    let locks = fetch_user_locks(deps, addr)?;
+    match locks.iter().find(|(_, lock)| lock.amount.is_zero()) {
+        Some((lock_id, _)) => Ok(*lock_id),
+        None if locks.len() < MAX_USER_LOCKS => Ok(locks.len() as u64 + 1),
+        None => Err(ContractError::TooManyLocks(MAX_USER_LOCKS)),
+    }
+}
+
 pub(crate) fn cancel_scheduled_slope(deps: DepsMut, slope: Uint128, period: u64) -> StdResult<()> {
     let end_period_key = U64Key::new(period);
     let last_slope_change = LAST_SLOPE_CHANGE
@@ -125,8 +222,48 @@ pub(crate) fn schedule_slope_change(deps: DepsMut, slope: Uint128, period: u64)
     }
 }
 
+pub(crate) fn cancel_user_scheduled_slope(
+    deps: DepsMut,
+    addr: &Addr,
+    slope: Uint128,
+    period: u64,
+) -> StdResult<()> {
+    let end_period_key = (addr.clone(), U64Key::new(period));
+    match USER_SLOPE_CHANGES.may_load(deps.storage, end_period_key.clone())? {
+        Some(old_scheduled_change) if !slope.is_zero() => {
+            let new_slope = old_scheduled_change - slope;
+            if !new_slope.is_zero() {
+                USER_SLOPE_CHANGES.save(deps.storage, end_period_key, &new_slope)
+            } else {
+                USER_SLOPE_CHANGES.remove(deps.storage, end_period_key);
+                Ok(())
+            }
+        }
+        _ => Ok(()),
+    }
+}
+
+pub(crate) fn schedule_user_slope_change(
+    deps: DepsMut,
+    addr: &Addr,
+    slope: Uint128,
+    period: u64,
+) -> StdResult<()> {
+    if !slope.is_zero() {
+        USER_SLOPE_CHANGES
+            .update(
+                deps.storage,
+                (addr.clone(), U64Key::new(period)),
+                |slope_opt| -> StdResult<Uint128> { Ok(slope_opt.unwrap_or_default() + slope) },
+            )
+            .map(|_| ())
+    } else {
+        Ok(())
+    }
+}
+
 /// Helper function for deserialization.
-pub(crate) fn deserialize_pair(pair: StdResult<Pair<Uint128>>) -> StdResult<(u64, Uint128)> {
+pub(crate) fn deserialize_pair<T>(pair: StdResult<Pair<T>>) -> StdResult<(u64, T)> {
     let (period_serialized, change) = pair?;
     let period_bytes: [u8; 8] = period_serialized
         .try_into()
@@ -151,6 +288,25 @@ pub(crate) fn fetch_slope_changes(
         .collect()
 }
 
+/// Fetches all of a staker's slope changes between `last_slope_change` and `period`.
+pub(crate) fn fetch_user_slope_changes(
+    deps: Deps,
+    addr: &Addr,
+    last_slope_change: u64,
+    period: u64,
+) -> StdResult<Vec<(u64, Uint128)>> {
+    USER_SLOPE_CHANGES
+        .prefix(addr.clone())
+        .range(
+            deps.storage,
+            Some(Bound::Exclusive(U64Key::new(last_slope_change).wrapped)),
+            Some(Bound::Inclusive(U64Key::new(period).wrapped)),
+            Order::Ascending,
+        )
+        .map(deserialize_pair)
+        .collect()
+}
+
 /// Bulk validation and conversion between [`String`] -> [`Addr`] for an array of addresses.
 /// If any address is invalid, the function returns [`StdError`].
 pub(crate) fn validate_addresses(deps: Deps, addresses: &[String]) -> StdResult<Vec<Addr>> {
@@ -5,7 +5,7 @@ use terra_multi_test::{next_block, ContractWrapper, Executor};
 
 use astroport_governance::utils::{get_period, MAX_LOCK_TIME, WEEK};
 use astroport_governance::voting_escrow::{
-    ConfigResponse, Cw20HookMsg, ExecuteMsg, LockInfoResponse, QueryMsg,
+    ConfigResponse, Cw20HookMsg, ExecuteMsg, LockInfoResponse, QueryMsg, MAX_USER_LOCKS,
 };
 
 use crate::test_utils::{mock_app, Helper, MULTIPLIER};
@@ -52,17 +52,17 @@ fn lock_unlock_logic() {
 
     // Try to increase the lock time for a position that doesn't exist
     let res = helper
-        .extend_lock_time(router_ref, "user", MAX_LOCK_TIME)
+        .extend_lock_time(router_ref, "user", 1, MAX_LOCK_TIME)
         .unwrap_err();
     assert_eq!(res.to_string(), "Lock does not exist");
 
     // Try to withdraw from a non-existent lock
-    let res = helper.withdraw(router_ref, "user").unwrap_err();
+    let res = helper.withdraw(router_ref, "user", 1).unwrap_err();
     assert_eq!(res.to_string(), "Lock does not exist");
 
     // Try to deposit more xASTRO in a position that does not already exist
     let res = helper
-        .extend_lock_amount(router_ref, "user", 1f32)
+        .extend_lock_amount(router_ref, "user", 1, 1f32)
         .unwrap_err();
     assert_eq!(res.to_string(), "Lock does not exist");
 
@@ -78,15 +78,15 @@ fn lock_unlock_logic() {
     helper.check_xastro_balance(router_ref, "user", 10);
     helper.check_xastro_balance(router_ref, helper.voting_instance.as_str(), 90);
 
-    // A user can have a single vxASTRO position
+    // The user has a single lock position so far
     let res = helper
-        .create_lock(router_ref, "user", MAX_LOCK_TIME, 1f32)
+        .extend_lock_time(router_ref, "user", 2, WEEK)
         .unwrap_err();
-    assert_eq!(res.to_string(), "Lock already exists");
+    assert_eq!(res.to_string(), "Lock does not exist");
 
     // Try to increase the lock time by less than a week
     let res = helper
-        .extend_lock_time(router_ref, "user", 86400)
+        .extend_lock_time(router_ref, "user", 1, 86400)
         .unwrap_err();
     assert_eq!(
         res.to_string(),
@@ -96,7 +96,7 @@ fn lock_unlock_logic() {
     // Try to exceed MAX_LOCK_TIME
     // We locked for 2 weeks so increasing by MAX_LOCK_TIME - week is impossible
     let res = helper
-        .extend_lock_time(router_ref, "user", MAX_LOCK_TIME - WEEK)
+        .extend_lock_time(router_ref, "user", 1, MAX_LOCK_TIME - WEEK)
         .unwrap_err();
     assert_eq!(
         res.to_string(),
@@ -104,12 +104,14 @@ fn lock_unlock_logic() {
     );
 
     // Add more xASTRO to the existing position
-    helper.extend_lock_amount(router_ref, "user", 9f32).unwrap();
+    helper
+        .extend_lock_amount(router_ref, "user", 1, 9f32)
+        .unwrap();
     helper.check_xastro_balance(router_ref, "user", 1);
     helper.check_xastro_balance(router_ref, helper.voting_instance.as_str(), 99);
 
     // Try to withdraw from a non-expired lock
-    let res = helper.withdraw(router_ref, "user").unwrap_err();
+    let res = helper.withdraw(router_ref, "user", 1).unwrap_err();
     assert_eq!(res.to_string(), "The lock time has not yet expired");
 
     // Go in the future
@@ -117,7 +119,7 @@ fn lock_unlock_logic() {
     router_ref.update_block(|block| block.time = block.time.plus_seconds(WEEK));
 
     // The lock has not yet expired since we locked for 2 weeks
-    let res = helper.withdraw(router_ref, "user").unwrap_err();
+    let res = helper.withdraw(router_ref, "user", 1).unwrap_err();
     assert_eq!(res.to_string(), "The lock time has not yet expired");
 
     // Go to the future again
@@ -126,7 +128,7 @@ fn lock_unlock_logic() {
 
     // Try to add more xASTRO to an expired position
     let res = helper
-        .extend_lock_amount(router_ref, "user", 1f32)
+        .extend_lock_amount(router_ref, "user", 1, 1f32)
         .unwrap_err();
     assert_eq!(
         res.to_string(),
@@ -134,7 +136,7 @@ fn lock_unlock_logic() {
     );
     // Try to increase the lock time for an expired position
     let res = helper
-        .extend_lock_time(router_ref, "user", WEEK)
+        .extend_lock_time(router_ref, "user", 1, WEEK)
         .unwrap_err();
     assert_eq!(
         res.to_string(),
@@ -146,13 +148,13 @@ fn lock_unlock_logic() {
     router_ref.update_block(|block| block.time = block.time.plus_seconds(5 * WEEK));
 
     // Time has passed so we can withdraw
-    helper.withdraw(router_ref, "user").unwrap();
+    helper.withdraw(router_ref, "user", 1).unwrap();
     helper.check_xastro_balance(router_ref, "user", 100);
     helper.check_xastro_balance(router_ref, helper.voting_instance.as_str(), 0);
 
     // Check that the lock has disappeared
     let res = helper
-        .extend_lock_amount(router_ref, "user", 1f32)
+        .extend_lock_amount(router_ref, "user", 1, 1f32)
         .unwrap_err();
     assert_eq!(res.to_string(), "Lock does not exist");
 }
@@ -236,7 +238,7 @@ fn new_lock_after_lock_expired() {
     router_ref.update_block(next_block);
     router_ref.update_block(|block| block.time = block.time.plus_seconds(WEEK * 5));
 
-    helper.withdraw(router_ref, "user").unwrap();
+    helper.withdraw(router_ref, "user", 1).unwrap();
     helper.check_xastro_balance(router_ref, "user", 100);
 
     let vp = helper.query_user_vp(router_ref, "user").unwrap();
@@ -398,10 +400,10 @@ fn voting_variable_decay() {
     router_ref.update_block(|block| block.time = block.time.plus_seconds(WEEK * 4));
 
     helper
-        .extend_lock_amount(router_ref, "user", 70f32)
+        .extend_lock_amount(router_ref, "user", 1, 70f32)
         .unwrap();
     helper
-        .extend_lock_time(router_ref, "user2", WEEK * 8)
+        .extend_lock_time(router_ref, "user2", 1, WEEK * 8)
         .unwrap();
     let vp = helper.query_user_vp(router_ref, "user").unwrap();
     assert_eq!(vp, 74.44231);
@@ -499,12 +501,12 @@ fn check_queries() {
     assert_eq!(balance, 90f32);
     // Make the lockup to live longer
     helper
-        .extend_lock_time(router_ref, "user", WEEK * 100)
+        .extend_lock_time(router_ref, "user", 1, WEEK * 100)
         .unwrap();
 
     router_ref.update_block(next_block);
     helper
-        .extend_lock_amount(router_ref, "user", 100f32)
+        .extend_lock_amount(router_ref, "user", 1, 100f32)
         .unwrap();
     let balance = helper
         .query_locked_balance_at(router_ref, "user", start_height)
@@ -530,7 +532,7 @@ fn check_queries() {
         bi.height += 1;
         bi.time = bi.time.plus_seconds(WEEK * 102);
     });
-    helper.withdraw(router_ref, "user").unwrap();
+    helper.withdraw(router_ref, "user", 1).unwrap();
     // Now the users' balance is zero
     let cur_height = router_ref.block_info().height + 1;
     let balance = helper
@@ -564,7 +566,7 @@ fn check_deposit_for() {
     let vp = helper.query_user_vp(router_ref, "user1").unwrap();
     assert_eq!(124.99999, vp);
     helper
-        .deposit_for(router_ref, "user2", "user1", 50f32)
+        .deposit_for(router_ref, "user2", "user1", 1, 50f32)
         .unwrap();
     let vp = helper.query_user_vp(router_ref, "user1").unwrap();
     assert_eq!(249.99998, vp);
@@ -691,7 +693,7 @@ fn check_blacklist() {
         .unwrap_err();
     assert_eq!(err.to_string(), "The user2 address is blacklisted");
     let err = helper
-        .deposit_for(router_ref, "user2", "user3", 50f32)
+        .deposit_for(router_ref, "user2", "user3", 1, 50f32)
         .unwrap_err();
     assert_eq!(err.to_string(), "The user2 address is blacklisted");
 
@@ -735,19 +737,19 @@ fn check_blacklist() {
 
     // user1 is now blacklisted
     let err = helper
-        .extend_lock_time(router_ref, "user1", WEEK * 10)
+        .extend_lock_time(router_ref, "user1", 1, WEEK * 10)
         .unwrap_err();
     assert_eq!(err.to_string(), "The user1 address is blacklisted");
     let err = helper
-        .extend_lock_amount(router_ref, "user1", 10f32)
+        .extend_lock_amount(router_ref, "user1", 1, 10f32)
         .unwrap_err();
     assert_eq!(err.to_string(), "The user1 address is blacklisted");
     let err = helper
-        .deposit_for(router_ref, "user2", "user1", 50f32)
+        .deposit_for(router_ref, "user2", "user1", 1, 50f32)
         .unwrap_err();
     assert_eq!(err.to_string(), "The user2 address is blacklisted");
     let err = helper
-        .deposit_for(router_ref, "user3", "user1", 50f32)
+        .deposit_for(router_ref, "user3", "user1", 1, 50f32)
         .unwrap_err();
     assert_eq!(err.to_string(), "The user1 address is blacklisted");
     // user1 doesn't have voting power now
@@ -771,7 +773,7 @@ fn check_blacklist() {
     router_ref.update_block(|block| block.time = block.time.plus_seconds(20 * WEEK));
 
     // The only option available for a blacklisted user is to withdraw their funds if their lock expired
-    helper.withdraw(router_ref, "user1").unwrap();
+    helper.withdraw(router_ref, "user1", 1).unwrap();
 
     // Remove user1 from the blacklist
     let res = helper
@@ -884,10 +886,10 @@ fn early_withdraw() {
     helper.check_xastro_balance(router_ref, helper.voting_instance.as_str(), 200);
 
     let early_withdrawal_amount = helper
-        .query_early_withdraw_amount(router_ref, "user2")
+        .query_early_withdraw_amount(router_ref, "user2", 1)
         .unwrap();
     assert_eq!(early_withdrawal_amount, 25.0);
-    helper.withdraw_early(router_ref, "user2").unwrap();
+    helper.withdraw_early(router_ref, "user2", 1).unwrap();
 
     // 75% penalty
     helper.check_xastro_balance(router_ref, "user2", 25);
@@ -912,10 +914,10 @@ fn early_withdraw() {
     helper.check_xastro_balance(router_ref, helper.voting_instance.as_str(), 100);
 
     let early_withdrawal_amount = helper
-        .query_early_withdraw_amount(router_ref, "user1")
+        .query_early_withdraw_amount(router_ref, "user1", 1)
         .unwrap();
     assert_eq!(early_withdrawal_amount, 50.0);
-    helper.withdraw_early(router_ref, "user1").unwrap();
+    helper.withdraw_early(router_ref, "user1", 1).unwrap();
 
     // 50% penalty
     helper.check_xastro_balance(router_ref, "user1", 50);
@@ -929,3 +931,330 @@ fn early_withdraw() {
     let total_vp = helper.query_total_vp(router_ref).unwrap();
     assert_eq!(total_vp, 0.0)
 }
+
+#[test]
+fn multiple_locks() {
+    let mut router = mock_app();
+    let router_ref = &mut router;
+    let helper = Helper::init(router_ref, Addr::unchecked("owner"));
+
+    // Mint ASTRO, stake it and mint xASTRO
+    helper.mint_xastro(router_ref, "user", 100);
+    helper.mint_xastro(router_ref, "user2", 100);
+    helper.mint_xastro(router_ref, "user3", 100);
+
+    // "user" opens two positions which are mirrored by single positions of "user2" and "user3"
+    helper
+        .create_lock(router_ref, "user", WEEK * 2, 50f32)
+        .unwrap();
+    helper
+        .create_lock(router_ref, "user", WEEK * 10, 30f32)
+        .unwrap();
+    helper
+        .create_lock(router_ref, "user2", WEEK * 2, 50f32)
+        .unwrap();
+    helper
+        .create_lock(router_ref, "user3", WEEK * 10, 30f32)
+        .unwrap();
+    helper.check_xastro_balance(router_ref, "user", 20);
+
+    let locks = helper.query_user_locks(router_ref, "user").unwrap();
+    assert_eq!(
+        locks
+            .iter()
+            .map(|lock| (lock.lock_id, lock.lock.amount.u128()))
+            .collect::<Vec<_>>(),
+        vec![(1, 50 * MULTIPLIER as u128), (2, 30 * MULTIPLIER as u128)]
+    );
+    let lock_info: LockInfoResponse = router_ref
+        .wrap()
+        .query_wasm_smart(
+            helper.voting_instance.clone(),
+            &QueryMsg::LockInfo {
+                user: "user".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(lock_info.amount.u128(), 80 * MULTIPLIER as u128);
+    assert_eq!(lock_info.end, locks[1].lock.end);
+
+    // The voting power of all positions is summed
+    let vp = helper.query_exact_user_vp(router_ref, "user").unwrap();
+    let vp2 = helper.query_exact_user_vp(router_ref, "user2").unwrap();
+    let vp3 = helper.query_exact_user_vp(router_ref, "user3").unwrap();
+    assert_eq!(vp, vp2 + vp3);
+    let total_vp = helper.query_exact_total_vp(router_ref).unwrap();
+    assert_eq!(total_vp, 2 * vp);
+
+    let height = router_ref.block_info().height;
+    router_ref.update_block(next_block);
+    router_ref.update_block(|block| block.time = block.time.plus_seconds(WEEK * 3));
+
+    // The first position expired, the second one keeps decaying
+    let vp = helper.query_exact_user_vp(router_ref, "user").unwrap();
+    let vp2 = helper.query_exact_user_vp(router_ref, "user2").unwrap();
+    let vp3 = helper.query_exact_user_vp(router_ref, "user3").unwrap();
+    assert_eq!(vp2, 0);
+    assert_eq!(vp, vp3);
+    let total_vp = helper.query_exact_total_vp(router_ref).unwrap();
+    assert_eq!(total_vp, 2 * vp);
+
+    // Positions are withdrawn one by one
+    let res = helper.withdraw(router_ref, "user", 2).unwrap_err();
+    assert_eq!(res.to_string(), "The lock time has not yet expired");
+    helper.withdraw(router_ref, "user", 1).unwrap();
+    helper.check_xastro_balance(router_ref, "user", 70);
+    let locks = helper.query_user_locks(router_ref, "user").unwrap();
+    assert_eq!(
+        locks.iter().map(|lock| lock.lock_id).collect::<Vec<_>>(),
+        vec![2]
+    );
+
+    let deposit = helper
+        .query_locked_balance_at(router_ref, "user", height)
+        .unwrap();
+    assert_eq!(deposit, 80.0);
+
+    // The id of the withdrawn position is reused
+    helper
+        .create_lock(router_ref, "user", WEEK * 5, 10f32)
+        .unwrap();
+    let locks = helper.query_user_locks(router_ref, "user").unwrap();
+    assert_eq!(
+        locks.iter().map(|lock| lock.lock_id).collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+
+    for _ in 2..MAX_USER_LOCKS {
+        helper.create_lock(router_ref, "user", WEEK, 1f32).unwrap();
+    }
+    let res = helper
+        .create_lock(router_ref, "user", WEEK, 1f32)
+        .unwrap_err();
+    assert_eq!(
+        res.to_string(),
+        format!(
+            "A staker can't have more than {} lock positions",
+            MAX_USER_LOCKS
+        )
+    );
+}
+
+#[test]
+fn transfer_lock() {
+    let mut router = mock_app();
+    let router_ref = &mut router;
+    let helper = Helper::init(router_ref, Addr::unchecked("owner"));
+
+    // Mint ASTRO, stake it and mint xASTRO
+    helper.mint_xastro(router_ref, "user1", 100);
+    helper.mint_xastro(router_ref, "user2", 100);
+
+    helper
+        .create_lock(router_ref, "user1", WEEK * 10, 50f32)
+        .unwrap();
+    helper
+        .create_lock(router_ref, "user2", WEEK * 5, 20f32)
+        .unwrap();
+
+    let vp1 = helper.query_exact_user_vp(router_ref, "user1").unwrap();
+    let vp2 = helper.query_exact_user_vp(router_ref, "user2").unwrap();
+    let total_vp = helper.query_exact_total_vp(router_ref).unwrap();
+    assert_eq!(total_vp, vp1 + vp2);
+
+    let res = helper
+        .transfer_lock(router_ref, "user1", 1, "user1")
+        .unwrap_err();
+    assert_eq!(
+        res.to_string(),
+        "A lock position can't be transferred to its owner"
+    );
+    let res = helper
+        .transfer_lock(router_ref, "user1", 2, "user2")
+        .unwrap_err();
+    assert_eq!(res.to_string(), "Lock does not exist");
+
+    helper
+        .update_blacklist(router_ref, Some(vec!["user3".to_string()]), None)
+        .unwrap();
+    let res = helper
+        .transfer_lock(router_ref, "user1", 1, "user3")
+        .unwrap_err();
+    assert_eq!(res.to_string(), "The user3 address is blacklisted");
+
+    // The lock is moved only when the recipient accepts the transfer
+    helper
+        .transfer_lock(router_ref, "user1", 1, "user2")
+        .unwrap();
+    let vp = helper.query_exact_user_vp(router_ref, "user1").unwrap();
+    assert_eq!(vp, vp1);
+    let res = helper
+        .accept_lock_transfer(router_ref, "user3", "user1", 1)
+        .unwrap_err();
+    assert_eq!(res.to_string(), "Lock transfer does not exist");
+
+    helper.cancel_lock_transfer(router_ref, "user1", 1).unwrap();
+    let res = helper
+        .accept_lock_transfer(router_ref, "user2", "user1", 1)
+        .unwrap_err();
+    assert_eq!(res.to_string(), "Lock transfer does not exist");
+    let res = helper
+        .cancel_lock_transfer(router_ref, "user1", 1)
+        .unwrap_err();
+    assert_eq!(res.to_string(), "Lock transfer does not exist");
+
+    helper
+        .transfer_lock(router_ref, "user1", 1, "user2")
+        .unwrap();
+
+    let height = router_ref.block_info().height;
+    router_ref.update_block(next_block);
+
+    // The lock is moved to the next free position of the recipient
+    let res = helper
+        .accept_lock_transfer(router_ref, "user2", "user1", 1)
+        .unwrap();
+    assert_eq!(res.events[1].attributes[4], attr("recipient_lock_id", "2"));
+
+    // The transfer can't be accepted twice
+    let res = helper
+        .accept_lock_transfer(router_ref, "user2", "user1", 1)
+        .unwrap_err();
+    assert_eq!(res.to_string(), "Lock transfer does not exist");
+
+    assert!(helper
+        .query_user_locks(router_ref, "user1")
+        .unwrap()
+        .is_empty());
+    let locks = helper.query_user_locks(router_ref, "user2").unwrap();
+    assert_eq!(
+        locks
+            .iter()
+            .map(|lock| (lock.lock_id, lock.lock.amount.u128()))
+            .collect::<Vec<_>>(),
+        vec![(1, 20 * MULTIPLIER as u128), (2, 50 * MULTIPLIER as u128)]
+    );
+
+    // The voting power moves together with the lock
+    let vp = helper.query_exact_user_vp(router_ref, "user1").unwrap();
+    assert_eq!(vp, 0);
+    let vp = helper.query_exact_user_vp(router_ref, "user2").unwrap();
+    assert_eq!(vp, vp1 + vp2);
+    let vp = helper.query_exact_total_vp(router_ref).unwrap();
+    assert_eq!(vp, total_vp);
+
+    let deposit = helper
+        .query_locked_balance_at(router_ref, "user1", height)
+        .unwrap();
+    assert_eq!(deposit, 50.0);
+    let deposit = helper
+        .query_locked_balance_at(router_ref, "user2", height)
+        .unwrap();
+    assert_eq!(deposit, 20.0);
+
+    // The transferred lock keeps decaying after the recipient's own lock expired
+    router_ref.update_block(next_block);
+    router_ref.update_block(|block| block.time = block.time.plus_seconds(WEEK * 6));
+
+    let vp = helper.query_exact_user_vp(router_ref, "user2").unwrap();
+    assert!(vp > 0);
+    let total_vp = helper.query_exact_total_vp(router_ref).unwrap();
+    assert_eq!(vp, total_vp);
+
+    let res = helper.withdraw(router_ref, "user1", 1).unwrap_err();
+    assert_eq!(res.to_string(), "Lock does not exist");
+    helper.withdraw(router_ref, "user2", 1).unwrap();
+
+    router_ref.update_block(next_block);
+    router_ref.update_block(|block| block.time = block.time.plus_seconds(WEEK * 5));
+
+    let vp = helper.query_exact_user_vp(router_ref, "user2").unwrap();
+    assert_eq!(vp, 0);
+    let vp = helper.query_exact_total_vp(router_ref).unwrap();
+    assert_eq!(vp, 0);
+
+    helper.withdraw(router_ref, "user2", 2).unwrap();
+    helper.check_xastro_balance(router_ref, "user1", 50);
+    helper.check_xastro_balance(router_ref, "user2", 150);
+}
+
+#[test]
+fn merge_lock() {
+    let mut router = mock_app();
+    let router_ref = &mut router;
+    let helper = Helper::init(router_ref, Addr::unchecked("owner"));
+
+    // Mint ASTRO, stake it and mint xASTRO
+    helper.mint_xastro(router_ref, "user1", 100);
+    helper.mint_xastro(router_ref, "user2", 100);
+
+    helper
+        .create_lock(router_ref, "user1", WEEK * 10, 30f32)
+        .unwrap();
+    helper
+        .create_lock(router_ref, "user1", WEEK * 5, 50f32)
+        .unwrap();
+    helper
+        .create_lock(router_ref, "user2", WEEK * 5, 20f32)
+        .unwrap();
+    let locks = helper.query_user_locks(router_ref, "user1").unwrap();
+    let end = locks[0].lock.end;
+
+    let res = helper.merge_lock(router_ref, "user1", 1, 1).unwrap_err();
+    assert_eq!(
+        res.to_string(),
+        "A lock position can't be merged with itself"
+    );
+    let res = helper.merge_lock(router_ref, "user1", 1, 3).unwrap_err();
+    assert_eq!(res.to_string(), "Lock does not exist");
+
+    let vp2 = helper.query_exact_user_vp(router_ref, "user2").unwrap();
+
+    router_ref.update_block(next_block);
+    router_ref.update_block(|block| block.time = block.time.plus_seconds(WEEK));
+    let vp_before = helper.query_exact_user_vp(router_ref, "user1").unwrap();
+
+    // The locks are merged into the first lock because it ends later
+    let res = helper.merge_lock(router_ref, "user1", 2, 1).unwrap();
+    assert_eq!(res.events[1].attributes[2], attr("lock_id", "1"));
+    assert_eq!(res.events[1].attributes[3], attr("merged_lock_id", "2"));
+
+    let locks = helper.query_user_locks(router_ref, "user1").unwrap();
+    assert_eq!(locks.len(), 1);
+    assert_eq!(locks[0].lock_id, 1);
+    assert_eq!(locks[0].lock.amount.u128(), 80 * MULTIPLIER as u128);
+    assert_eq!(locks[0].lock.end, end);
+
+    // The merged xASTRO is boosted according to the remaining lock time
+    let vp = helper.query_exact_user_vp(router_ref, "user1").unwrap();
+    assert!(vp > vp_before);
+    let vp2_now = helper.query_exact_user_vp(router_ref, "user2").unwrap();
+    assert!(vp2_now < vp2);
+    let total_vp = helper.query_exact_total_vp(router_ref).unwrap();
+    assert_eq!(total_vp, vp + vp2_now);
+
+    // The merged xASTRO is locked until the end of the remaining lock
+    router_ref.update_block(next_block);
+    router_ref.update_block(|block| block.time = block.time.plus_seconds(WEEK * 5));
+
+    let res = helper.withdraw(router_ref, "user1", 2).unwrap_err();
+    assert_eq!(res.to_string(), "Lock does not exist");
+    let res = helper.withdraw(router_ref, "user1", 1).unwrap_err();
+    assert_eq!(res.to_string(), "The lock time has not yet expired");
+
+    let vp = helper.query_exact_user_vp(router_ref, "user1").unwrap();
+    assert!(vp > 0);
+    let total_vp = helper.query_exact_total_vp(router_ref).unwrap();
+    assert_eq!(total_vp, vp);
+
+    router_ref.update_block(next_block);
+    router_ref.update_block(|block| block.time = block.time.plus_seconds(WEEK * 5));
+
+    let vp = helper.query_exact_user_vp(router_ref, "user1").unwrap();
+    assert_eq!(vp, 0);
+    let total_vp = helper.query_exact_total_vp(router_ref).unwrap();
+    assert_eq!(total_vp, 0);
+
+    helper.withdraw(router_ref, "user1", 1).unwrap();
+    helper.check_xastro_balance(router_ref, "user1", 100);
+}
@@ -72,6 +72,10 @@ impl Simulator {
     }
 
     fn create_lock(&mut self, user: &str, amount: f64, interval: u64) -> Result<AppResponse> {
+        // The simulation models a single lock position per user
+        if self.locked.contains_key(user) {
+            return Err(anyhow::anyhow!("Lock already exists"));
+        }
         let block_period = self.block_period();
         let periods_interval = get_periods_count(interval);
         self.helper
@@ -93,7 +97,7 @@ impl Simulator {
 
     fn increase_time(&mut self, user: &str, interval: u64) -> Result<AppResponse> {
         self.helper
-            .extend_lock_time(&mut self.router, user, interval)
+            .extend_lock_time(&mut self.router, user, 1, interval)
             .map(|response| {
                 let cur_period = self.block_period() as usize;
                 let periods_interval = get_periods_count(interval);
@@ -121,7 +125,7 @@ impl Simulator {
 
     fn extend_lock(&mut self, user: &str, amount: f64) -> Result<AppResponse> {
         self.helper
-            .extend_lock_amount(&mut self.router, user, amount as f32)
+            .extend_lock_amount(&mut self.router, user, 1, amount as f32)
             .map(|response| {
                 let cur_period = self.block_period() as usize;
                 let (user_balance, end) =
@@ -143,7 +147,7 @@ impl Simulator {
 
     fn withdraw(&mut self, user: &str) -> Result<AppResponse> {
         self.helper
-            .withdraw(&mut self.router, user)
+            .withdraw(&mut self.router, user, 1)
             .map(|response| {
                 let cur_period = self.block_period();
                 self.add_point(cur_period as usize, user, 0.0, cur_period);
@@ -2,7 +2,7 @@ use anyhow::Result;
 use astroport::{staking as xastro, token as astro};
 use astroport_governance::utils::EPOCH_START;
 use astroport_governance::voting_escrow::{
-    Cw20HookMsg, ExecuteMsg, InstantiateMsg, QueryMsg, VotingPowerResponse,
+    Cw20HookMsg, ExecuteMsg, InstantiateMsg, QueryMsg, UserLockResponse, VotingPowerResponse,
 };
 use cosmwasm_std::testing::{mock_env, MockApi, MockStorage};
 use cosmwasm_std::{
@@ -227,13 +227,14 @@ impl Helper {
         &self,
         router: &mut TerraApp,
         user: &str,
+        lock_id: u64,
         amount: f32,
     ) -> Result<AppResponse> {
         let amount = (amount * MULTIPLIER as f32) as u64;
         let cw20msg = Cw20ExecuteMsg::Send {
             contract: self.voting_instance.to_string(),
             amount: Uint128::from(amount),
-            msg: to_binary(&Cw20HookMsg::ExtendLockAmount {}).unwrap(),
+            msg: to_binary(&Cw20HookMsg::ExtendLockAmount { lock_id }).unwrap(),
         };
         router.execute_contract(
             Addr::unchecked(user),
@@ -248,6 +249,7 @@ impl Helper {
         router: &mut TerraApp,
         from: &str,
         to: &str,
+        lock_id: u64,
         amount: f32,
     ) -> Result<AppResponse> {
         let amount = (amount * MULTIPLIER as f32) as u64;
@@ -256,6 +258,7 @@ impl Helper {
             amount: Uint128::from(amount),
             msg: to_binary(&Cw20HookMsg::DepositFor {
                 user: to.to_string(),
+                lock_id,
             })
             .unwrap(),
         };
@@ -271,30 +274,104 @@ impl Helper {
         &self,
         router: &mut TerraApp,
         user: &str,
+        lock_id: u64,
         time: u64,
     ) -> Result<AppResponse> {
         router.execute_contract(
             Addr::unchecked(user),
             self.voting_instance.clone(),
-            &ExecuteMsg::ExtendLockTime { time },
+            &ExecuteMsg::ExtendLockTime { lock_id, time },
             &[],
         )
     }
 
-    pub fn withdraw(&self, router: &mut TerraApp, user: &str) -> Result<AppResponse> {
+    pub fn withdraw(&self, router: &mut TerraApp, user: &str, lock_id: u64) -> Result<AppResponse> {
         router.execute_contract(
             Addr::unchecked(user),
             self.voting_instance.clone(),
-            &ExecuteMsg::Withdraw {},
+            &ExecuteMsg::Withdraw { lock_id },
             &[],
         )
     }
 
-    pub fn withdraw_early(&self, router: &mut TerraApp, user: &str) -> Result<AppResponse> {
+    pub fn withdraw_early(
+        &self,
+        router: &mut TerraApp,
+        user: &str,
+        lock_id: u64,
+    ) -> Result<AppResponse> {
+        router.execute_contract(
+            Addr::unchecked(user),
+            self.voting_instance.clone(),
+            &ExecuteMsg::WithdrawEarly { lock_id },
+            &[],
+        )
+    }
+
+    pub fn transfer_lock(
+        &self,
+        router: &mut TerraApp,
+        user: &str,
+        lock_id: u64,
+        recipient: &str,
+    ) -> Result<AppResponse> {
+        router.execute_contract(
+            Addr::unchecked(user),
+            self.voting_instance.clone(),
+            &ExecuteMsg::TransferLock {
+                lock_id,
+                recipient: recipient.to_string(),
+            },
+            &[],
+        )
+    }
+
+    pub fn accept_lock_transfer(
+        &self,
+        router: &mut TerraApp,
+        user: &str,
+        sender: &str,
+        lock_id: u64,
+    ) -> Result<AppResponse> {
         router.execute_contract(
             Addr::unchecked(user),
             self.voting_instance.clone(),
-            &ExecuteMsg::WithdrawEarly {},
+            &ExecuteMsg::AcceptLockTransfer {
+                sender: sender.to_string(),
+                lock_id,
+            },
+            &[],
+        )
+    }
+
+    pub fn cancel_lock_transfer(
+        &self,
+        router: &mut TerraApp,
+        user: &str,
+        lock_id: u64,
+    ) -> Result<AppResponse> {
+        router.execute_contract(
+            Addr::unchecked(user),
+            self.voting_instance.clone(),
+            &ExecuteMsg::CancelLockTransfer { lock_id },
+            &[],
+        )
+    }
+
+    pub fn merge_lock(
+        &self,
+        router: &mut TerraApp,
+        user: &str,
+        first_lock_id: u64,
+        second_lock_id: u64,
+    ) -> Result<AppResponse> {
+        router.execute_contract(
+            Addr::unchecked(user),
+            self.voting_instance.clone(),
+            &ExecuteMsg::MergeLock {
+                first_lock_id,
+                second_lock_id,
+            },
             &[],
         )
     }
@@ -422,18 +499,37 @@ impl Helper {
             .map(|vp: VotingPowerResponse| vp.voting_power.u128() as f32 / MULTIPLIER as f32)
     }
 
-    pub fn query_early_withdraw_amount(&self, router: &mut TerraApp, user: &str) -> StdResult<f32> {
+    pub fn query_early_withdraw_amount(
+        &self,
+        router: &mut TerraApp,
+        user: &str,
+        lock_id: u64,
+    ) -> StdResult<f32> {
         router
             .wrap()
             .query_wasm_smart(
                 self.voting_instance.clone(),
                 &QueryMsg::EarlyWithdrawAmount {
                     user: user.to_string(),
+                    lock_id,
                 },
             )
             .map(|amount: Uint128| amount.u128() as f32 / MULTIPLIER as f32)
     }
 
+    pub fn query_user_locks(
+        &self,
+        router: &mut TerraApp,
+        user: &str,
+    ) -> StdResult<Vec<UserLockResponse>> {
+        router.wrap().query_wasm_smart(
+            self.voting_instance.clone(),
+            &QueryMsg::UserLocks {
+                user: user.to_string(),
+            },
+        )
+    }
+
     pub fn query_locked_balance_at(
         &self,
         router: &mut TerraApp,
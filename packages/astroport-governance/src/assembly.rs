@@ -7,18 +7,36 @@ use std::fmt::{Display, Formatter, Result};
 
 pub const MINIMUM_PROPOSAL_REQUIRED_THRESHOLD_PERCENTAGE: u64 = 33;
 pub const MAX_PROPOSAL_REQUIRED_THRESHOLD_PERCENTAGE: u64 = 100;
-pub const MINIMUM_DELAY: u64 = 12_342; // 1 day in blocks (7 seconds as 1 block)
-pub const MINIMUM_EXPIRATION_PERIOD: u64 = 86_399; // 1 week in blocks (7 seconds as 1 block)
+pub const MINIMUM_PROPOSAL_REQUIRED_VETO_THRESHOLD_PERCENTAGE: u64 = 10;
+pub const MINIMUM_DELAY: u64 = 86_400; // 1 day in seconds
+pub const MINIMUM_EXPIRATION_PERIOD: u64 = 604_800; // 1 week in seconds
+pub const DEFAULT_PROPOSAL_TYPE: &str = "default";
 
-// Proposal validation attributes
-const MIN_TITLE_LENGTH: usize = 4;
-const MAX_TITLE_LENGTH: usize = 64;
-const MIN_DESC_LENGTH: usize = 4;
-const MAX_DESC_LENGTH: usize = 1024;
-const MIN_LINK_LENGTH: usize = 12;
-const MAX_LINK_LENGTH: usize = 128;
+// Upper bounds for the configurable proposal validation limits
+pub const MAX_TITLE_LENGTH_LIMIT: u64 = 256;
+pub const MAX_DESC_LENGTH_LIMIT: u64 = 16_384;
+pub const MAX_LINK_LENGTH_LIMIT: u64 = 512;
+
+pub const MAX_VOTING_POWER_SOURCES: usize = 10;
+
+// The maximum number of delegators of a delegatee, counted separately for general delegations and
+// for the delegations on every proposal
+pub const MAX_DELEGATORS: usize = 25;
+
+// The maximum number of active proposals an address can have submitted with vxASTRO voting power
+pub const MAX_ACTIVE_PROPOSALS_PER_SUBMITTER: usize = 3;
+
+// Timeout (in seconds) of the IBC packets sent to satellites
+pub const IBC_PACKET_TIMEOUT: u64 = 86_400;
 
 const SAFE_TEXT_CHARS: &str = "!&?#()*+'-./\"";
+// Line breaks and tabs are the only control characters allowed in a description
+const SAFE_DESC_CONTROL_CHARS: &str = "\n\r\t";
+// Bidirectional override and isolate characters can be used to visually reorder text
+const UNSAFE_DESC_CHARS: &str =
+    "\u{202A}\u{202B}\u{202C}\u{202D}\u{202E}\u{2066}\u{2067}\u{2068}\u{2069}";
+// Length of a hex-encoded SHA-256 hash
+const CONTENT_HASH_LENGTH: usize = 64;
 
 /// This structure holds the parameters used for creating an Assembly contract.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -29,11 +47,11 @@ pub struct InstantiateMsg {
     pub vxastro_token_addr: Option<String>,
     /// Address of the builder unlock contract
     pub builder_unlock_addr: String,
-    /// Proposal voting period
+    /// Proposal voting period in seconds
     pub proposal_voting_period: u64,
-    /// Proposal effective delay
+    /// Proposal effective delay in seconds
     pub proposal_effective_delay: u64,
-    /// Proposal expiration period
+    /// Proposal expiration period in seconds
     pub proposal_expiration_period: u64,
     /// Proposal required deposit
     pub proposal_required_deposit: Uint128,
@@ -41,8 +59,40 @@ pub struct InstantiateMsg {
     pub proposal_required_quorum: String,
     /// Proposal required threshold
     pub proposal_required_threshold: String,
+    /// Proposal required veto threshold
+    pub proposal_required_veto_threshold: String,
+    /// Required deposit for an expedited proposal
+    pub expedited_proposal_required_deposit: Uint128,
+    /// Expedited proposal voting period in seconds
+    pub expedited_proposal_voting_period: u64,
+    /// Expedited proposal required threshold
+    pub expedited_proposal_required_threshold: String,
+    /// Whether a proposal can be ended before its voting period ends once the voting power that
+    /// wasn't cast can't change its result. Disabled if not set
+    pub early_finalization_enabled: Option<bool>,
+    /// Minimum vxASTRO voting power that allows submitting a proposal without an xASTRO deposit.
+    /// Submission with vxASTRO voting power is disabled if not set
+    pub proposal_required_vxastro_power: Option<Uint128>,
+    /// Share of the deposit slashed when a proposal doesn't reach the quorum
+    pub quorum_failure_deposit_slash: String,
+    /// Share of the deposit slashed when a proposal is vetoed
+    pub veto_deposit_slash: String,
+    /// Address that receives slashed deposits. Slashed deposits are burned if not set
+    pub slashed_deposit_receiver: Option<String>,
+    /// Address that can cancel any proposal before it is executed
+    pub guardian: Option<String>,
+    /// Timelock contract that queues the messages of passed proposals. Proposal messages are
+    /// dispatched directly by the Assembly if not set
+    pub timelock_addr: Option<String>,
+    /// Proposal title, description and link length limits. Default limits apply if not set
+    pub proposal_validation: Option<ProposalValidationConfig>,
+    /// Contracts the voting power is read from. If not set, the xASTRO, builder unlock and
+    /// vxASTRO contracts are used with a weight of one
+    pub voting_power_sources: Option<Vec<VotingPowerSource>>,
     /// Whitelisted links
     pub whitelisted_links: Vec<String>,
+    /// Proposal types with their own deposit, quorum, threshold, voting period and effective delay
+    pub proposal_types: Option<Vec<ProposalType>>,
 }
 
 /// This enum describes all execute functions available in the contract.
@@ -51,6 +101,21 @@ pub struct InstantiateMsg {
 pub enum ExecuteMsg {
     /// Receive a message of type [`Cw20ReceiveMsg`]
     Receive(Cw20ReceiveMsg),
+    /// Submit a new proposal without an xASTRO deposit
+    /// ## Executor
+    /// Only an address whose vxASTRO voting power at the end of the previous week is at least
+    /// `proposal_required_vxastro_power` and that has less than [`MAX_ACTIVE_PROPOSALS_PER_SUBMITTER`]
+    /// active proposals can submit a proposal this way. Expedited proposals require an xASTRO deposit
+    SubmitProposal {
+        title: String,
+        description: String,
+        link: Option<String>,
+        /// Hex-encoded SHA-256 hash of the document behind the link
+        content_hash: Option<String>,
+        messages: Option<Vec<ProposalMessage>>,
+        /// Name of a registered proposal type. The default proposal rules apply if not set
+        proposal_type: Option<String>,
+    },
     /// Cast a vote for an active proposal
     CastVote {
         /// Proposal identifier
@@ -58,7 +123,8 @@ pub enum ExecuteMsg {
         /// Vote option
         vote: ProposalVoteOption,
     },
-    /// Set the status of a proposal that expired
+    /// Set the status of a proposal whose voting period ended. If early finalization is enabled,
+    /// a proposal can also be ended once the voting power that wasn't cast can't change its result
     EndProposal {
         /// Proposal identifier
         proposal_id: u64,
@@ -68,7 +134,40 @@ pub enum ExecuteMsg {
         /// Proposal identifier
         proposal_id: u64,
     },
-    /// Remove a proposal that was already executed (or failed/expired)
+    /// Cancel a proposal
+    /// ## Executor
+    /// The submitter can cancel an active proposal before any votes are cast.
+    /// The guardian can cancel any proposal before it is executed, including a proposal queued in the Timelock.
+    CancelProposal {
+        /// Proposal identifier
+        proposal_id: u64,
+    },
+    /// Update the status of a proposal queued in the Timelock once its Timelock transaction was executed,
+    /// cancelled or expired. Anyone can execute this
+    SyncQueuedProposal {
+        /// Proposal identifier
+        proposal_id: u64,
+    },
+    /// Delegate voting power to another address
+    Delegate {
+        /// The address that receives the voting power
+        delegatee: String,
+        /// If set, the delegation only applies to this proposal and overrides the general delegation
+        proposal_id: Option<u64>,
+    },
+    /// Remove a voting power delegation
+    Undelegate {
+        /// If set, only the delegation for this proposal is removed
+        proposal_id: Option<u64>,
+    },
+    /// Check that proposal messages can be executed by running them on behalf of the Assembly.
+    /// The transaction is always reverted: it fails with the "Messages executed successfully!"
+    /// error if all messages succeed, otherwise with the error of the failed message
+    CheckMessages {
+        /// Messages to check
+        messages: Vec<ProposalMessage>,
+    },
+    /// Remove a proposal that was already executed (or failed/expired/cancelled)
     RemoveCompletedProposal {
         /// Proposal identifier
         proposal_id: u64,
@@ -77,6 +176,12 @@ pub enum ExecuteMsg {
     /// ## Executor
     /// Only the Assembly contract is allowed to update its own parameters
     UpdateConfig(UpdateConfig),
+    /// Convert the next batch of proposals created before v1.1.0 to the current layout.
+    /// Other messages are rejected until all proposals are converted. Anyone can execute this
+    MigrateProposals {
+        /// The maximum number of proposals to convert
+        limit: Option<u32>,
+    },
 }
 
 /// Thie enum describes all the queries available in the contract.
@@ -91,15 +196,55 @@ pub enum QueryMsg {
         start: Option<u64>,
         /// The amount of proposals to return
         limit: Option<u32>,
+        /// Return only proposals with the specified status
+        status: Option<ProposalStatus>,
+        /// Return only proposals posted by the specified submitter
+        submitter: Option<String>,
+        /// Return proposals in descending order of their ids
+        descending: Option<bool>,
     },
     /// Return information about a specific proposal
     Proposal { proposal_id: u64 },
     /// Return information about the votes cast on a specific proposal
     ProposalVotes { proposal_id: u64 },
-    /// Return user voting power for a specific proposal
+    /// Return the list of voters (and their voting power) that chose a specific option on a proposal
+    ProposalVoters {
+        proposal_id: u64,
+        option: ProposalVoteOption,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Return the vote cast by a user on a specific proposal
+    UserVote { proposal_id: u64, user: String },
+    /// Return the votes cast by a user on all proposals
+    UserVotes {
+        user: String,
+        /// Proposal id after which to start querying
+        start_after: Option<u64>,
+        /// The amount of votes to return
+        limit: Option<u32>,
+    },
+    /// Return user voting power (including the power delegated to them) for a specific proposal
     UserVotingPower { user: String, proposal_id: u64 },
+    /// Return the address a user delegated their voting power to
+    Delegation {
+        user: String,
+        /// If set, return the delegation that applies to this proposal
+        proposal_id: Option<u64>,
+    },
+    /// Return the voting power delegated to an address that can still be used on a specific proposal
+    DelegatedVotingPower { delegatee: String, proposal_id: u64 },
     /// Return total voting power for a specific proposal
     TotalVotingPower { proposal_id: u64 },
+    /// Return the IBC channels opened by satellites
+    IbcChannels {},
+    /// Return the summaries of the proposals removed from the proposal list
+    ArchivedProposals {
+        /// Id after which to start querying
+        start_after: Option<u64>,
+        /// The amount of archived proposals to return
+        limit: Option<u32>,
+    },
 }
 
 /// ## Description
@@ -112,7 +257,13 @@ pub enum Cw20HookMsg {
         title: String,
         description: String,
         link: Option<String>,
+        /// Hex-encoded SHA-256 hash of the document behind the link
+        content_hash: Option<String>,
         messages: Option<Vec<ProposalMessage>>,
+        /// Name of a registered proposal type. The default proposal rules apply if not set
+        proposal_type: Option<String>,
+        /// Whether the proposal is voted on under the expedited rules
+        expedited: Option<bool>,
     },
 }
 
@@ -125,11 +276,11 @@ pub struct Config {
     pub vxastro_token_addr: Option<Addr>,
     /// Builder unlock contract address
     pub builder_unlock_addr: Addr,
-    /// Proposal voting period
+    /// Proposal voting period in seconds
     pub proposal_voting_period: u64,
-    /// Proposal effective delay
+    /// Proposal effective delay in seconds
     pub proposal_effective_delay: u64,
-    /// Proposal expiration period
+    /// Proposal expiration period in seconds
     pub proposal_expiration_period: u64,
     /// Proposal required deposit
     pub proposal_required_deposit: Uint128,
@@ -137,15 +288,213 @@ pub struct Config {
     pub proposal_required_quorum: Decimal,
     /// Proposal required threshold
     pub proposal_required_threshold: Decimal,
+    /// Proposal required veto threshold
+    pub proposal_required_veto_threshold: Decimal,
+    /// Required deposit for an expedited proposal
+    pub expedited_proposal_required_deposit: Uint128,
+    /// Expedited proposal voting period in seconds
+    pub expedited_proposal_voting_period: u64,
+    /// Expedited proposal required threshold
+    pub expedited_proposal_required_threshold: Decimal,
+    /// Whether a proposal can be ended before its voting period ends once the voting power that
+    /// wasn't cast can't change its result
+    pub early_finalization_enabled: bool,
+    /// Minimum vxASTRO voting power that allows submitting a proposal without an xASTRO deposit.
+    /// Submission with vxASTRO voting power is disabled if not set
+    pub proposal_required_vxastro_power: Option<Uint128>,
+    /// Share of the deposit slashed when a proposal doesn't reach the quorum
+    pub quorum_failure_deposit_slash: Decimal,
+    /// Share of the deposit slashed when a proposal is vetoed
+    pub veto_deposit_slash: Decimal,
+    /// Address that receives slashed deposits. Slashed deposits are burned if not set
+    pub slashed_deposit_receiver: Option<Addr>,
+    /// Address that can cancel any proposal before it is executed
+    pub guardian: Option<Addr>,
+    /// Timelock contract that queues the messages of passed proposals. Proposal messages are
+    /// dispatched directly by the Assembly if not set
+    pub timelock_addr: Option<Addr>,
+    /// Proposal title, description and link length limits
+    pub proposal_validation: ProposalValidationConfig,
+    /// Contracts the voting power is read from
+    pub voting_power_sources: Vec<VotingPowerSource>,
     /// Whitelisted links
     pub whitelisted_links: Vec<String>,
+    /// Proposal types with their own rules. Proposals without a type use the default proposal rules
+    pub proposal_types: Vec<ProposalType>,
+    /// Satellites that are allowed to open IBC channels with the Assembly
+    pub ibc_satellites: Vec<IbcSatellite>,
 }
 
 impl Config {
     pub fn validate(&self) -> StdResult<()> {
-        if self.proposal_required_threshold
+        self.default_proposal_type().validate()?;
+
+        self.proposal_validation.validate()?;
+
+        if self.voting_power_sources.is_empty()
+            || self.voting_power_sources.len() > MAX_VOTING_POWER_SOURCES
+        {
+            return Err(StdError::generic_err(format!(
+                "The number of voting power sources must be between 1 and {}",
+                MAX_VOTING_POWER_SOURCES
+            )));
+        }
+
+        for (i, source) in self.voting_power_sources.iter().enumerate() {
+            if source.weight.is_zero() {
+                return Err(StdError::generic_err(format!(
+                    "The weight of the voting power source {} cannot be zero",
+                    source.contract_addr
+                )));
+            }
+
+            if self.voting_power_sources[..i]
+                .iter()
+                .any(|s| s.contract_addr == source.contract_addr && s.adapter == source.adapter)
+            {
+                return Err(StdError::generic_err(format!(
+                    "Duplicate voting power source: {}",
+                    source.contract_addr
+                )));
+            }
+        }
+
+        for (i, proposal_type) in self.proposal_types.iter().enumerate() {
+            if proposal_type.name.is_empty() || proposal_type.name == DEFAULT_PROPOSAL_TYPE {
+                return Err(StdError::generic_err(format!(
+                    "Invalid proposal type name: {}",
+                    proposal_type.name
+                )));
+            }
+
+            if self.proposal_types[..i]
+                .iter()
+                .any(|t| t.name == proposal_type.name)
+            {
+                return Err(StdError::generic_err(format!(
+                    "Duplicate proposal type: {}",
+                    proposal_type.name
+                )));
+            }
+
+            proposal_type.validate()?;
+
+            if proposal_type.voting_period < self.expedited_proposal_voting_period {
+                return Err(StdError::generic_err(format!(
+                    "The voting period of the proposal type {} cannot be shorter than the expedited proposal voting period",
+                    proposal_type.name
+                )));
+            }
+        }
+
+        if self.proposal_required_veto_threshold
             > Decimal::percent(MAX_PROPOSAL_REQUIRED_THRESHOLD_PERCENTAGE)
-            || self.proposal_required_threshold
+            || self.proposal_required_veto_threshold
+                < Decimal::percent(MINIMUM_PROPOSAL_REQUIRED_VETO_THRESHOLD_PERCENTAGE)
+        {
+            return Err(StdError::generic_err(format!(
+                "The required veto threshold for a proposal cannot be lower than {}% or higher than {}%",
+                MINIMUM_PROPOSAL_REQUIRED_VETO_THRESHOLD_PERCENTAGE,
+                MAX_PROPOSAL_REQUIRED_THRESHOLD_PERCENTAGE
+            )));
+        }
+
+        if self.expedited_proposal_required_threshold
+            > Decimal::percent(MAX_PROPOSAL_REQUIRED_THRESHOLD_PERCENTAGE)
+            || self.expedited_proposal_required_threshold < self.proposal_required_threshold
+        {
+            return Err(StdError::generic_err(format!(
+                "The required threshold for an expedited proposal cannot be lower than the regular threshold or higher than {}%",
+                MAX_PROPOSAL_REQUIRED_THRESHOLD_PERCENTAGE
+            )));
+        }
+
+        if self.expedited_proposal_voting_period > self.proposal_voting_period {
+            return Err(StdError::generic_err(
+                "The voting period for an expedited proposal cannot be longer than the regular voting period",
+            ));
+        }
+
+        if self.expedited_proposal_required_deposit < self.proposal_required_deposit {
+            return Err(StdError::generic_err(
+                "The required deposit for an expedited proposal cannot be lower than the regular deposit",
+            ));
+        }
+
+        if self.quorum_failure_deposit_slash > Decimal::one()
+            || self.veto_deposit_slash > Decimal::one()
+        {
+            return Err(StdError::generic_err(
+                "The slashed share of a proposal deposit cannot be higher than 100%",
+            ));
+        }
+
+        if self.proposal_expiration_period < MINIMUM_EXPIRATION_PERIOD {
+            return Err(StdError::generic_err(format!(
+                "The expiration period for a proposal cannot be less than {} seconds.",
+                MINIMUM_EXPIRATION_PERIOD
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the default proposal rules, which apply to proposals submitted without a type.
+    pub fn default_proposal_type(&self) -> ProposalType {
+        ProposalType {
+            name: DEFAULT_PROPOSAL_TYPE.to_string(),
+            required_deposit: self.proposal_required_deposit,
+            required_quorum: self.proposal_required_quorum,
+            required_threshold: self.proposal_required_threshold,
+            voting_period: self.proposal_voting_period,
+            effective_delay: self.proposal_effective_delay,
+        }
+    }
+
+    /// Returns the rules for the specified proposal type or the default rules if no type is specified.
+    /// Returns [`None`] if the type is not registered.
+    pub fn proposal_type(&self, name: &Option<String>) -> Option<ProposalType> {
+        match name {
+            Some(name) => self
+                .proposal_types
+                .iter()
+                .find(|proposal_type| &proposal_type.name == name)
+                .cloned(),
+            None => Some(self.default_proposal_type()),
+        }
+    }
+}
+
+/// This structure describes the rules applied to proposals of a specific type.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ProposalType {
+    /// Proposal type name
+    pub name: String,
+    /// Proposal required deposit
+    pub required_deposit: Uint128,
+    /// Proposal required quorum
+    pub required_quorum: Decimal,
+    /// Proposal required threshold
+    pub required_threshold: Decimal,
+    /// Proposal voting period in seconds
+    pub voting_period: u64,
+    /// Proposal effective delay in seconds
+    pub effective_delay: u64,
+}
+
+/// This structure describes a satellite that is allowed to open an IBC channel with the Assembly.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct IbcSatellite {
+    /// IBC connection to the satellite chain
+    pub connection_id: String,
+    /// IBC port of the satellite contract
+    pub port_id: String,
+}
+
+impl ProposalType {
+    pub fn validate(&self) -> StdResult<()> {
+        if self.required_threshold > Decimal::percent(MAX_PROPOSAL_REQUIRED_THRESHOLD_PERCENTAGE)
+            || self.required_threshold
                 < Decimal::percent(MINIMUM_PROPOSAL_REQUIRED_THRESHOLD_PERCENTAGE)
         {
             return Err(StdError::generic_err(format!(
@@ -155,33 +504,141 @@ impl Config {
             )));
         }
 
-        if self.proposal_required_quorum
-            > Decimal::percent(MAX_PROPOSAL_REQUIRED_THRESHOLD_PERCENTAGE)
-        {
+        if self.required_quorum > Decimal::percent(MAX_PROPOSAL_REQUIRED_THRESHOLD_PERCENTAGE) {
             return Err(StdError::generic_err(format!(
                 "The required quorum for a proposal cannot be higher than {}%",
                 MAX_PROPOSAL_REQUIRED_THRESHOLD_PERCENTAGE
             )));
         }
 
-        if self.proposal_effective_delay < MINIMUM_DELAY {
+        if self.effective_delay < MINIMUM_DELAY {
             return Err(StdError::generic_err(format!(
-                "The effective delay for a proposal cannot be less than {} blocks.",
+                "The effective delay for a proposal cannot be less than {} seconds.",
                 MINIMUM_DELAY
             )));
         }
 
-        if self.proposal_expiration_period < MINIMUM_EXPIRATION_PERIOD {
-            return Err(StdError::generic_err(format!(
-                "The expiration period for a proposal cannot be less than {} blocks.",
-                MINIMUM_EXPIRATION_PERIOD
-            )));
+        Ok(())
+    }
+}
+
+/// This structure describes the length limits applied to proposal texts. Title and link lengths
+/// are counted in bytes and description lengths in characters.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ProposalValidationConfig {
+    /// Minimum title length
+    pub min_title_length: u64,
+    /// Maximum title length
+    pub max_title_length: u64,
+    /// Minimum description length
+    pub min_description_length: u64,
+    /// Maximum description length
+    pub max_description_length: u64,
+    /// Minimum link length
+    pub min_link_length: u64,
+    /// Maximum link length
+    pub max_link_length: u64,
+}
+
+impl Default for ProposalValidationConfig {
+    fn default() -> Self {
+        ProposalValidationConfig {
+            min_title_length: 4,
+            max_title_length: 64,
+            min_description_length: 4,
+            max_description_length: 4096,
+            min_link_length: 12,
+            max_link_length: 128,
+        }
+    }
+}
+
+impl ProposalValidationConfig {
+    pub fn validate(&self) -> StdResult<()> {
+        let limits = [
+            (
+                "title",
+                self.min_title_length,
+                self.max_title_length,
+                MAX_TITLE_LENGTH_LIMIT,
+            ),
+            (
+                "description",
+                self.min_description_length,
+                self.max_description_length,
+                MAX_DESC_LENGTH_LIMIT,
+            ),
+            (
+                "link",
+                self.min_link_length,
+                self.max_link_length,
+                MAX_LINK_LENGTH_LIMIT,
+            ),
+        ];
+
+        for (name, min_length, max_length, length_limit) in limits.iter() {
+            if *min_length == 0 || min_length > max_length || max_length > length_limit {
+                return Err(StdError::generic_err(format!(
+                    "The {} length limits must be between 1 and {} with the minimum not exceeding the maximum",
+                    name, length_limit
+                )));
+            }
         }
 
         Ok(())
     }
 }
 
+/// This structure describes a contract the voting power is read from.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct VotingPowerSource {
+    /// Contract address
+    pub contract_addr: Addr,
+    /// Query interface used to read the voting power from the contract
+    pub adapter: VotingPowerAdapter,
+    /// Multiplier applied to both the user and the total voting power read from the contract
+    pub weight: Decimal,
+}
+
+/// This enum describes the query interfaces supported for reading the voting power.
+/// User voting power is read at the block (or the second) before the proposal was submitted.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum VotingPowerAdapter {
+    /// A CW20 token with balance and total supply snapshots, like xASTRO
+    Cw20Snapshot,
+    /// The builder unlock contract. The voting power is the amount of ASTRO that is not yet withdrawn
+    BuilderUnlock,
+    /// The vxASTRO contract. The xASTRO locked by a user is added to their voting power,
+    /// as the xASTRO total supply already accounts for it
+    VotingEscrow,
+    /// Any contract that implements the [`VotingPowerQueryMsg`] queries
+    VotingPowerAt,
+}
+
+impl Display for VotingPowerAdapter {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        match self {
+            VotingPowerAdapter::Cw20Snapshot => fmt.write_str("cw20_snapshot"),
+            VotingPowerAdapter::BuilderUnlock => fmt.write_str("builder_unlock"),
+            VotingPowerAdapter::VotingEscrow => fmt.write_str("voting_escrow"),
+            VotingPowerAdapter::VotingPowerAt => fmt.write_str("voting_power_at"),
+        }
+    }
+}
+
+/// This enum describes the generic voting power queries a contract must implement to be used as
+/// a [`VotingPowerAdapter::VotingPowerAt`] source. Both queries return a
+/// [`VotingPowerResponse`](crate::voting_escrow::VotingPowerResponse).
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum VotingPowerQueryMsg {
+    /// Return the user's voting power at the specified timestamp
+    VotingPowerAt { user: String, time: u64 },
+    /// Return the total voting power at the specified timestamp
+    TotalVotingPowerAt { time: u64 },
+}
+
 /// This structure sotres the params used when updating the main Assembly contract params.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct UpdateConfig {
@@ -191,11 +648,11 @@ pub struct UpdateConfig {
     pub vxastro_token_addr: Option<String>,
     /// Builder unlock contract address
     pub builder_unlock_addr: Option<String>,
-    /// Proposal voting period
+    /// Proposal voting period in seconds
     pub proposal_voting_period: Option<u64>,
-    /// Proposal effective delay
+    /// Proposal effective delay in seconds
     pub proposal_effective_delay: Option<u64>,
-    /// Proposal expiration period
+    /// Proposal expiration period in seconds
     pub proposal_expiration_period: Option<u64>,
     /// Proposal required deposit
     pub proposal_required_deposit: Option<u128>,
@@ -203,10 +660,46 @@ pub struct UpdateConfig {
     pub proposal_required_quorum: Option<String>,
     /// Proposal required threshold
     pub proposal_required_threshold: Option<String>,
+    /// Proposal required veto threshold
+    pub proposal_required_veto_threshold: Option<String>,
+    /// Required deposit for an expedited proposal
+    pub expedited_proposal_required_deposit: Option<u128>,
+    /// Expedited proposal voting period in seconds
+    pub expedited_proposal_voting_period: Option<u64>,
+    /// Expedited proposal required threshold
+    pub expedited_proposal_required_threshold: Option<String>,
+    /// Whether a proposal can be ended before its voting period ends once the voting power that
+    /// wasn't cast can't change its result
+    pub early_finalization_enabled: Option<bool>,
+    /// Minimum vxASTRO voting power that allows submitting a proposal without an xASTRO deposit.
+    /// Zero disables submission with vxASTRO voting power
+    pub proposal_required_vxastro_power: Option<u128>,
+    /// Share of the deposit slashed when a proposal doesn't reach the quorum
+    pub quorum_failure_deposit_slash: Option<String>,
+    /// Share of the deposit slashed when a proposal is vetoed
+    pub veto_deposit_slash: Option<String>,
+    /// Address that receives slashed deposits
+    pub slashed_deposit_receiver: Option<String>,
+    /// Address that can cancel any proposal before it is executed
+    pub guardian: Option<String>,
+    /// Timelock contract that queues the messages of passed proposals
+    pub timelock_addr: Option<String>,
+    /// Proposal title, description and link length limits
+    pub proposal_validation: Option<ProposalValidationConfig>,
+    /// Contracts the voting power is read from. Replaces the whole list
+    pub voting_power_sources: Option<Vec<VotingPowerSource>>,
     /// Links to remove from whitelist
     pub whitelist_remove: Option<Vec<String>>,
     /// Links to add to whitelist
     pub whitelist_add: Option<Vec<String>>,
+    /// Proposal types to add. Registered types with the same name are replaced
+    pub proposal_types_add: Option<Vec<ProposalType>>,
+    /// Names of the proposal types to remove
+    pub proposal_types_remove: Option<Vec<String>>,
+    /// Satellites to allow opening IBC channels with the Assembly
+    pub ibc_satellites_add: Option<Vec<IbcSatellite>>,
+    /// Satellites to disallow opening IBC channels with the Assembly
+    pub ibc_satellites_remove: Option<Vec<IbcSatellite>>,
 }
 
 /// This structure stores data for a proposal.
@@ -218,39 +711,58 @@ pub struct Proposal {
     pub submitter: Addr,
     /// Status of the proposal
     pub status: ProposalStatus,
+    /// Name of the proposal type. The default proposal rules apply if not set
+    pub proposal_type: Option<String>,
+    /// Whether the proposal is voted on under the expedited rules
+    pub expedited: bool,
+    /// Whether the proposal didn't pass the expedited vote and was converted to a regular proposal
+    pub converted_from_expedited: bool,
     /// `For` power of proposal
     pub for_power: Uint128,
     /// `Against` power of proposal
     pub against_power: Uint128,
-    /// `For` votes for the proposal
-    pub for_voters: Vec<Addr>,
-    /// `Against` votes for the proposal
-    pub against_voters: Vec<Addr>,
+    /// `Abstain` power of proposal
+    pub abstain_power: Uint128,
+    /// `NoWithVeto` power of proposal
+    pub no_with_veto_power: Uint128,
     /// Start block of proposal
     pub start_block: u64,
     /// Start time of proposal
     pub start_time: u64,
-    /// End block of proposal
-    pub end_block: u64,
+    /// Timestamp (in seconds) when the voting period ends
+    pub end_time: u64,
+    /// Timestamp (in seconds) after which the proposal can be executed
+    pub effective_time: u64,
+    /// Timestamp (in seconds) after which the proposal expires
+    pub expiration_time: u64,
     /// Proposal title
     pub title: String,
     /// Proposal description
     pub description: String,
     /// Proposal link
     pub link: Option<String>,
+    /// Hex-encoded SHA-256 hash of the document behind the link. Clients can use it to verify
+    /// that the linked document hasn't changed since the proposal was submitted
+    pub content_hash: Option<String>,
     /// Proposal messages
     pub messages: Option<Vec<ProposalMessage>>,
     /// Amount of xASTRO deposited in order to post the proposal
     pub deposit_amount: Uint128,
+    /// Execution outcome of the proposal messages in their execution order
+    pub execution_results: Vec<ProposalMessageResult>,
+    /// Acknowledgement status of the proposal messages sent to satellites
+    pub ibc_message_statuses: Vec<ProposalIbcMessageStatus>,
 }
 
 impl Proposal {
-    pub fn validate(&self, whitelisted_links: Vec<String>) -> StdResult<()> {
+    pub fn validate(&self, config: &Config) -> StdResult<()> {
+        let limits = &config.proposal_validation;
+
         // Title validation
-        if self.title.len() < MIN_TITLE_LENGTH {
+        if (self.title.len() as u64) < limits.min_title_length {
             return Err(StdError::generic_err("Title too short!"));
         }
-        if self.title.len() > MAX_TITLE_LENGTH {
+        if self.title.len() as u64 > limits.max_title_length {
             return Err(StdError::generic_err("Title too long!"));
         }
         if !self.title.chars().all(|c| {
@@ -262,29 +774,35 @@ impl Proposal {
         }
 
         // Description validation
-        if self.description.len() < MIN_DESC_LENGTH {
+        let description_length = self.description.chars().count() as u64;
+        if description_length < limits.min_description_length {
             return Err(StdError::generic_err("Description too short!"));
         }
-        if self.description.len() > MAX_DESC_LENGTH {
+        if description_length > limits.max_description_length {
             return Err(StdError::generic_err("Description too long!"));
         }
-        if !self.description.chars().all(|c| {
-            c.is_ascii_alphanumeric() || c.is_ascii_whitespace() || SAFE_TEXT_CHARS.contains(c)
+        if self.description.chars().any(|c| {
+            (c.is_control() && !SAFE_DESC_CONTROL_CHARS.contains(c))
+                || UNSAFE_DESC_CHARS.contains(c)
         }) {
             return Err(StdError::generic_err(
-                "Description is not in alphanumeric format",
+                "Description contains unsafe characters!",
             ));
         }
 
         // Link validation
         if let Some(link) = &self.link {
-            if link.len() < MIN_LINK_LENGTH {
+            if (link.len() as u64) < limits.min_link_length {
                 return Err(StdError::generic_err("Link too short!"));
             }
-            if link.len() > MAX_LINK_LENGTH {
+            if link.len() as u64 > limits.max_link_length {
                 return Err(StdError::generic_err("Link too long!"));
             }
-            if !whitelisted_links.iter().any(|wl| link.starts_with(wl)) {
+            if !config
+                .whitelisted_links
+                .iter()
+                .any(|wl| link.starts_with(wl))
+            {
                 return Err(StdError::generic_err("Link is not whitelisted!"));
             }
             if !is_safe_link(link) {
@@ -294,6 +812,22 @@ impl Proposal {
             }
         }
 
+        // Content hash validation
+        if let Some(content_hash) = &self.content_hash {
+            if self.link.is_none() {
+                return Err(StdError::generic_err(
+                    "Content hash can only be set along with a link!",
+                ));
+            }
+            if content_hash.len() != CONTENT_HASH_LENGTH
+                || !content_hash.chars().all(|c| c.is_ascii_hexdigit())
+            {
+                return Err(StdError::generic_err(
+                    "Content hash must be a hex-encoded SHA-256 hash!",
+                ));
+            }
+        }
+
         Ok(())
     }
 }
@@ -303,9 +837,13 @@ impl Proposal {
 pub enum ProposalStatus {
     Active,
     Passed,
+    /// The proposal messages are queued in the Timelock
+    Queued,
     Rejected,
     Executed,
     Expired,
+    Cancelled,
+    FailedExecution,
 }
 
 impl Display for ProposalStatus {
@@ -313,9 +851,12 @@ impl Display for ProposalStatus {
         match self {
             ProposalStatus::Active {} => fmt.write_str("active"),
             ProposalStatus::Passed {} => fmt.write_str("passed"),
+            ProposalStatus::Queued {} => fmt.write_str("queued"),
             ProposalStatus::Rejected {} => fmt.write_str("rejected"),
             ProposalStatus::Executed {} => fmt.write_str("executed"),
             ProposalStatus::Expired {} => fmt.write_str("expired"),
+            ProposalStatus::Cancelled {} => fmt.write_str("cancelled"),
+            ProposalStatus::FailedExecution {} => fmt.write_str("failed_execution"),
         }
     }
 }
@@ -327,6 +868,56 @@ pub struct ProposalMessage {
     pub order: Uint64,
     /// Execution message
     pub msg: CosmosMsg,
+    /// IBC channel of the satellite that executes the message on a remote chain.
+    /// The message is executed by the Assembly if not set
+    pub ibc_channel: Option<String>,
+}
+
+/// This structure describes the execution outcome of a proposal message.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ProposalMessageResult {
+    /// Order of execution of the message
+    pub order: Uint64,
+    /// Error returned by the message. `None` if the message was executed successfully
+    pub error: Option<String>,
+}
+
+/// This structure describes the acknowledgement status of a proposal message sent to a satellite.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ProposalIbcMessageStatus {
+    /// Order of execution of the message
+    pub order: Uint64,
+    /// IBC channel the message was sent to
+    pub channel: String,
+    /// Acknowledgement status
+    pub status: IbcMessageStatus,
+    /// Error returned by the satellite if the message failed
+    pub error: Option<String>,
+}
+
+/// This enum describes available acknowledgement statuses of a message sent to a satellite.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum IbcMessageStatus {
+    /// The packet was sent and is waiting for an acknowledgement
+    Pending,
+    /// The message was executed by the satellite
+    Succeeded,
+    /// The message failed on the satellite chain
+    Failed,
+    /// The packet wasn't relayed before its timeout
+    TimedOut,
+}
+
+impl Display for IbcMessageStatus {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        match self {
+            IbcMessageStatus::Pending => fmt.write_str("pending"),
+            IbcMessageStatus::Succeeded => fmt.write_str("succeeded"),
+            IbcMessageStatus::Failed => fmt.write_str("failed"),
+            IbcMessageStatus::TimedOut => fmt.write_str("timed_out"),
+        }
+    }
 }
 
 /// This structure describes a proposal vote.
@@ -343,6 +934,10 @@ pub struct ProposalVote {
 pub enum ProposalVoteOption {
     For,
     Against,
+    /// Counts toward the quorum but is ignored when calculating the threshold
+    Abstain,
+    /// Counts as a vote against the proposal and rejects it if the veto threshold is reached
+    NoWithVeto,
 }
 
 impl Display for ProposalVoteOption {
@@ -350,10 +945,23 @@ impl Display for ProposalVoteOption {
         match self {
             ProposalVoteOption::For {} => fmt.write_str("for"),
             ProposalVoteOption::Against {} => fmt.write_str("against"),
+            ProposalVoteOption::Abstain {} => fmt.write_str("abstain"),
+            ProposalVoteOption::NoWithVeto {} => fmt.write_str("no_with_veto"),
         }
     }
 }
 
+/// This structure describes a vote cast by a user in the user votes response.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct UserVoteResponse {
+    /// Proposal identifier
+    pub proposal_id: u64,
+    /// Voted option for the proposal
+    pub option: ProposalVoteOption,
+    /// Vote power
+    pub power: Uint128,
+}
+
 /// This structure describes a proposal vote response.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct ProposalVotesResponse {
@@ -363,6 +971,10 @@ pub struct ProposalVotesResponse {
     pub for_power: Uint128,
     /// Total amount of `against` votes for a proposal.
     pub against_power: Uint128,
+    /// Total amount of `abstain` votes for a proposal.
+    pub abstain_power: Uint128,
+    /// Total amount of `no_with_veto` votes for a proposal.
+    pub no_with_veto_power: Uint128,
 }
 
 /// This structure describes proposal list response.
@@ -372,6 +984,40 @@ pub struct ProposalListResponse {
     pub proposal_list: Vec<Proposal>,
 }
 
+/// This structure stores the summary of a proposal removed from the proposal list.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ArchivedProposal {
+    /// Unique proposal ID
+    pub proposal_id: Uint64,
+    /// The address of the proposal submitter
+    pub submitter: Addr,
+    /// Proposal title
+    pub title: String,
+    /// Final status of the proposal
+    pub status: ProposalStatus,
+    /// `For` power of proposal
+    pub for_power: Uint128,
+    /// `Against` power of proposal
+    pub against_power: Uint128,
+    /// `Abstain` power of proposal
+    pub abstain_power: Uint128,
+    /// `NoWithVeto` power of proposal
+    pub no_with_veto_power: Uint128,
+    /// Start time of proposal
+    pub start_time: u64,
+    /// Timestamp (in seconds) when the voting period ended
+    pub end_time: u64,
+    /// Timestamp (in seconds) when the proposal was removed from the proposal list
+    pub archived_time: u64,
+}
+
+/// This structure describes archived proposal list response.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ArchivedProposalListResponse {
+    pub proposal_count: Uint64,
+    pub archived_proposal_list: Vec<ArchivedProposal>,
+}
+
 pub mod helpers {
     use cosmwasm_std::{StdError, StdResult};
 
@@ -136,11 +136,23 @@ pub mod msg {
         Config {},
         // State of this contract
         State {},
+        // State of this contract at the beginning of the specified block
+        StateAt {
+            /// Block height at which we query the state
+            height: u64,
+        },
         // Parameters and current status of an allocation
         Allocation {
             /// Account whose allocation status we query
             account: String,
         },
+        // Parameters and status of an allocation at the beginning of the specified block
+        AllocationAt {
+            /// Account whose allocation status we query
+            account: String,
+            /// Block height at which we query the allocation
+            height: u64,
+        },
         // Unlocked tokens from an allocation
         UnlockedTokens {
             /// Account whose amount of unlocked ASTRO we query for
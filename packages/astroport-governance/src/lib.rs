@@ -4,6 +4,8 @@ pub mod builder_unlock;
 pub mod escrow_fee_distributor;
 pub mod generator_controller;
 pub mod querier;
+pub mod satellite;
+pub mod timelock;
 pub mod utils;
 pub mod voting_escrow;
 
@@ -0,0 +1,95 @@
+use cosmwasm_std::{Addr, Binary, CosmosMsg, Uint64};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// IBC channel version used by the Assembly and its satellites
+pub const IBC_APP_VERSION: &str = "astroport-governance-v1";
+
+/// This structure holds the parameters used for creating a Satellite contract.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct InstantiateMsg {
+    /// Address that can update the Satellite parameters
+    pub owner: String,
+    /// IBC port of the Assembly on the main chain (for example `wasm.terra1...`)
+    pub controller_port: String,
+    /// IBC connection to the main chain the governance channel must be opened on
+    pub connection_id: String,
+    /// Channel of the Assembly the governance channel must be connected to. Any channel from the
+    /// Assembly port is accepted if not set
+    pub controller_channel: Option<String>,
+}
+
+/// This enum describes all execute functions available in the contract.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// Update parameters in the Satellite contract
+    /// ## Executor
+    /// Only the owner can execute this
+    UpdateConfig {
+        /// New owner address
+        owner: Option<String>,
+        /// New IBC port of the Assembly. The open governance channel is kept
+        controller_port: Option<String>,
+        /// New IBC connection to the main chain
+        connection_id: Option<String>,
+        /// New channel of the Assembly the governance channel must be connected to
+        controller_channel: Option<String>,
+    },
+}
+
+/// This enum describes all the queries available in the contract.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    /// Return the contract's configuration
+    Config {},
+}
+
+/// This structure stores general parameters for the Satellite contract.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Config {
+    /// Address that can update the Satellite parameters
+    pub owner: Addr,
+    /// IBC port of the Assembly on the main chain
+    pub controller_port: String,
+    /// IBC connection to the main chain the governance channel must be opened on
+    pub connection_id: String,
+    /// Channel of the Assembly the governance channel must be connected to
+    pub controller_channel: Option<String>,
+    /// Channel the Assembly messages are received from. `None` until a channel is opened
+    pub governance_channel: Option<String>,
+    /// Whether the governance channel was closed. A new channel is accepted only after the owner
+    /// updates the config
+    pub channel_closed: bool,
+}
+
+/// This enum describes the data of the IBC packets sent by the Assembly to its satellites.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SatellitePacket {
+    /// Execute a proposal message on the satellite chain
+    ExecuteProposalMessage {
+        /// Proposal identifier
+        proposal_id: u64,
+        /// Order of execution of the message in the proposal
+        order: Uint64,
+        /// Message executed by the Satellite contract
+        msg: CosmosMsg,
+    },
+}
+
+/// This enum describes the acknowledgement returned by a satellite for every received packet.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SatelliteAck {
+    /// The message was executed successfully
+    Result(Binary),
+    /// The message failed with the specified error
+    Error(String),
+}
+
+/// This structure describes a migration message.
+/// We currently take no arguments for migrations.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MigrateMsg {}
@@ -0,0 +1,161 @@
+use cosmwasm_std::{Addr, CosmosMsg, StdError, StdResult};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result};
+
+pub const MINIMUM_TIMELOCK_DELAY: u64 = 86_400; // 1 day in seconds
+pub const MINIMUM_TIMELOCK_GRACE_PERIOD: u64 = 86_400; // 1 day in seconds
+
+/// This structure holds the parameters used for creating a Timelock contract.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct InstantiateMsg {
+    /// Address that is allowed to queue transactions (the Assembly)
+    pub admin: String,
+    /// Address that can cancel any queued transaction
+    pub guardian: Option<String>,
+    /// Delay (in seconds) between queueing a transaction and the moment it can be executed
+    pub delay: u64,
+    /// Period (in seconds) after the ETA during which a transaction can be executed
+    pub grace_period: u64,
+}
+
+/// This enum describes all execute functions available in the contract.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// Queue the messages of a passed proposal for execution after the delay
+    /// ## Executor
+    /// Only the admin can execute this
+    QueueTransaction {
+        /// Identifier of the proposal the messages belong to
+        proposal_id: u64,
+        /// Messages to execute
+        messages: Vec<CosmosMsg>,
+    },
+    /// Execute a queued transaction whose ETA has passed and whose grace period hasn't ended.
+    /// Anyone can execute this
+    ExecuteTransaction {
+        /// Transaction identifier
+        transaction_id: u64,
+    },
+    /// Cancel a queued transaction
+    /// ## Executor
+    /// Only the guardian or the admin can execute this
+    CancelTransaction {
+        /// Transaction identifier
+        transaction_id: u64,
+    },
+    /// Update parameters in the Timelock contract
+    /// ## Executor
+    /// Only the Timelock contract is allowed to update its own parameters
+    UpdateConfig {
+        /// New admin address
+        admin: Option<String>,
+        /// New guardian address
+        guardian: Option<String>,
+        /// New delay in seconds
+        delay: Option<u64>,
+        /// New grace period in seconds
+        grace_period: Option<u64>,
+    },
+}
+
+/// This enum describes all the queries available in the contract.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    /// Return the contract's configuration
+    Config {},
+    /// Return information about a specific transaction
+    Transaction { transaction_id: u64 },
+    /// Return the latest transaction queued for a specific proposal
+    ProposalTransaction { proposal_id: u64 },
+    /// Return the list of transactions
+    Transactions {
+        /// Id after which to start querying
+        start_after: Option<u64>,
+        /// The amount of transactions to return
+        limit: Option<u32>,
+    },
+}
+
+/// This structure stores general parameters for the Timelock contract.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Config {
+    /// Address that is allowed to queue transactions
+    pub admin: Addr,
+    /// Address that can cancel any queued transaction
+    pub guardian: Option<Addr>,
+    /// Delay (in seconds) between queueing a transaction and the moment it can be executed
+    pub delay: u64,
+    /// Period (in seconds) after the ETA during which a transaction can be executed
+    pub grace_period: u64,
+}
+
+impl Config {
+    pub fn validate(&self) -> StdResult<()> {
+        if self.delay < MINIMUM_TIMELOCK_DELAY {
+            return Err(StdError::generic_err(format!(
+                "The timelock delay cannot be less than {} seconds.",
+                MINIMUM_TIMELOCK_DELAY
+            )));
+        }
+
+        if self.grace_period < MINIMUM_TIMELOCK_GRACE_PERIOD {
+            return Err(StdError::generic_err(format!(
+                "The timelock grace period cannot be less than {} seconds.",
+                MINIMUM_TIMELOCK_GRACE_PERIOD
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// This structure stores data for a queued transaction.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Transaction {
+    /// Unique transaction ID
+    pub transaction_id: u64,
+    /// Identifier of the proposal the messages belong to
+    pub proposal_id: u64,
+    /// Messages to execute
+    pub messages: Vec<CosmosMsg>,
+    /// Timestamp (in seconds) after which the transaction can be executed
+    pub eta: u64,
+    /// Status of the transaction
+    pub status: TransactionStatus,
+}
+
+/// This enum describes available transaction statuses.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TransactionStatus {
+    Queued,
+    Executed,
+    Cancelled,
+}
+
+impl Display for TransactionStatus {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {
+        match self {
+            TransactionStatus::Queued => fmt.write_str("queued"),
+            TransactionStatus::Executed => fmt.write_str("executed"),
+            TransactionStatus::Cancelled => fmt.write_str("cancelled"),
+        }
+    }
+}
+
+/// This structure describes a transaction list response.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TransactionListResponse {
+    /// The total amount of transactions queued in the contract
+    pub transaction_count: u64,
+    /// The list of transactions that are returned
+    pub transactions: Vec<Transaction>,
+}
+
+/// This structure describes a migration message.
+/// We currently take no arguments for migrations.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MigrateMsg {}
@@ -1,11 +1,14 @@
 use crate::voting_escrow::QueryMsg::{
-    LockInfo, TotalVotingPower, TotalVotingPowerAt, UserVotingPower, UserVotingPowerAt,
+    LockInfo, TotalVotingPower, TotalVotingPowerAt, UserLocks, UserVotingPower, UserVotingPowerAt,
 };
 use cosmwasm_std::{Addr, Binary, Decimal, QuerierWrapper, StdResult, Uint128};
 use cw20::{Cw20ReceiveMsg, Logo};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// The maximum number of lock positions that a staker can have at the same time
+pub const MAX_USER_LOCKS: usize = 10;
+
 /// This structure stores marketing information for vxASTRO.
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
 pub struct InstantiateMarketingInfo {
@@ -40,21 +43,37 @@ pub struct InstantiateMsg {
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
-    /// Extend the lockup time for your staked xASTRO
-    ExtendLockTime { time: u64 },
+    /// Extend the lockup time for one of your vxASTRO positions
+    ExtendLockTime { lock_id: u64, time: u64 },
     /// Receives a message of type [`Cw20ReceiveMsg`] and processes it depending on the received
     /// template.
     Receive(Cw20ReceiveMsg),
-    /// Withdraw xASTRO from the vxASTRO contract
-    Withdraw {},
-    /// Early withdrawal with slashing penalty
-    WithdrawEarly {},
+    /// Withdraw xASTRO from an expired vxASTRO position
+    Withdraw { lock_id: u64 },
+    /// Early withdrawal from a vxASTRO position with slashing penalty
+    WithdrawEarly { lock_id: u64 },
+    /// Propose to transfer one of your vxASTRO positions together with its voting power to another address
+    TransferLock { lock_id: u64, recipient: String },
+    /// Accept a vxASTRO position transfer proposed to you
+    AcceptLockTransfer { sender: String, lock_id: u64 },
+    /// Cancel a proposed transfer of one of your vxASTRO positions
+    CancelLockTransfer { lock_id: u64 },
+    /// Merge two of your vxASTRO positions into the one with the later end
+    MergeLock {
+        first_lock_id: u64,
+        second_lock_id: u64,
+    },
     ConfigureEarlyWithdrawal {
         /// The maximum penalty that can be applied to a user
         max_penalty: Option<Decimal>,
         /// The address that will receive the slashed funds
         slashed_fund_receiver: Option<String>,
     },
+    /// Set the generator controller whose active votes block lock position transfers
+    UpdateConfig {
+        /// The generator controller address
+        generator_controller: Option<String>,
+    },
     /// A callback after early withdrawal to send slashed ASTRO to the slashed funds receiver
     EarlyWithdrawCallback {
         /// Contracts' ASTRO balance before callback
@@ -90,12 +109,12 @@ pub enum ExecuteMsg {
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum Cw20HookMsg {
-    /// Create a vxASTRO position and lock xASTRO for `time` amount of time
+    /// Create a new vxASTRO position and lock xASTRO for `time` amount of time
     CreateLock { time: u64 },
-    /// Deposit xASTRO in another user's vxASTRO position
-    DepositFor { user: String },
-    /// Add more xASTRO to your vxASTRO position
-    ExtendLockAmount {},
+    /// Deposit xASTRO in one of another user's vxASTRO positions
+    DepositFor { user: String, lock_id: u64 },
+    /// Add more xASTRO to one of your vxASTRO positions
+    ExtendLockAmount { lock_id: u64 },
 }
 
 /// This structure describes the query messages available in the contract.
@@ -122,11 +141,13 @@ pub enum QueryMsg {
     UserVotingPowerAt { user: String, time: u64 },
     /// Return the user's voting power at a specific period
     UserVotingPowerAtPeriod { user: String, period: u64 },
-    /// Return information about a user's lock position
+    /// Return information about a user's lock positions aggregated together
     LockInfo { user: String },
-    /// Return the amount of xASTRO that the staker can withdraw right now after the penalty is applied
-    /// for early withdrawal
-    EarlyWithdrawAmount { user: String },
+    /// Return information about every lock position of a user
+    UserLocks { user: String },
+    /// Return the amount of xASTRO that the staker can withdraw right now from a lock position
+    /// after the penalty is applied for early withdrawal
+    EarlyWithdrawAmount { user: String, lock_id: u64 },
     /// Return user's locked xASTRO balance at the given block height
     UserDepositAtHeight { user: String, height: u64 },
     /// Return the  vxASTRO contract configuration
@@ -154,6 +175,15 @@ pub struct LockInfoResponse {
     pub slope: Uint128,
 }
 
+/// This structure is used to return the lock information for one of a user's vxASTRO positions.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct UserLockResponse {
+    /// The lock identifier
+    pub lock_id: u64,
+    /// The lock position information
+    pub lock: LockInfoResponse,
+}
+
 /// This structure stores the parameters returned when querying for a contract's configuration.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct ConfigResponse {
@@ -171,6 +201,8 @@ pub struct ConfigResponse {
     pub astro_addr: String,
     /// The address of $xASTRO staking contract
     pub xastro_staking_addr: String,
+    /// The generator controller address
+    pub generator_controller_addr: Option<String>,
 }
 
 /// This structure describes a Migration message.
@@ -251,3 +283,18 @@ pub fn get_lock_info(
     )?;
     Ok(lock_info)
 }
+
+/// ## Description
+/// Queries the information of every lock position of a user from the voting escrow contract.
+pub fn get_user_locks(
+    querier: QuerierWrapper,
+    escrow_addr: &Addr,
+    user: &Addr,
+) -> StdResult<Vec<UserLockResponse>> {
+    querier.query_wasm_smart(
+        escrow_addr.clone(),
+        &UserLocks {
+            user: user.to_string(),
+        },
+    )
+}
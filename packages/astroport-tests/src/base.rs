@@ -231,13 +231,14 @@ impl BaseAstroportTestPackage {
         &mut self,
         router: &mut TerraApp,
         user: &str,
+        lock_id: u64,
         amount: u64,
     ) -> Result<AppResponse> {
         let amount = amount * MULTIPLIER;
         let cw20msg = Cw20ExecuteMsg::Send {
             contract: self.voting_escrow.clone().unwrap().address.to_string(),
             amount: Uint128::from(amount),
-            msg: to_binary(&Cw20HookMsg::ExtendLockAmount {}).unwrap(),
+            msg: to_binary(&Cw20HookMsg::ExtendLockAmount { lock_id }).unwrap(),
         };
         router.execute_contract(
             Addr::unchecked(user),
@@ -251,21 +252,22 @@ impl BaseAstroportTestPackage {
         &mut self,
         router: &mut TerraApp,
         user: &str,
+        lock_id: u64,
         time: u64,
     ) -> Result<AppResponse> {
         router.execute_contract(
             Addr::unchecked(user),
             self.voting_escrow.clone().unwrap().address,
-            &ExecuteMsg::ExtendLockTime { time },
+            &ExecuteMsg::ExtendLockTime { lock_id, time },
             &[],
         )
     }
 
-    pub fn withdraw(&self, router: &mut TerraApp, user: &str) -> Result<AppResponse> {
+    pub fn withdraw(&self, router: &mut TerraApp, user: &str, lock_id: u64) -> Result<AppResponse> {
         router.execute_contract(
             Addr::unchecked(user),
             self.voting_escrow.clone().unwrap().address,
-            &ExecuteMsg::Withdraw {},
+            &ExecuteMsg::Withdraw { lock_id },
             &[],
         )
     }
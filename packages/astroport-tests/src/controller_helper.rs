@@ -140,6 +140,18 @@ impl ControllerHelper {
             )
             .unwrap();
 
+        // Setup controller in voting escrow contract
+        router
+            .execute_contract(
+                owner.clone(),
+                escrow_helper.escrow_instance.clone(),
+                &astroport_governance::voting_escrow::ExecuteMsg::UpdateConfig {
+                    generator_controller: Some(controller.to_string()),
+                },
+                &[],
+            )
+            .unwrap();
+
         Self {
             owner: owner.to_string(),
             generator,
@@ -1,7 +1,8 @@
 use anyhow::Result;
 use astroport::{staking as xastro, token as astro};
 use astroport_governance::voting_escrow::{
-    Cw20HookMsg, ExecuteMsg, InstantiateMsg, LockInfoResponse, QueryMsg, VotingPowerResponse,
+    Cw20HookMsg, ExecuteMsg, InstantiateMsg, LockInfoResponse, QueryMsg, UserLockResponse,
+    VotingPowerResponse,
 };
 use cosmwasm_std::{attr, to_binary, Addr, Decimal, QueryRequest, StdResult, Uint128, WasmQuery};
 use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg, MinterResponse};
@@ -189,13 +190,14 @@ impl EscrowHelper {
         &self,
         router: &mut TerraApp,
         user: &str,
+        lock_id: u64,
         amount: f32,
     ) -> Result<AppResponse> {
         let amount = (amount * MULTIPLIER as f32) as u64;
         let cw20msg = Cw20ExecuteMsg::Send {
             contract: self.escrow_instance.to_string(),
             amount: Uint128::from(amount),
-            msg: to_binary(&Cw20HookMsg::ExtendLockAmount {}).unwrap(),
+            msg: to_binary(&Cw20HookMsg::ExtendLockAmount { lock_id }).unwrap(),
         };
         router.execute_contract(
             Addr::unchecked(user),
@@ -210,6 +212,7 @@ impl EscrowHelper {
         router: &mut TerraApp,
         from: &str,
         to: &str,
+        lock_id: u64,
         amount: f32,
     ) -> Result<AppResponse> {
         let amount = (amount * MULTIPLIER as f32) as u64;
@@ -218,6 +221,7 @@ impl EscrowHelper {
             amount: Uint128::from(amount),
             msg: to_binary(&Cw20HookMsg::DepositFor {
                 user: to.to_string(),
+                lock_id,
             })
             .unwrap(),
         };
@@ -233,21 +237,58 @@ impl EscrowHelper {
         &self,
         router: &mut TerraApp,
         user: &str,
+        lock_id: u64,
         time: u64,
     ) -> Result<AppResponse> {
         router.execute_contract(
             Addr::unchecked(user),
             self.escrow_instance.clone(),
-            &ExecuteMsg::ExtendLockTime { time },
+            &ExecuteMsg::ExtendLockTime { lock_id, time },
             &[],
         )
     }
 
-    pub fn withdraw(&self, router: &mut TerraApp, user: &str) -> Result<AppResponse> {
+    pub fn withdraw(&self, router: &mut TerraApp, user: &str, lock_id: u64) -> Result<AppResponse> {
         router.execute_contract(
             Addr::unchecked(user),
             self.escrow_instance.clone(),
-            &ExecuteMsg::Withdraw {},
+            &ExecuteMsg::Withdraw { lock_id },
+            &[],
+        )
+    }
+
+    pub fn transfer_lock(
+        &self,
+        router: &mut TerraApp,
+        user: &str,
+        lock_id: u64,
+        recipient: &str,
+    ) -> Result<AppResponse> {
+        router.execute_contract(
+            Addr::unchecked(user),
+            self.escrow_instance.clone(),
+            &ExecuteMsg::TransferLock {
+                lock_id,
+                recipient: recipient.to_string(),
+            },
+            &[],
+        )
+    }
+
+    pub fn accept_lock_transfer(
+        &self,
+        router: &mut TerraApp,
+        user: &str,
+        sender: &str,
+        lock_id: u64,
+    ) -> Result<AppResponse> {
+        router.execute_contract(
+            Addr::unchecked(user),
+            self.escrow_instance.clone(),
+            &ExecuteMsg::AcceptLockTransfer {
+                sender: sender.to_string(),
+                lock_id,
+            },
             &[],
         )
     }
@@ -351,4 +392,17 @@ impl EscrowHelper {
             },
         )
     }
+
+    pub fn query_user_locks(
+        &self,
+        router: &mut TerraApp,
+        user: &str,
+    ) -> StdResult<Vec<UserLockResponse>> {
+        router.wrap().query_wasm_smart(
+            self.escrow_instance.clone(),
+            &QueryMsg::UserLocks {
+                user: user.to_string(),
+            },
+        )
+    }
 }
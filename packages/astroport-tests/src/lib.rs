@@ -10,6 +10,9 @@ pub mod controller_helper;
 #[allow(clippy::all)]
 #[allow(dead_code)]
 pub mod escrow_helper;
+#[allow(clippy::all)]
+#[allow(dead_code)]
+pub mod timelock_helper;
 
 pub fn mock_app() -> TerraApp {
     let mut env = mock_env();
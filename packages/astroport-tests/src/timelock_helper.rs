@@ -0,0 +1,136 @@
+use anyhow::Result;
+use astroport_governance::timelock::{
+    Config, ExecuteMsg, InstantiateMsg, QueryMsg, Transaction, TransactionListResponse,
+};
+use cosmwasm_std::{Addr, CosmosMsg, StdResult};
+use terra_multi_test::{AppResponse, ContractWrapper, Executor, TerraApp};
+
+pub const TIMELOCK_DELAY: u64 = 2 * 86400;
+pub const TIMELOCK_GRACE_PERIOD: u64 = 7 * 86400;
+
+pub struct TimelockHelper {
+    pub admin: Addr,
+    pub guardian: Addr,
+    pub timelock_instance: Addr,
+}
+
+impl TimelockHelper {
+    pub fn init(router: &mut TerraApp, admin: Addr, guardian: Addr) -> Self {
+        let timelock_contract = Box::new(ContractWrapper::new_with_empty(
+            astro_timelock::contract::execute,
+            astro_timelock::contract::instantiate,
+            astro_timelock::contract::query,
+        ));
+
+        let timelock_code_id = router.store_code(timelock_contract);
+
+        let msg = InstantiateMsg {
+            admin: admin.to_string(),
+            guardian: Some(guardian.to_string()),
+            delay: TIMELOCK_DELAY,
+            grace_period: TIMELOCK_GRACE_PERIOD,
+        };
+        let timelock_instance = router
+            .instantiate_contract(
+                timelock_code_id,
+                admin.clone(),
+                &msg,
+                &[],
+                String::from("Timelock"),
+                None,
+            )
+            .unwrap();
+
+        Self {
+            admin,
+            guardian,
+            timelock_instance,
+        }
+    }
+
+    pub fn queue_transaction(
+        &self,
+        router: &mut TerraApp,
+        sender: &str,
+        proposal_id: u64,
+        messages: Vec<CosmosMsg>,
+    ) -> Result<AppResponse> {
+        router.execute_contract(
+            Addr::unchecked(sender),
+            self.timelock_instance.clone(),
+            &ExecuteMsg::QueueTransaction {
+                proposal_id,
+                messages,
+            },
+            &[],
+        )
+    }
+
+    pub fn execute_transaction(
+        &self,
+        router: &mut TerraApp,
+        sender: &str,
+        transaction_id: u64,
+    ) -> Result<AppResponse> {
+        router.execute_contract(
+            Addr::unchecked(sender),
+            self.timelock_instance.clone(),
+            &ExecuteMsg::ExecuteTransaction { transaction_id },
+            &[],
+        )
+    }
+
+    pub fn cancel_transaction(
+        &self,
+        router: &mut TerraApp,
+        sender: &str,
+        transaction_id: u64,
+    ) -> Result<AppResponse> {
+        router.execute_contract(
+            Addr::unchecked(sender),
+            self.timelock_instance.clone(),
+            &ExecuteMsg::CancelTransaction { transaction_id },
+            &[],
+        )
+    }
+
+    pub fn query_config(&self, router: &mut TerraApp) -> StdResult<Config> {
+        router
+            .wrap()
+            .query_wasm_smart(self.timelock_instance.clone(), &QueryMsg::Config {})
+    }
+
+    pub fn query_transaction(
+        &self,
+        router: &mut TerraApp,
+        transaction_id: u64,
+    ) -> StdResult<Transaction> {
+        router.wrap().query_wasm_smart(
+            self.timelock_instance.clone(),
+            &QueryMsg::Transaction { transaction_id },
+        )
+    }
+
+    pub fn query_proposal_transaction(
+        &self,
+        router: &mut TerraApp,
+        proposal_id: u64,
+    ) -> StdResult<Transaction> {
+        router.wrap().query_wasm_smart(
+            self.timelock_instance.clone(),
+            &QueryMsg::ProposalTransaction { proposal_id },
+        )
+    }
+
+    pub fn query_transactions(
+        &self,
+        router: &mut TerraApp,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> StdResult<TransactionListResponse> {
+        router.wrap().query_wasm_smart(
+            self.timelock_instance.clone(),
+            &QueryMsg::Transactions { start_after, limit },
+        )
+    }
+}
@@ -148,12 +148,18 @@ async function deployAssembly(terra: LCDClient, wallet: any) {
             "xastro_token_addr": network.xastroAddress,
             "vxastro_token_addr": network.votingEscrow,
             "builder_unlock_addr": network.builderUnlockAddress,
-            "proposal_voting_period": 57600,
-            "proposal_effective_delay": 28800,
-            "proposal_expiration_period": 201600,
+            "proposal_voting_period": 345600, // 4 days
+            "proposal_effective_delay": 172800, // 2 days
+            "proposal_expiration_period": 1209600, // 14 days
             "proposal_required_deposit": "30000000000", // 30k ASTRO
             "proposal_required_quorum": "0.1", // 10%
             "proposal_required_threshold": '0.50',   // 50%
+            "proposal_required_veto_threshold": '0.334',   // 33.4%
+            "expedited_proposal_required_deposit": "60000000000", // 60k ASTRO
+            "expedited_proposal_voting_period": 86400, // 1 day
+            "expedited_proposal_required_threshold": '0.667',   // 66.7%
+            "quorum_failure_deposit_slash": '0',
+            "veto_deposit_slash": '1',
             "whitelisted_links": ["https://forum.astroport.fi/", "http://forum.astroport.fi/", "https://astroport.fi/", "http://astroport.fi/"]
         }
     )